@@ -0,0 +1,37 @@
+//! Build script: generates the `ConstNat<N>` → nat table used by `base::num::literal`, and the
+//! table's bound `CONST_NAT_BOUND` (the only place it is defined).
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::PathBuf;
+
+/// Bits per generated natural: the table covers `N < 1 << CONST_NAT_BITS`. One more bit doubles
+/// the impl count (and the crate's compile time).
+const CONST_NAT_BITS: u32 = 11;
+
+fn main() {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "/// Exclusive upper bound of the `ConstNat<N>` table (`N` has at most {CONST_NAT_BITS} bits).\n\
+         pub const CONST_NAT_BOUND: usize = {};\n",
+        1usize << CONST_NAT_BITS
+    )
+    .unwrap();
+    out.push_str("impl ToNat for ConstNat<0> {\n    type Nat = UTerm;\n}\n");
+    // Each entry shifts the low bit of `n` in below the (already canonical) entry for `n / 2`.
+    for n in 1usize..1 << CONST_NAT_BITS {
+        let bit = if n % 2 == 0 { "B0" } else { "B1" };
+        writeln!(
+            out,
+            "impl ToNat for ConstNat<{n}> {{\n    type Nat = UInt<{bit}, NatOf<{}>>;\n}}",
+            n / 2
+        )
+        .unwrap();
+    }
+
+    let path = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("const_nat_table.rs");
+    fs::write(path, out).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,209 @@
+//! Open-world compiler domains: a downstream crate adds its own keys.
+//!
+//! Shows:
+//! - `declare_domain!`: a local domain marker with an external code (`Ext<CrateId, Local>`),
+//!   its keys (indices by position) and the matching table skeleton
+//! - a compile-time collision check over this crate's domain codes
+//! - `NodeShape` impls: each key's payload shape (here all nullary), which the kernel-only walk,
+//!   `pretty`, `metrics`, `normal` and `dot` read
+//! - `LowerDomain` / `ReifyDomain` impls supplying the domain's closed tables
+//! - a `NoReifyRule` slot for the frontend key, so a stray `KX_SHOUT` after lowering is a named
+//!   error (the reify table's real handlers are what may survive lowering)
+//! - user keys composing with built-in combinators (`then`) under `compile`
+
+use core::marker::PhantomData;
+
+use morphism::{assert_distinct_domain_codes, compile, shape, then, LowerDomain, ReifyDomain};
+use morphism::{Ext, NoReifyRule, NodeShape, NullaryToken, OpOnce, Tagged, Then, U0, U7};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Keys (the downstream crate owns its own identity space)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// This crate's id (non-zero; namespace `0` belongs to `morphism` itself). Registered in the
+/// `CrateId` table of morphism's registry README.
+pub type CrateId = U7;
+
+#[allow(non_camel_case_types)]
+pub type D_TEXT = Ext<CrateId, U0>;
+
+/// Every domain code this crate owns (cons-list).
+pub type MyDomainCodes = (D_TEXT, ());
+
+// Fails the build on a local collision; list other crates' codes too to check across crates.
+const _: fn() = assert_distinct_domain_codes::<MyDomainCodes>;
+
+morphism::declare_domain! {
+    /// Local marker: keys name it, and this crate can implement `LowerDomain` / `ReifyDomain` for it.
+    domain struct TextDomain: D_TEXT;
+
+    semantics(table = TextSemanticsTable) {
+        KX_TRIM,  // U0, kernel
+        KX_UPPER, // U1, kernel
+        KX_SHOUT, // U2, frontend: trim, then upper
+    }
+}
+
+impl NodeShape<KX_TRIM> for TextDomain {
+    type Shape = shape::Nullary;
+}
+
+impl NodeShape<KX_UPPER> for TextDomain {
+    type Shape = shape::Nullary;
+}
+
+impl NodeShape<KX_SHOUT> for TextDomain {
+    type Shape = shape::Nullary;
+}
+
+pub type TrimProg = Tagged<KX_TRIM, NullaryToken>;
+pub type UpperProg = Tagged<KX_UPPER, NullaryToken>;
+pub type ShoutProg = Tagged<KX_SHOUT, NullaryToken>;
+
+pub const fn trim() -> TrimProg {
+    Tagged::new(NullaryToken)
+}
+
+pub const fn upper() -> UpperProg {
+    Tagged::new(NullaryToken)
+}
+
+pub const fn shout() -> ShoutProg {
+    Tagged::new(NullaryToken)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Runtime ops
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TrimOp;
+
+impl OpOnce<String> for TrimOp {
+    type OutVal = String;
+
+    fn run(self, s: String) -> String {
+        s.trim().to_string()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UpperOp;
+
+impl OpOnce<String> for UpperOp {
+    type OutVal = String;
+
+    fn run(self, s: String) -> String {
+        s.to_uppercase()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Lowering table: kernel keys are identity, `shout` desugars into kernel keys
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerTrim;
+
+impl OpOnce<NullaryToken> for LowerTrim {
+    type OutVal = TrimProg;
+
+    fn run(self, _: NullaryToken) -> TrimProg {
+        trim()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerUpper;
+
+impl OpOnce<NullaryToken> for LowerUpper {
+    type OutVal = UpperProg;
+
+    fn run(self, _: NullaryToken) -> UpperProg {
+        upper()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerShout;
+
+impl OpOnce<NullaryToken> for LowerShout {
+    type OutVal = Then<TrimProg, UpperProg>;
+
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        then(trim(), upper())
+    }
+}
+
+impl<Input> LowerDomain<Input> for TextDomain {
+    type Table = TextSemanticsTable<
+        LowerTrim,  // U0: KX_TRIM
+        LowerUpper, // U1: KX_UPPER
+        LowerShout, // U2: KX_SHOUT
+    >;
+
+    fn lower_table() -> Self::Table {
+        (LowerTrim, LowerUpper, LowerShout)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Reify table: kernel keys (`KX_SHOUT` never survives lowering)
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileTrim<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> OpOnce<NullaryToken> for CompileTrim<Input> {
+    type OutVal = TrimOp;
+
+    fn run(self, _: NullaryToken) -> TrimOp {
+        TrimOp
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileUpper<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> OpOnce<NullaryToken> for CompileUpper<Input> {
+    type OutVal = UpperOp;
+
+    fn run(self, _: NullaryToken) -> UpperOp {
+        UpperOp
+    }
+}
+
+// Kernel keys are the ones with a real handler here: `Compile` lets `KX_TRIM` / `KX_UPPER`
+// through from lowering to reify; `KX_SHOUT` is a type error at its `NoReifyRule` slot.
+impl<Input> ReifyDomain<Input> for TextDomain {
+    type Table = TextSemanticsTable<
+        CompileTrim<Input>,    // U0: KX_TRIM
+        CompileUpper<Input>,   // U1: KX_UPPER
+        NoReifyRule<KX_SHOUT>, // U2: KX_SHOUT (lowered away; reaching reify is a type error)
+    >;
+
+    fn reify_table() -> Self::Table {
+        (
+            CompileTrim(PhantomData),
+            CompileUpper(PhantomData),
+            NoReifyRule::new(),
+        )
+    }
+}
+
+fn main() {
+    println!("--- Custom kernel keys ---");
+    let out = compile::<String, _>(trim()).run(String::from("  hi  "));
+    assert_eq!(out, "hi");
+
+    println!("\n--- Composed with built-in combinators ---");
+    let out = compile::<String, _>(then(upper(), trim())).run(String::from("  hi  "));
+    assert_eq!(out, "HI");
+
+    println!("\n--- Frontend key desugared by the domain's lowering table ---");
+    let out = compile::<String, _>(shout()).run(String::from("  hello  "));
+    println!("{out}");
+    assert_eq!(out, "HELLO");
+
+    println!("✓ Custom domain demo passed");
+}
@@ -0,0 +1,75 @@
+//! Graphviz export of the `foldable` example's programs, before and after lowering.
+//!
+//! Shows:
+//! - `ProgDot::dot_into` / `DotGraph`: one named DOT graph per program
+//! - the frontend program (`fold_children_l`, `fold_everything_l`) and its lowered kernel
+//!
+//! Prints every graph to stdout; with a directory argument, writes `<dir>/<name>.dot` instead:
+//!
+//! ```text
+//! cargo run --example dot_export -- target/dot && dot -Tsvg -O target/dot/*.dot
+//! ```
+
+use std::path::PathBuf;
+
+use morphism::{fold_children_l_prog, fold_everything_l_prog, id, lower, op_lift, then};
+use morphism::{DotGraph, HlistNode, OpOnce, OpTy, ProgDot};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CountL;
+
+impl<S> OpTy<(usize, S)> for CountL {
+    type OutTy = usize;
+}
+
+impl<S> OpOnce<(usize, S)> for CountL {
+    type OutVal = usize;
+
+    #[inline]
+    fn run(self, (acc, _elem): (usize, S)) -> Self::OutVal {
+        acc + 1
+    }
+}
+
+/// One named DOT graph for `prog`.
+fn graph<P: ProgDot>(name: &str, prog: &P) -> (String, String) {
+    let mut graph = DotGraph::default();
+    prog.dot_into(&mut graph);
+    (name.to_owned(), graph.finish(name))
+}
+
+fn main() -> std::io::Result<()> {
+    // `(1u8, 2u16, 3u32).fold_children_l(CountL, 0usize)` builds this program.
+    let fold_children = then(id(), fold_children_l_prog(op_lift(CountL), 0usize));
+    // `(1u8, 2u16, 3u32).fold_everything_l(CountL, 0usize)` builds this one.
+    let fold_everything = then(id(), fold_everything_l_prog(op_lift(CountL), 0usize));
+
+    type Flat = HlistNode<(u8, u16, u32)>;
+
+    let graphs = [
+        graph("fold_children_l", &fold_children),
+        graph("fold_children_l_lowered", &lower::<Flat, _>(fold_children)),
+        graph("fold_everything_l", &fold_everything),
+        graph(
+            "fold_everything_l_lowered",
+            &lower::<Flat, _>(fold_everything),
+        ),
+    ];
+
+    match std::env::args().nth(1).map(PathBuf::from) {
+        Some(dir) => {
+            std::fs::create_dir_all(&dir)?;
+            for (name, source) in &graphs {
+                let path = dir.join(format!("{name}.dot"));
+                std::fs::write(&path, source)?;
+                println!("wrote {}", path.display());
+            }
+        }
+        None => {
+            for (_, source) in &graphs {
+                println!("{source}");
+            }
+        }
+    }
+    Ok(())
+}
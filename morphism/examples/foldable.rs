@@ -2,6 +2,7 @@
 //!
 //! Shows:
 //! - `x.fold_children_l(op, acc)`
+//! - `x.fold_everything_l(op, acc)` (deep fold over every leaf)
 
 use morphism::{Container, Foldable, FromContainer, IntoContainer};
 use morphism::{Id, OpOnce, OpTy, Tagged, IX_NEWTYPENODE};
@@ -60,5 +61,12 @@ fn main() {
     let n_l_leaf: usize = leaf.fold_children_l(CountL, 0usize);
     assert_eq!(n_l_leaf, 0);
 
+    println!("\n--- Deep fold: every leaf of a nested product ---");
+    let nested = (1u8, (2u16, (3u32, Leaf3((4u8, 5u8)))), 6u64);
+
+    // Nested tuples are traversed; `Leaf3` stays atomic (its tuple payload counts once).
+    let n_deep: usize = nested.fold_everything_l(CountL, 0usize);
+    assert_eq!(n_deep, 5);
+
     println!("✓ Foldable demo passed");
 }
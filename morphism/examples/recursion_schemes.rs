@@ -0,0 +1,150 @@
+//! Recursion schemes over a host AST.
+//!
+//! Shows:
+//! - describing a recursive type by its base functor (`LayerFunctor`, `Recursive`, `Corecursive`)
+//! - `cata(alg)` (evaluate), `para(alg)` (render with subterm access), `ana(coalg)` (generate)
+
+use morphism::{ana, cata, para, Corecursive, LayerFunctor, OpOnce, Recursive};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Lit(i32),
+    Mul(Box<Expr>, Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+}
+
+/// One layer of `Expr`: recursive positions replaced by `R`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExprF<R> {
+    Lit(i32),
+    Mul(R, R),
+    Add(R, R),
+}
+
+impl LayerFunctor for Expr {
+    type Layer<R> = ExprF<R>;
+
+    fn map_layer<A, B, F>(layer: ExprF<A>, f: F) -> ExprF<B>
+    where
+        F: OpOnce<A, OutVal = B> + Clone,
+    {
+        match layer {
+            ExprF::Lit(n) => ExprF::Lit(n),
+            ExprF::Mul(l, r) => {
+                let l = f.clone().run(l);
+                ExprF::Mul(l, f.run(r))
+            }
+            ExprF::Add(l, r) => {
+                let l = f.clone().run(l);
+                ExprF::Add(l, f.run(r))
+            }
+        }
+    }
+}
+
+impl Recursive for Expr {
+    fn project(self) -> ExprF<Expr> {
+        match self {
+            Expr::Lit(n) => ExprF::Lit(n),
+            Expr::Mul(l, r) => ExprF::Mul(*l, *r),
+            Expr::Add(l, r) => ExprF::Add(*l, *r),
+        }
+    }
+}
+
+impl Corecursive for Expr {
+    fn embed(layer: ExprF<Expr>) -> Self {
+        match layer {
+            ExprF::Lit(n) => Expr::Lit(n),
+            ExprF::Mul(l, r) => Expr::Mul(Box::new(l), Box::new(r)),
+            ExprF::Add(l, r) => Expr::Add(Box::new(l), Box::new(r)),
+        }
+    }
+}
+
+/// Algebra: evaluate one layer whose children are already values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Eval;
+
+impl OpOnce<ExprF<i64>> for Eval {
+    type OutVal = i64;
+
+    fn run(self, layer: ExprF<i64>) -> i64 {
+        match layer {
+            ExprF::Lit(n) => n as i64,
+            ExprF::Mul(l, r) => l * r,
+            ExprF::Add(l, r) => l + r,
+        }
+    }
+}
+
+/// Paramorphism algebra: parenthesise `Add` only when it appears under `Mul`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Render;
+
+fn operand((sub, s): (Expr, String)) -> String {
+    match sub {
+        Expr::Add(..) => format!("({s})"),
+        _ => s,
+    }
+}
+
+impl OpOnce<ExprF<(Expr, String)>> for Render {
+    type OutVal = String;
+
+    fn run(self, layer: ExprF<(Expr, String)>) -> String {
+        match layer {
+            ExprF::Lit(n) => n.to_string(),
+            ExprF::Mul(l, r) => format!("{} * {}", operand(l), operand(r)),
+            ExprF::Add((_, l), (_, r)) => format!("{l} + {r}"),
+        }
+    }
+}
+
+/// Unfold seed: either emit a literal or expand `n!` one step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Seed {
+    Lit(i32),
+    Expand(i32),
+}
+
+/// Coalgebra: `n! ↦ n * (n-1)!`, bottoming out at `1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FactorialSeeds;
+
+impl OpOnce<Seed> for FactorialSeeds {
+    type OutVal = ExprF<Seed>;
+
+    fn run(self, seed: Seed) -> ExprF<Seed> {
+        match seed {
+            Seed::Lit(n) => ExprF::Lit(n),
+            Seed::Expand(n) if n <= 1 => ExprF::Lit(1),
+            Seed::Expand(n) => ExprF::Mul(Seed::Lit(n), Seed::Expand(n - 1)),
+        }
+    }
+}
+
+fn main() {
+    let lit = Expr::Lit;
+    let add = |l, r| Expr::Add(Box::new(l), Box::new(r));
+    let mul = |l, r| Expr::Mul(Box::new(l), Box::new(r));
+
+    println!("--- cata: evaluate ---");
+    let e = mul(add(lit(1), lit(2)), add(lit(3), mul(lit(4), lit(5))));
+    let v: i64 = cata(Eval).run(e.clone());
+    println!("value = {v}");
+    assert_eq!(v, 69);
+
+    println!("\n--- para: render with subterm access ---");
+    let s: String = para(Render).run(e);
+    println!("{s}");
+    assert_eq!(s, "(1 + 2) * (3 + 4 * 5)");
+
+    println!("\n--- ana: generate, then cata ---");
+    let f: Expr = ana(FactorialSeeds).run(Seed::Expand(5));
+    let v: i64 = cata(Eval).run(f);
+    println!("5! = {v}");
+    assert_eq!(v, 120);
+
+    println!("✓ Recursion schemes demo passed");
+}
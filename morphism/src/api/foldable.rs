@@ -2,12 +2,14 @@
 //!
 //! Eager (consuming) API:
 //! - `x.fold_children_l(op, acc)`
+//! - `x.fold_everything_l(op, acc)` (deep: every leaf of a nested product)
 
 use crate::IntoContainer;
 use crate::{Compile, OpOnce, Tagged, Then};
-use crate::{FoldChildrenLProg, Id, OpLift};
+use crate::{FoldChildrenLProg, FoldEverythingLProg, Id, OpLift};
 
 type FoldChildrenLPipe<Op, Acc> = Then<Id, FoldChildrenLProg<OpLift<Op>, Acc>>;
+type FoldEverythingLPipe<Op, Acc> = Then<Id, FoldEverythingLProg<OpLift<Op>, Acc>>;
 
 pub trait Foldable: IntoContainer + Sized {
     #[inline]
@@ -19,6 +21,16 @@ pub trait Foldable: IntoContainer + Sized {
     {
         self.into_container().fold_children_l(op, acc).run()
     }
+
+    #[inline]
+    fn fold_everything_l<Op, Acc, Out>(self, op: Op, acc: Acc) -> Out
+    where
+        Compile<Tagged<Self::Tag, Self::Payload>>: OpOnce<FoldEverythingLPipe<Op, Acc>>,
+        <Compile<Tagged<Self::Tag, Self::Payload>> as OpOnce<FoldEverythingLPipe<Op, Acc>>>::OutVal:
+            OpOnce<Tagged<Self::Tag, Self::Payload>, OutVal = Out>,
+    {
+        self.into_container().fold_everything_l(op, acc).run()
+    }
 }
 
 impl<T> Foldable for T where T: IntoContainer {}
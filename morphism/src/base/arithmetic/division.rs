@@ -0,0 +1,110 @@
+//! Division operations for type-level numbers (quotient and remainder).
+
+use core::marker::PhantomData;
+
+use crate::base::arithmetic::multiplication::PushLsb;
+use crate::base::arithmetic::subtraction::Sub;
+use crate::base::boolean::{False, True};
+use crate::base::num::nat::{UInt, UTerm, B0, B1};
+use crate::base::order::relational::GeOp;
+use crate::kit::combinators::{FstOp, SndOp};
+use crate::kit::op::{OpTy, OpTyOut};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Public API
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `Lhs -> (Lhs / Rhs, Lhs % Rhs)`; defined for nonzero `Rhs` only.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DivRem<Rhs>(PhantomData<fn() -> Rhs>);
+
+/// `Lhs -> Lhs / Rhs` (the quotient of `DivRem<Rhs>`).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Div<Rhs>(PhantomData<fn() -> Rhs>);
+
+/// `Lhs -> Lhs % Rhs` (the remainder of `DivRem<Rhs>`).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rem<Rhs>(PhantomData<fn() -> Rhs>);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Implementation details
+// ─────────────────────────────────────────────────────────────────────────────
+
+// One long-division step, selected by `R >= Rhs` (`Self` input is `True` / `False`):
+// - `True`: quotient bit 1, remainder `R - Rhs`
+// - `False`: quotient bit 0, remainder `R`
+//
+// Note: `pub` because it appears in `DivRem<Rhs>::OutTy`; it is an implementation detail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(clippy::type_complexity)] // PhantomData with tuple is intentionally complex for type-level encoding
+pub struct DivRemPick<Rhs, Q, R>(PhantomData<fn() -> (Rhs, Q, R)>);
+
+impl<Rhs, Q, R> Default for DivRemPick<Rhs, Q, R> {
+    #[inline]
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Rhs, Q, R> OpTy<True> for DivRemPick<Rhs, Q, R>
+where
+    PushLsb<B1>: OpTy<Q>,
+    Sub<Rhs>: OpTy<R>,
+{
+    type OutTy = (OpTyOut<PushLsb<B1>, Q>, OpTyOut<Sub<Rhs>, R>);
+}
+
+impl<Rhs, Q, R> OpTy<False> for DivRemPick<Rhs, Q, R>
+where
+    PushLsb<B0>: OpTy<Q>,
+{
+    type OutTy = (OpTyOut<PushLsb<B0>, Q>, R);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Type-level long division for **binary** naturals (MSB-first over `Lhs`'s bits).
+// ─────────────────────────────────────────────────────────────────────────────
+
+// Type-level helper aliases (readability): quotient / remainder of the higher bits `T`, and the
+// partial remainder once bit `B` is shifted in.
+type QuotOf<D, T> = OpTyOut<FstOp, OpTyOut<DivRem<D>, T>>;
+type PartialRem<D, B, T> = OpTyOut<PushLsb<B>, OpTyOut<SndOp, OpTyOut<DivRem<D>, T>>>;
+type StepOut<D, B, T> = OpTyOut<
+    DivRemPick<D, QuotOf<D, T>, PartialRem<D, B, T>>,
+    OpTyOut<GeOp<D>, PartialRem<D, B, T>>,
+>;
+
+// 0 / D = 0 rem 0
+impl<DB, DT> OpTy<UTerm> for DivRem<UInt<DB, DT>> {
+    type OutTy = (UTerm, UTerm);
+}
+
+// (2T + B) / D: divide `T`, shift `B` into the remainder, then subtract `D` if it fits.
+impl<B, T, DB, DT> OpTy<UInt<B, T>> for DivRem<UInt<DB, DT>>
+where
+    DivRem<UInt<DB, DT>>: OpTy<T>,
+    FstOp: OpTy<OpTyOut<DivRem<UInt<DB, DT>>, T>>,
+    SndOp: OpTy<OpTyOut<DivRem<UInt<DB, DT>>, T>>,
+    PushLsb<B>: OpTy<OpTyOut<SndOp, OpTyOut<DivRem<UInt<DB, DT>>, T>>>,
+    GeOp<UInt<DB, DT>>: OpTy<PartialRem<UInt<DB, DT>, B, T>>,
+    DivRemPick<UInt<DB, DT>, QuotOf<UInt<DB, DT>, T>, PartialRem<UInt<DB, DT>, B, T>>:
+        OpTy<OpTyOut<GeOp<UInt<DB, DT>>, PartialRem<UInt<DB, DT>, B, T>>>,
+{
+    type OutTy = StepOut<UInt<DB, DT>, B, T>;
+}
+
+impl<Lhs, Rhs> OpTy<Lhs> for Div<Rhs>
+where
+    DivRem<Rhs>: OpTy<Lhs>,
+    FstOp: OpTy<OpTyOut<DivRem<Rhs>, Lhs>>,
+{
+    type OutTy = OpTyOut<FstOp, OpTyOut<DivRem<Rhs>, Lhs>>;
+}
+
+impl<Lhs, Rhs> OpTy<Lhs> for Rem<Rhs>
+where
+    DivRem<Rhs>: OpTy<Lhs>,
+    SndOp: OpTy<OpTyOut<DivRem<Rhs>, Lhs>>,
+{
+    type OutTy = OpTyOut<SndOp, OpTyOut<DivRem<Rhs>, Lhs>>;
+}
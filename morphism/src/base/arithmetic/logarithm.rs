@@ -0,0 +1,32 @@
+//! Base-2 logarithm for type-level numbers.
+
+use crate::base::arithmetic::addition::Add;
+use crate::base::num::nat::{UInt, UTerm, B1};
+use crate::base::num::U1;
+use crate::kit::op::{OpTy, OpTyOut};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Public API
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `n -> floor(log2(n))`; defined for canonical `n >= 1` only (the index of the top bit).
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Log2;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Type-level log2 for **binary** naturals (count the bits above the top one).
+// ─────────────────────────────────────────────────────────────────────────────
+
+// log2(1) = 0
+impl OpTy<UInt<B1, UTerm>> for Log2 {
+    type OutTy = UTerm;
+}
+
+// log2(2T + B) = log2(T) + 1, for T >= 1
+impl<B, B2, T> OpTy<UInt<B, UInt<B2, T>>> for Log2
+where
+    Log2: OpTy<UInt<B2, T>>,
+    Add<U1>: OpTy<OpTyOut<Log2, UInt<B2, T>>>,
+{
+    type OutTy = OpTyOut<Add<U1>, OpTyOut<Log2, UInt<B2, T>>>;
+}
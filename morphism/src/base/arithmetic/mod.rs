@@ -5,3 +5,157 @@ pub use addition::*;
 
 mod subtraction;
 pub use subtraction::*;
+
+mod multiplication;
+pub use multiplication::*;
+
+mod division;
+pub use division::*;
+
+mod logarithm;
+pub use logarithm::*;
+
+#[cfg(test)]
+mod tests {
+    //! Exhaustive checks over `U0..=U64`, compared against `usize` arithmetic through an
+    //! evaluator that only accepts canonical naturals (no leading `B0` frames).
+
+    use super::{DivRem, Log2, Mul};
+    use crate::base::num::*;
+    use crate::base::order::{MaxOp, MinOp};
+    use crate::kit::op::{OpTy, OpTyOut};
+
+    /// Value of a canonical natural (`UInt<B0, UTerm>` has no impl).
+    trait Canon {
+        const N: usize;
+    }
+
+    impl Canon for UTerm {
+        const N: usize = 0;
+    }
+
+    impl Canon for UInt<B1, UTerm> {
+        const N: usize = 1;
+    }
+
+    impl<B, T> Canon for UInt<B0, UInt<B, T>>
+    where
+        UInt<B, T>: Canon,
+    {
+        const N: usize = 2 * <UInt<B, T>>::N;
+    }
+
+    impl<B, T> Canon for UInt<B1, UInt<B, T>>
+    where
+        UInt<B, T>: Canon,
+    {
+        const N: usize = 2 * <UInt<B, T>>::N + 1;
+    }
+
+    fn n<T: Canon>() -> usize {
+        T::N
+    }
+
+    /// `Lhs / Self` and `Lhs % Self` for nonzero `Self`; nothing to check for `Self = 0`.
+    trait CheckDivRem<Lhs> {
+        fn check(lhs: usize, rhs: usize);
+    }
+
+    impl<Lhs> CheckDivRem<Lhs> for UTerm {
+        fn check(_: usize, _: usize) {}
+    }
+
+    impl<Lhs, B, T, Q: Canon, R: Canon> CheckDivRem<Lhs> for UInt<B, T>
+    where
+        DivRem<UInt<B, T>>: OpTy<Lhs, OutTy = (Q, R)>,
+    {
+        fn check(lhs: usize, rhs: usize) {
+            assert_eq!(
+                (n::<Q>(), n::<R>()),
+                (lhs / rhs, lhs % rhs),
+                "{lhs} divrem {rhs}"
+            );
+        }
+    }
+
+    /// `log2(Self)` for nonzero `Self`; nothing to check for `Self = 0`.
+    trait CheckLog2 {
+        fn check(value: usize);
+    }
+
+    impl CheckLog2 for UTerm {
+        fn check(_: usize) {}
+    }
+
+    impl<B, T> CheckLog2 for UInt<B, T>
+    where
+        Log2: OpTy<UInt<B, T>>,
+        OpTyOut<Log2, UInt<B, T>>: Canon,
+    {
+        fn check(value: usize) {
+            let got = n::<OpTyOut<Log2, UInt<B, T>>>();
+            assert_eq!(got, value.ilog2() as usize, "log2 {value}");
+        }
+    }
+
+    fn check_pair<A, B>(a: usize, b: usize)
+    where
+        Mul<B>: OpTy<A>,
+        OpTyOut<Mul<B>, A>: Canon,
+        MinOp<B>: OpTy<A>,
+        OpTyOut<MinOp<B>, A>: Canon,
+        MaxOp<B>: OpTy<A>,
+        OpTyOut<MaxOp<B>, A>: Canon,
+        B: CheckDivRem<A>,
+    {
+        assert_eq!(n::<OpTyOut<Mul<B>, A>>(), a * b, "{a} * {b}");
+        assert_eq!(n::<OpTyOut<MinOp<B>, A>>(), a.min(b), "min({a}, {b})");
+        assert_eq!(n::<OpTyOut<MaxOp<B>, A>>(), a.max(b), "max({a}, {b})");
+        B::check(a, b);
+    }
+
+    macro_rules! with_nats {
+        ($cb:ident) => {
+            $cb!(
+                U0 => 0, U1 => 1, U2 => 2, U3 => 3, U4 => 4, U5 => 5, U6 => 6, U7 => 7,
+                U8 => 8, U9 => 9, U10 => 10, U11 => 11, U12 => 12, U13 => 13, U14 => 14, U15 => 15,
+                U16 => 16, U17 => 17, U18 => 18, U19 => 19, U20 => 20, U21 => 21, U22 => 22, U23 => 23,
+                U24 => 24, U25 => 25, U26 => 26, U27 => 27, U28 => 28, U29 => 29, U30 => 30, U31 => 31,
+                U32 => 32, U33 => 33, U34 => 34, U35 => 35, U36 => 36, U37 => 37, U38 => 38, U39 => 39,
+                U40 => 40, U41 => 41, U42 => 42, U43 => 43, U44 => 44, U45 => 45, U46 => 46, U47 => 47,
+                U48 => 48, U49 => 49, U50 => 50, U51 => 51, U52 => 52, U53 => 53, U54 => 54, U55 => 55,
+                U56 => 56, U57 => 57, U58 => 58, U59 => 59, U60 => 60, U61 => 61, U62 => 62, U63 => 63,
+                U64 => 64,
+            )
+        };
+    }
+
+    // Cartesian product of the list with itself; the full list rides along as one `tt`.
+    macro_rules! cross {
+        (@rows $all:tt $( $a:ident => $x:literal ),*) => {
+            $( cross!(@row $a => $x; $all); )*
+        };
+        (@row $a:ident => $x:literal; [ $( $b:ident => $y:literal ),* ]) => {
+            $( check_pair::<$a, $b>($x, $y); )*
+        };
+        ($( $a:ident => $x:literal ),* $(,)?) => {
+            cross!(@rows [ $( $a => $x ),* ] $( $a => $x ),*)
+        };
+    }
+
+    macro_rules! unary {
+        ($( $a:ident => $x:literal ),* $(,)?) => {
+            $( <$a as CheckLog2>::check($x); )*
+        };
+    }
+
+    #[test]
+    fn binary_ops_match_usize_up_to_u64() {
+        with_nats!(cross);
+    }
+
+    #[test]
+    fn log2_matches_usize_up_to_u64() {
+        with_nats!(unary);
+    }
+}
@@ -0,0 +1,68 @@
+//! Multiplication operations for type-level numbers.
+
+use core::marker::PhantomData;
+
+use crate::base::arithmetic::addition::Add;
+use crate::base::num::nat::{UInt, UTerm, B0, B1};
+use crate::kit::op::{OpTy, OpTyOut};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Public API
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mul<Rhs>(PhantomData<fn() -> Rhs>);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Implementation details
+// ─────────────────────────────────────────────────────────────────────────────
+
+// Bit shift-in (`2 * n + B`), canonical on zero: `PushLsb<B0>` of `UTerm` stays `UTerm`.
+//
+// Note: `pub` because it appears in `Mul<Rhs>::OutTy` and `DivRem<Rhs>::OutTy`; it is an
+// implementation detail of the `base::arithmetic` modules.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PushLsb<B>(PhantomData<fn() -> B>);
+
+impl OpTy<UTerm> for PushLsb<B0> {
+    type OutTy = UTerm;
+}
+
+impl OpTy<UTerm> for PushLsb<B1> {
+    type OutTy = UInt<B1, UTerm>;
+}
+
+impl<Bit, B, T> OpTy<UInt<B, T>> for PushLsb<Bit> {
+    type OutTy = UInt<Bit, UInt<B, T>>;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Type-level multiplication for **binary** naturals (shift-and-add over `Lhs`'s bits).
+// ─────────────────────────────────────────────────────────────────────────────
+
+// Type-level helper alias (readability): `2 * (T * Rhs)`.
+type DoubledProduct<Rhs, T> = OpTyOut<PushLsb<B0>, OpTyOut<Mul<Rhs>, T>>;
+
+// 0 * Rhs = 0
+impl<Rhs> OpTy<UTerm> for Mul<Rhs> {
+    type OutTy = UTerm;
+}
+
+// (2T) * Rhs = 2 (T * Rhs)
+impl<T, Rhs> OpTy<UInt<B0, T>> for Mul<Rhs>
+where
+    Mul<Rhs>: OpTy<T>,
+    PushLsb<B0>: OpTy<OpTyOut<Mul<Rhs>, T>>,
+{
+    type OutTy = DoubledProduct<Rhs, T>;
+}
+
+// (2T + 1) * Rhs = 2 (T * Rhs) + Rhs (the carry-aware adder does the rest)
+impl<T, Rhs> OpTy<UInt<B1, T>> for Mul<Rhs>
+where
+    Mul<Rhs>: OpTy<T>,
+    PushLsb<B0>: OpTy<OpTyOut<Mul<Rhs>, T>>,
+    Add<Rhs>: OpTy<DoubledProduct<Rhs, T>>,
+{
+    type OutTy = OpTyOut<Add<Rhs>, DoubledProduct<Rhs, T>>;
+}
@@ -1,20 +1,21 @@
-//! Boolean operations (type-level only).
+//! Boolean operations.
 //!
 //! ## Purpose
-//! Provide a minimal set of boolean ops as `OpTy` morphisms: `Not`, `And`, `Or`, `Xor`, `AndNot`.
+//! Provide a minimal set of boolean ops as morphisms: `Not`, `And`, `Or`, `Xor`, `AndNot`.
 //!
 //! ## Invariants
-//! - `OpTy` only (no `OpOnce`).
-//! - No tagging / registry keys (these ops are unreflected).
+//! - `OpTy` on `True` / `False`, and `OpOnce` on their (zero-sized) values with the same output.
+//! - The right operand of binary ops is a type parameter (`And<Rhs>`), built with `Default`.
+//! - Plain `OpTy` / `OpOnce` values, not tagged: the program forms (`not_prog`, `and_prog`, ..)
+//!   are separate `BoolDomain` nodes in `control/`.
 //!
 //! ## Non-goals
-//! - No compiler behavior.
-//! - No value-level boolean evaluation.
+//! - No compiler behavior (reflected boolean programs live in `control/`).
 
 use core::marker::PhantomData;
 
 use super::{False, True};
-use crate::kit::op::{OpTy, OpTyOut};
+use crate::kit::op::{OpOnce, OpTy, OpTyOut};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // NOT
@@ -32,6 +33,24 @@ impl OpTy<False> for Not {
     type OutTy = True;
 }
 
+impl OpOnce<True> for Not {
+    type OutVal = False;
+
+    #[inline]
+    fn run(self, _: True) -> Self::OutVal {
+        False::default()
+    }
+}
+
+impl OpOnce<False> for Not {
+    type OutVal = True;
+
+    #[inline]
+    fn run(self, _: False) -> Self::OutVal {
+        True::default()
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // AND
 // ─────────────────────────────────────────────────────────────────────────────
@@ -48,6 +67,24 @@ impl<Rhs> OpTy<False> for And<Rhs> {
     type OutTy = False;
 }
 
+impl<Rhs: Default> OpOnce<True> for And<Rhs> {
+    type OutVal = Rhs;
+
+    #[inline]
+    fn run(self, _: True) -> Self::OutVal {
+        Rhs::default()
+    }
+}
+
+impl<Rhs> OpOnce<False> for And<Rhs> {
+    type OutVal = False;
+
+    #[inline]
+    fn run(self, lhs: False) -> Self::OutVal {
+        lhs
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // OR
 // ─────────────────────────────────────────────────────────────────────────────
@@ -64,6 +101,24 @@ impl<Rhs> OpTy<False> for Or<Rhs> {
     type OutTy = Rhs;
 }
 
+impl<Rhs> OpOnce<True> for Or<Rhs> {
+    type OutVal = True;
+
+    #[inline]
+    fn run(self, lhs: True) -> Self::OutVal {
+        lhs
+    }
+}
+
+impl<Rhs: Default> OpOnce<False> for Or<Rhs> {
+    type OutVal = Rhs;
+
+    #[inline]
+    fn run(self, _: False) -> Self::OutVal {
+        Rhs::default()
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // XOR
 // ─────────────────────────────────────────────────────────────────────────────
@@ -83,6 +138,27 @@ impl<Rhs> OpTy<False> for Xor<Rhs> {
     type OutTy = Rhs;
 }
 
+impl<Rhs: Default> OpOnce<True> for Xor<Rhs>
+where
+    Not: OpOnce<Rhs>,
+{
+    type OutVal = <Not as OpOnce<Rhs>>::OutVal;
+
+    #[inline]
+    fn run(self, _: True) -> Self::OutVal {
+        Not.run(Rhs::default())
+    }
+}
+
+impl<Rhs: Default> OpOnce<False> for Xor<Rhs> {
+    type OutVal = Rhs;
+
+    #[inline]
+    fn run(self, _: False) -> Self::OutVal {
+        Rhs::default()
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // AND-NOT
 // ─────────────────────────────────────────────────────────────────────────────
@@ -101,3 +177,40 @@ where
 impl<Rhs> OpTy<False> for AndNot<Rhs> {
     type OutTy = False;
 }
+
+impl<Rhs: Default> OpOnce<True> for AndNot<Rhs>
+where
+    Not: OpOnce<Rhs>,
+{
+    type OutVal = <Not as OpOnce<Rhs>>::OutVal;
+
+    #[inline]
+    fn run(self, _: True) -> Self::OutVal {
+        Not.run(Rhs::default())
+    }
+}
+
+impl<Rhs> OpOnce<False> for AndNot<Rhs> {
+    type OutVal = False;
+
+    #[inline]
+    fn run(self, lhs: False) -> Self::OutVal {
+        lhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kit::op::OpOnce;
+
+    #[test]
+    fn value_level_ops_match_type_level() {
+        let (t, f) = (True::default(), False::default());
+        assert_eq!(Not.run(t), f);
+        assert_eq!(And::<False>::default().run(t), f);
+        assert_eq!(Or::<True>::default().run(f), t);
+        assert_eq!(Xor::<True>::default().run(t), f);
+        assert_eq!(AndNot::<False>::default().run(t), t);
+    }
+}
@@ -1,4 +1,4 @@
-//! Convenience aliases for small binary naturals (`U0..U64`).
+//! Convenience aliases for small binary naturals (`U0..U255`).
 //!
 //! - Encoding is the `UTerm/UInt<B, T>` LSB-first nat syntax.
 //! - These are purely ergonomic aliases; they introduce no new semantics.
@@ -72,3 +72,194 @@ pub type U61 = UInt<B1, U30>; // 61
 pub type U62 = UInt<B0, U31>; // 62
 pub type U63 = UInt<B1, U31>; // 63
 pub type U64 = UInt<B0, U32>; // 64
+pub type U65 = UInt<B1, U32>; // 65
+pub type U66 = UInt<B0, U33>; // 66
+pub type U67 = UInt<B1, U33>; // 67
+pub type U68 = UInt<B0, U34>; // 68
+pub type U69 = UInt<B1, U34>; // 69
+pub type U70 = UInt<B0, U35>; // 70
+pub type U71 = UInt<B1, U35>; // 71
+pub type U72 = UInt<B0, U36>; // 72
+pub type U73 = UInt<B1, U36>; // 73
+pub type U74 = UInt<B0, U37>; // 74
+pub type U75 = UInt<B1, U37>; // 75
+pub type U76 = UInt<B0, U38>; // 76
+pub type U77 = UInt<B1, U38>; // 77
+pub type U78 = UInt<B0, U39>; // 78
+pub type U79 = UInt<B1, U39>; // 79
+pub type U80 = UInt<B0, U40>; // 80
+pub type U81 = UInt<B1, U40>; // 81
+pub type U82 = UInt<B0, U41>; // 82
+pub type U83 = UInt<B1, U41>; // 83
+pub type U84 = UInt<B0, U42>; // 84
+pub type U85 = UInt<B1, U42>; // 85
+pub type U86 = UInt<B0, U43>; // 86
+pub type U87 = UInt<B1, U43>; // 87
+pub type U88 = UInt<B0, U44>; // 88
+pub type U89 = UInt<B1, U44>; // 89
+pub type U90 = UInt<B0, U45>; // 90
+pub type U91 = UInt<B1, U45>; // 91
+pub type U92 = UInt<B0, U46>; // 92
+pub type U93 = UInt<B1, U46>; // 93
+pub type U94 = UInt<B0, U47>; // 94
+pub type U95 = UInt<B1, U47>; // 95
+pub type U96 = UInt<B0, U48>; // 96
+pub type U97 = UInt<B1, U48>; // 97
+pub type U98 = UInt<B0, U49>; // 98
+pub type U99 = UInt<B1, U49>; // 99
+pub type U100 = UInt<B0, U50>; // 100
+pub type U101 = UInt<B1, U50>; // 101
+pub type U102 = UInt<B0, U51>; // 102
+pub type U103 = UInt<B1, U51>; // 103
+pub type U104 = UInt<B0, U52>; // 104
+pub type U105 = UInt<B1, U52>; // 105
+pub type U106 = UInt<B0, U53>; // 106
+pub type U107 = UInt<B1, U53>; // 107
+pub type U108 = UInt<B0, U54>; // 108
+pub type U109 = UInt<B1, U54>; // 109
+pub type U110 = UInt<B0, U55>; // 110
+pub type U111 = UInt<B1, U55>; // 111
+pub type U112 = UInt<B0, U56>; // 112
+pub type U113 = UInt<B1, U56>; // 113
+pub type U114 = UInt<B0, U57>; // 114
+pub type U115 = UInt<B1, U57>; // 115
+pub type U116 = UInt<B0, U58>; // 116
+pub type U117 = UInt<B1, U58>; // 117
+pub type U118 = UInt<B0, U59>; // 118
+pub type U119 = UInt<B1, U59>; // 119
+pub type U120 = UInt<B0, U60>; // 120
+pub type U121 = UInt<B1, U60>; // 121
+pub type U122 = UInt<B0, U61>; // 122
+pub type U123 = UInt<B1, U61>; // 123
+pub type U124 = UInt<B0, U62>; // 124
+pub type U125 = UInt<B1, U62>; // 125
+pub type U126 = UInt<B0, U63>; // 126
+pub type U127 = UInt<B1, U63>; // 127
+pub type U128 = UInt<B0, U64>; // 128
+pub type U129 = UInt<B1, U64>; // 129
+pub type U130 = UInt<B0, U65>; // 130
+pub type U131 = UInt<B1, U65>; // 131
+pub type U132 = UInt<B0, U66>; // 132
+pub type U133 = UInt<B1, U66>; // 133
+pub type U134 = UInt<B0, U67>; // 134
+pub type U135 = UInt<B1, U67>; // 135
+pub type U136 = UInt<B0, U68>; // 136
+pub type U137 = UInt<B1, U68>; // 137
+pub type U138 = UInt<B0, U69>; // 138
+pub type U139 = UInt<B1, U69>; // 139
+pub type U140 = UInt<B0, U70>; // 140
+pub type U141 = UInt<B1, U70>; // 141
+pub type U142 = UInt<B0, U71>; // 142
+pub type U143 = UInt<B1, U71>; // 143
+pub type U144 = UInt<B0, U72>; // 144
+pub type U145 = UInt<B1, U72>; // 145
+pub type U146 = UInt<B0, U73>; // 146
+pub type U147 = UInt<B1, U73>; // 147
+pub type U148 = UInt<B0, U74>; // 148
+pub type U149 = UInt<B1, U74>; // 149
+pub type U150 = UInt<B0, U75>; // 150
+pub type U151 = UInt<B1, U75>; // 151
+pub type U152 = UInt<B0, U76>; // 152
+pub type U153 = UInt<B1, U76>; // 153
+pub type U154 = UInt<B0, U77>; // 154
+pub type U155 = UInt<B1, U77>; // 155
+pub type U156 = UInt<B0, U78>; // 156
+pub type U157 = UInt<B1, U78>; // 157
+pub type U158 = UInt<B0, U79>; // 158
+pub type U159 = UInt<B1, U79>; // 159
+pub type U160 = UInt<B0, U80>; // 160
+pub type U161 = UInt<B1, U80>; // 161
+pub type U162 = UInt<B0, U81>; // 162
+pub type U163 = UInt<B1, U81>; // 163
+pub type U164 = UInt<B0, U82>; // 164
+pub type U165 = UInt<B1, U82>; // 165
+pub type U166 = UInt<B0, U83>; // 166
+pub type U167 = UInt<B1, U83>; // 167
+pub type U168 = UInt<B0, U84>; // 168
+pub type U169 = UInt<B1, U84>; // 169
+pub type U170 = UInt<B0, U85>; // 170
+pub type U171 = UInt<B1, U85>; // 171
+pub type U172 = UInt<B0, U86>; // 172
+pub type U173 = UInt<B1, U86>; // 173
+pub type U174 = UInt<B0, U87>; // 174
+pub type U175 = UInt<B1, U87>; // 175
+pub type U176 = UInt<B0, U88>; // 176
+pub type U177 = UInt<B1, U88>; // 177
+pub type U178 = UInt<B0, U89>; // 178
+pub type U179 = UInt<B1, U89>; // 179
+pub type U180 = UInt<B0, U90>; // 180
+pub type U181 = UInt<B1, U90>; // 181
+pub type U182 = UInt<B0, U91>; // 182
+pub type U183 = UInt<B1, U91>; // 183
+pub type U184 = UInt<B0, U92>; // 184
+pub type U185 = UInt<B1, U92>; // 185
+pub type U186 = UInt<B0, U93>; // 186
+pub type U187 = UInt<B1, U93>; // 187
+pub type U188 = UInt<B0, U94>; // 188
+pub type U189 = UInt<B1, U94>; // 189
+pub type U190 = UInt<B0, U95>; // 190
+pub type U191 = UInt<B1, U95>; // 191
+pub type U192 = UInt<B0, U96>; // 192
+pub type U193 = UInt<B1, U96>; // 193
+pub type U194 = UInt<B0, U97>; // 194
+pub type U195 = UInt<B1, U97>; // 195
+pub type U196 = UInt<B0, U98>; // 196
+pub type U197 = UInt<B1, U98>; // 197
+pub type U198 = UInt<B0, U99>; // 198
+pub type U199 = UInt<B1, U99>; // 199
+pub type U200 = UInt<B0, U100>; // 200
+pub type U201 = UInt<B1, U100>; // 201
+pub type U202 = UInt<B0, U101>; // 202
+pub type U203 = UInt<B1, U101>; // 203
+pub type U204 = UInt<B0, U102>; // 204
+pub type U205 = UInt<B1, U102>; // 205
+pub type U206 = UInt<B0, U103>; // 206
+pub type U207 = UInt<B1, U103>; // 207
+pub type U208 = UInt<B0, U104>; // 208
+pub type U209 = UInt<B1, U104>; // 209
+pub type U210 = UInt<B0, U105>; // 210
+pub type U211 = UInt<B1, U105>; // 211
+pub type U212 = UInt<B0, U106>; // 212
+pub type U213 = UInt<B1, U106>; // 213
+pub type U214 = UInt<B0, U107>; // 214
+pub type U215 = UInt<B1, U107>; // 215
+pub type U216 = UInt<B0, U108>; // 216
+pub type U217 = UInt<B1, U108>; // 217
+pub type U218 = UInt<B0, U109>; // 218
+pub type U219 = UInt<B1, U109>; // 219
+pub type U220 = UInt<B0, U110>; // 220
+pub type U221 = UInt<B1, U110>; // 221
+pub type U222 = UInt<B0, U111>; // 222
+pub type U223 = UInt<B1, U111>; // 223
+pub type U224 = UInt<B0, U112>; // 224
+pub type U225 = UInt<B1, U112>; // 225
+pub type U226 = UInt<B0, U113>; // 226
+pub type U227 = UInt<B1, U113>; // 227
+pub type U228 = UInt<B0, U114>; // 228
+pub type U229 = UInt<B1, U114>; // 229
+pub type U230 = UInt<B0, U115>; // 230
+pub type U231 = UInt<B1, U115>; // 231
+pub type U232 = UInt<B0, U116>; // 232
+pub type U233 = UInt<B1, U116>; // 233
+pub type U234 = UInt<B0, U117>; // 234
+pub type U235 = UInt<B1, U117>; // 235
+pub type U236 = UInt<B0, U118>; // 236
+pub type U237 = UInt<B1, U118>; // 237
+pub type U238 = UInt<B0, U119>; // 238
+pub type U239 = UInt<B1, U119>; // 239
+pub type U240 = UInt<B0, U120>; // 240
+pub type U241 = UInt<B1, U120>; // 241
+pub type U242 = UInt<B0, U121>; // 242
+pub type U243 = UInt<B1, U121>; // 243
+pub type U244 = UInt<B0, U122>; // 244
+pub type U245 = UInt<B1, U122>; // 245
+pub type U246 = UInt<B0, U123>; // 246
+pub type U247 = UInt<B1, U123>; // 247
+pub type U248 = UInt<B0, U124>; // 248
+pub type U249 = UInt<B1, U124>; // 249
+pub type U250 = UInt<B0, U125>; // 250
+pub type U251 = UInt<B1, U125>; // 251
+pub type U252 = UInt<B0, U126>; // 252
+pub type U253 = UInt<B1, U126>; // 253
+pub type U254 = UInt<B0, U127>; // 254
+pub type U255 = UInt<B1, U127>; // 255
@@ -0,0 +1,140 @@
+//! Literal bridge between `usize` and the nat syntax.
+//!
+//! ## Purpose
+//!
+//! Aliases stop at `U64`; larger naturals up to `CONST_NAT_BOUND - 1` (2047) are spelled as
+//! `nat!(n)`, and APIs that prefer plain const generics (`const N: usize`) convert through
+//! `ConstNat<N>`:
+//! - `NatToUsize`: the value of a natural, as an associated `const VALUE: usize`
+//! - `ConstNat<N>` / `ToNat`: the canonical LSB-first `UInt` chain for `N`
+//! - `NatOf<N>`: shorthand for `<ConstNat<N> as ToNat>::Nat`
+//! - `nat!(n)`: a type macro expanding to `NatOf<{ n }>`
+//!
+//! ## Invariants
+//!
+//! - `ToNat` is implemented for every `N < CONST_NAT_BOUND` and always yields the canonical form
+//!   (`NatOf<0>` is `UTerm`, never `UInt<B0, UTerm>`), so results unify with `U0..U255`.
+//! - The table and `CONST_NAT_BOUND` are generated by `build.rs` (`CONST_NAT_BITS`), one impl per
+//!   value; raising the bound by one bit doubles the impl count. Naturals past the bound are
+//!   built with `base::arithmetic` (`Mul`, `Add`) from ones in range.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use morphism::{nat, NatToUsize, U64};
+//!
+//! const _: () = assert!(<nat!(1288) as NatToUsize>::VALUE == 1288);
+//! let _: U64 = <nat!(64)>::default();
+//! ```
+
+use crate::base::num::nat::{UInt, UTerm, B0, B1};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Nat → usize
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Value of a natural as a `usize` constant.
+///
+/// Defined for every `UTerm` / `UInt<_, _>` chain; non-canonical leading `B0` frames add nothing.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a natural",
+    note = "naturals are `UTerm` / `UInt<B0 | B1, _>` chains, e.g. `U0..U255` or `nat!(n)`"
+)]
+pub trait NatToUsize {
+    const VALUE: usize;
+}
+
+impl NatToUsize for UTerm {
+    const VALUE: usize = 0;
+}
+
+impl<T: NatToUsize> NatToUsize for UInt<B0, T> {
+    const VALUE: usize = 2 * T::VALUE;
+}
+
+impl<T: NatToUsize> NatToUsize for UInt<B1, T> {
+    const VALUE: usize = 2 * T::VALUE + 1;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// usize → Nat
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A `usize` lifted to a type, to be mapped onto the nat syntax by `ToNat`.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConstNat<const N: usize>;
+
+/// `ConstNat<N>` → canonical `UInt` chain with value `N`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no nat mapping",
+    note = "`ConstNat<N>` is generated for `N < CONST_NAT_BOUND` (2048)"
+)]
+pub trait ToNat {
+    type Nat: NatToUsize + Default;
+}
+
+/// The canonical natural for `N` (`NatOf<5>` is `U5`).
+pub type NatOf<const N: usize> = <ConstNat<N> as ToNat>::Nat;
+
+/// Type-level natural literal: `nat!(1288)` is the canonical `UInt` chain for 1288.
+///
+/// Accepts any constant `usize` expression below `CONST_NAT_BOUND` (2048); larger values fail
+/// with "`ConstNat<N>` has no nat mapping".
+#[macro_export]
+macro_rules! nat {
+    ($n:expr) => {
+        $crate::NatOf<{ $n }>
+    };
+}
+
+// `CONST_NAT_BOUND` and one impl per value below it, generated by `build.rs`: `ConstNat<n>` is the
+// low bit of `n` shifted in below `NatOf<n / 2>`, so every entry is canonical by construction.
+include!(concat!(env!("OUT_DIR"), "/const_nat_table.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::num::*;
+
+    fn same<T>(_: T, _: T) {}
+
+    #[test]
+    fn nat_macro_matches_aliases() {
+        same(<nat!(0)>::default(), U0::default());
+        same(<nat!(1)>::default(), U1::default());
+        same(<nat!(2 + 3)>::default(), U5::default());
+        same(<nat!(64)>::default(), U64::default());
+    }
+
+    #[test]
+    fn round_trips_through_usize() {
+        assert_eq!(<nat!(1288) as NatToUsize>::VALUE, 1288);
+        assert_eq!(
+            <nat!(CONST_NAT_BOUND - 1) as NatToUsize>::VALUE,
+            CONST_NAT_BOUND - 1
+        );
+        assert_eq!(
+            <UInt<B0, UInt<B1, UInt<B0, UTerm>>> as NatToUsize>::VALUE,
+            2
+        );
+    }
+
+    #[test]
+    fn naturals_past_the_bound_come_from_arithmetic() {
+        type Big = crate::OpTyOut<crate::Mul<nat!(CONST_NAT_BOUND - 1)>, U64>;
+        assert_eq!(<Big as NatToUsize>::VALUE, (CONST_NAT_BOUND - 1) * 64);
+    }
+
+    #[test]
+    fn const_generic_apis_convert_internally() {
+        fn lift<const N: usize>() -> NatOf<N>
+        where
+            ConstNat<N>: ToNat,
+        {
+            Default::default()
+        }
+
+        same(lift::<37>(), <nat!(37)>::default());
+        assert_eq!(<NatOf<37> as NatToUsize>::VALUE, 37);
+    }
+}
@@ -2,8 +2,9 @@
 //!
 //! This module provides:
 //! - **Natural number representation** (`nat.rs`): binary LSB-first encoding (`B0`, `B1`, `UTerm`, `UInt`) and normalization
-//! - **Common naturals aliases** (`aliases.rs`): `U0..U64` convenience aliases
+//! - **Common naturals aliases** (`aliases.rs`): `U0..U255` convenience aliases
 //! - **Predicates** (`predicates.rs`): small numeric predicates (odd/even/zero)
+//! - **Literals** (`literal.rs`): `nat!(n)`, `NatToUsize`, and the `ConstNat<N>` const-generic bridge
 //!
 //! Arithmetic operations live in `base::arithmetic`.
 //!
@@ -17,3 +18,6 @@ pub use aliases::*;
 
 pub mod predicates;
 pub use predicates::*;
+
+mod literal;
+pub use literal::*;
@@ -0,0 +1,55 @@
+//! Type-level minimum / maximum for **binary** naturals.
+//!
+//! Both are a single `LeOp` comparison followed by a selection, so they inherit its domain:
+//! `UTerm` / `UInt<_, _>` on both sides.
+
+use core::marker::PhantomData;
+
+use crate::base::boolean::elim::IfApply;
+use crate::base::num::nat::{UInt, UTerm};
+use crate::base::order::relational::LeOp;
+use crate::kit::combinators::{ConstCOp, IdOp};
+use crate::kit::op::{OpTy, OpTyOut};
+
+/// min(Lhs, Rhs)
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MinOp<Rhs>(PhantomData<fn() -> Rhs>);
+
+/// max(Lhs, Rhs)
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaxOp<Rhs>(PhantomData<fn() -> Rhs>);
+
+// `Lhs <= Rhs` picks `Lhs` for min and `Rhs` for max.
+type MinBranch<Rhs> = IfApply<LeOp<Rhs>, IdOp, ConstCOp<Rhs>>;
+type MaxBranch<Rhs> = IfApply<LeOp<Rhs>, ConstCOp<Rhs>, IdOp>;
+
+// Impls are split on the `Lhs` constructor (like `GeOp` / `GtOp`), so an uninferred `Lhs`
+// never reaches the comparison.
+
+impl<Rhs> OpTy<UTerm> for MinOp<Rhs>
+where
+    MinBranch<Rhs>: OpTy<UTerm>,
+{
+    type OutTy = OpTyOut<MinBranch<Rhs>, UTerm>;
+}
+
+impl<B, T, Rhs> OpTy<UInt<B, T>> for MinOp<Rhs>
+where
+    MinBranch<Rhs>: OpTy<UInt<B, T>>,
+{
+    type OutTy = OpTyOut<MinBranch<Rhs>, UInt<B, T>>;
+}
+
+impl<Rhs> OpTy<UTerm> for MaxOp<Rhs>
+where
+    MaxBranch<Rhs>: OpTy<UTerm>,
+{
+    type OutTy = OpTyOut<MaxBranch<Rhs>, UTerm>;
+}
+
+impl<B, T, Rhs> OpTy<UInt<B, T>> for MaxOp<Rhs>
+where
+    MaxBranch<Rhs>: OpTy<UInt<B, T>>,
+{
+    type OutTy = OpTyOut<MaxBranch<Rhs>, UInt<B, T>>;
+}
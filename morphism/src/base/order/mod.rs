@@ -9,4 +9,7 @@ pub use equality::*;
 pub mod relational;
 pub use relational::*;
 
+mod minmax;
+pub use minmax::*;
+
 mod cmp;
@@ -0,0 +1,91 @@
+//! Variable binding in program syntax: `lam`, `var::<Ix>` and `let_` (de Bruijn indices).
+//!
+//! Programs are point-free; binders name intermediate values so later stages can share them
+//! without `fanout` gymnastics. Bound values live in an **environment**: a cons-list HList of
+//! nested pairs, innermost binding first:
+//!
+//! ```text
+//! (x0, (x1, (x2, ())))   // var::<U0>() = x0, var::<U1>() = x1, ..
+//! ```
+//!
+//! - `lam(body)`: `x ↦ body((x, ()))` (opens a scope with the input as variable 0)
+//! - `let_(bound, body)`: `env ↦ body((bound(env), env))` (pushes a binding)
+//! - `var::<Ix>()`: `env ↦ env[Ix]` (looks a binding up)
+//!
+//! All three lower away into `fst` / `snd` / `fanout` / `const_move` plumbing
+//! (`compiler/lower/desugar/binding.rs`); nothing of the binding layer reaches reify.
+
+use core::marker::PhantomData;
+
+use crate::{fst, snd, then, Fst, Snd, Tagged, Then, UnrollRepeat};
+use crate::{UInt, UTerm, IX_LAM, IX_LET, IX_VAR};
+
+/// High-level program: run `Body` in a fresh environment holding the input as variable 0.
+///
+/// Lowers to `fanout(id, const_move(())) >>> body`.
+pub type Lam<Body> = Tagged<IX_LAM, Body>;
+
+/// High-level program: the environment entry at de Bruijn index `Ix` (`U0` = innermost).
+///
+/// Lowers to `snd` (`Ix` times) `>>> fst`. The input must be an environment (inside `lam` /
+/// `let_`).
+pub type Var<Ix> = Tagged<IX_VAR, PhantomData<fn() -> Ix>>;
+
+/// High-level program: bind `Bound(env)` as variable 0, then run `Body` in the extended
+/// environment.
+///
+/// Payload is `(bound, body)`; lowers to `fanout(bound, id) >>> body`.
+pub type Let<Bound, Body> = Tagged<IX_LET, (Bound, Body)>;
+
+/// Construct a lambda scope: `body` sees the input as `var::<U0>()`.
+#[inline]
+pub const fn lam<Body>(body: Body) -> Lam<Body> {
+    Tagged::new(body)
+}
+
+/// Construct a variable reference by de Bruijn index.
+#[inline]
+pub const fn var<Ix>() -> Var<Ix> {
+    Tagged::new(PhantomData)
+}
+
+/// Construct a let binding: `body` sees `bound`'s result as `var::<U0>()`, and every outer
+/// variable one index further out.
+#[inline]
+pub const fn let_<Bound, Body>(bound: Bound, body: Body) -> Let<Bound, Body> {
+    Tagged::new((bound, body))
+}
+
+/// Environment lookup for a de Bruijn index (`Self`, a canonical natural).
+///
+/// `U0` is `fst`; any other `Ix` drops `Ix` bindings with a balanced `snd` chain (see
+/// `UnrollRepeat`), then takes `fst`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a canonical natural to index an environment with",
+    note = "use `U0..U255`, `nat!(n)` or a normalized `UInt` chain as the de Bruijn index"
+)]
+pub trait EnvLookup {
+    type Out;
+    fn lookup() -> Self::Out;
+}
+
+impl EnvLookup for UTerm {
+    type Out = Fst;
+
+    #[inline]
+    fn lookup() -> Self::Out {
+        fst()
+    }
+}
+
+impl<B, T> EnvLookup for UInt<B, T>
+where
+    UInt<B, T>: UnrollRepeat<Snd>,
+{
+    type Out = Then<<UInt<B, T> as UnrollRepeat<Snd>>::Out, Fst>;
+
+    #[inline]
+    fn lookup() -> Self::Out {
+        then(<UInt<B, T>>::unroll(snd()), fst())
+    }
+}
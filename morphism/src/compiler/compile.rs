@@ -1,8 +1,12 @@
 use core::marker::PhantomData;
 
+#[cfg(feature = "explain")]
+use super::explain::{self, LowerTrace};
 use super::lower::LowerTable;
+use super::optimize::Optimize;
 use super::reify::ReifyTable;
-use crate::OpOnce;
+use super::verify::{verify_kernel, KernelOnly, Verified};
+use crate::{OpOnce, OpTy, OpTyOut};
 
 /// A compiled artifact that is guaranteed to be runnable on `P`.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -21,6 +25,13 @@ impl<P, A> Compiled<P, A> {
     }
 }
 
+impl<P, A> OpTy<P> for Compiled<P, A>
+where
+    A: OpTy<P>,
+{
+    type OutTy = OpTyOut<A, P>;
+}
+
 impl<P, A> OpOnce<P> for Compiled<P, A>
 where
     A: OpOnce<P>,
@@ -33,7 +44,8 @@ where
     }
 }
 
-/// Stable migration entrypoint: compile using the **closed-table** pipeline (closed lowering → closed reify).
+/// Stable migration entrypoint: compile using the **closed-table** pipeline (closed lowering →
+/// kernel-only walk → optimize → closed reify).
 ///
 /// This intentionally fails to compile if `Prog` contains keys not yet supported by the closed tables.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -44,23 +56,70 @@ impl<P> Compile<P> {
     pub const fn new() -> Self {
         Self(PhantomData)
     }
+
+    /// Compile like `run`, and also return the lowering trace (see `LowerTrace`).
+    ///
+    /// Requires the `explain` cargo feature.
+    #[cfg(feature = "explain")]
+    #[allow(clippy::type_complexity)]
+    pub fn explain<Prog>(
+        prog: Prog,
+    ) -> (
+        Compiled<P, <ReifyTable<P> as OpOnce<Optimized<P, Prog>>>::OutVal>,
+        LowerTrace,
+    )
+    where
+        LowerTable<P>: OpOnce<Prog>,
+        <LowerTable<P> as OpOnce<Prog>>::OutVal: KernelOnly,
+        Optimize: OpOnce<Verified<<LowerTable<P> as OpOnce<Prog>>::OutVal>>,
+        ReifyTable<P>: OpOnce<Optimized<P, Prog>>,
+    {
+        let (lowered, trace) = explain::record(|| lower::<P, _>(prog));
+        (compile_lowered::<P, _>(lowered), trace)
+    }
 }
 
+/// The pipeline after lowering (kernel-only walk → optimize → closed reify), shared by
+/// `Compile::run` and `Compile::explain` so the two cannot drift apart.
+#[inline]
+#[allow(clippy::type_complexity)]
+fn compile_lowered<P, Lowered>(
+    lowered: Lowered,
+) -> Compiled<P, <ReifyTable<P> as OpOnce<<Optimize as OpOnce<Verified<Lowered>>>::OutVal>>::OutVal>
+where
+    Lowered: KernelOnly,
+    Optimize: OpOnce<Verified<Lowered>>,
+    ReifyTable<P>: OpOnce<<Optimize as OpOnce<Verified<Lowered>>>::OutVal>,
+{
+    Compiled::new(ReifyTable::<P>::new().run(Optimize.run(verify_kernel(lowered))))
+}
+
+/// Lowered and optimized program: what `Compile<P>` hands to reify (the lowered program has
+/// passed the kernel-only walk, see `compiler/verify`).
+pub type Optimized<P, Prog> =
+    <Optimize as OpOnce<Verified<<LowerTable<P> as OpOnce<Prog>>::OutVal>>>::OutVal;
+
 impl<P, Prog> OpOnce<Prog> for Compile<P>
 where
     LowerTable<P>: OpOnce<Prog>,
-    ReifyTable<P>: OpOnce<<LowerTable<P> as OpOnce<Prog>>::OutVal>,
+    <LowerTable<P> as OpOnce<Prog>>::OutVal: KernelOnly,
+    Optimize: OpOnce<Verified<<LowerTable<P> as OpOnce<Prog>>::OutVal>>,
+    ReifyTable<P>: OpOnce<Optimized<P, Prog>>,
 {
-    type OutVal =
-        Compiled<P, <ReifyTable<P> as OpOnce<<LowerTable<P> as OpOnce<Prog>>::OutVal>>::OutVal>;
+    type OutVal = Compiled<P, <ReifyTable<P> as OpOnce<Optimized<P, Prog>>>::OutVal>;
 
     #[inline]
     fn run(self, prog: Prog) -> Self::OutVal {
-        let lowered = LowerTable::<P>::new().run(prog);
-        let runnable = ReifyTable::<P>::new().run(lowered);
-        Compiled::new(runnable)
+        compile_lowered::<P, _>(lower::<P, _>(prog))
     }
 }
+
+/// Output type of `Prog` run on `P`, computed without building values.
+///
+/// Every reify output implements `OpTy` next to its `OpOnce`, so the compiled artifact can be
+/// evaluated at the type level. Lifted ops (`op_lift(op)`) need their own `OpTy` impl.
+pub type ProgOut<P, Prog> = OpTyOut<<Compile<P> as OpOnce<Prog>>::OutVal, P>;
+
 /// Convenience helper: compile a program using the closed pipeline.
 #[inline]
 pub fn compile<P, Prog>(prog: Prog) -> <Compile<P> as OpOnce<Prog>>::OutVal
@@ -69,3 +128,98 @@ where
 {
     Compile::<P>::new().run(prog)
 }
+
+/// Lower a program without reifying it (the first half of `compile`).
+///
+/// Useful to inspect what lowering did, e.g. `pretty(&lower::<P, _>(prog))`.
+#[inline]
+pub fn lower<P, Prog>(prog: Prog) -> <LowerTable<P> as OpOnce<Prog>>::OutVal
+where
+    LowerTable<P>: OpOnce<Prog>,
+{
+    LowerTable::<P>::new().run(prog)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_type_eq, fold_children_l_prog, map_children_prog, op_lift, then, FoldChildrenLProg,
+        HlistNode, MapChildrenProg, OpLift, Tagged, Then,
+    };
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    impl<T> OpTy<T> for AddTen {
+        type OutTy = T;
+    }
+
+    impl<T: core::ops::Add<Output = T> + From<u8>> OpOnce<T> for AddTen {
+        type OutVal = T;
+        fn run(self, x: T) -> T {
+            x + T::from(10)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Widen;
+
+    impl<T> OpTy<T> for Widen {
+        type OutTy = u64;
+    }
+
+    impl<T: Into<u64>> OpOnce<T> for Widen {
+        type OutVal = u64;
+        fn run(self, x: T) -> u64 {
+            x.into()
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Sum;
+
+    impl<X> OpTy<(u64, X)> for Sum {
+        type OutTy = u64;
+    }
+
+    impl<X: Into<u64>> OpOnce<(u64, X)> for Sum {
+        type OutVal = u64;
+        fn run(self, (acc, x): (u64, X)) -> u64 {
+            acc + x.into()
+        }
+    }
+
+    type H = HlistNode<(u8, u16)>;
+
+    /// Run a compiled program, with the result type named up front.
+    fn run_on<P, Prog>(prog: Prog, input: P) -> ProgOut<P, Prog>
+    where
+        Compile<P>: OpOnce<Prog>,
+        <Compile<P> as OpOnce<Prog>>::OutVal: OpTy<P> + OpOnce<P, OutVal = ProgOut<P, Prog>>,
+    {
+        compile::<P, _>(prog).run(input)
+    }
+
+    #[test]
+    fn prog_out_computes_result_types() {
+        assert_type_eq::<ProgOut<u32, Then<OpLift<AddTen>, OpLift<Widen>>>, u64>();
+        assert_type_eq::<ProgOut<H, MapChildrenProg<OpLift<Widen>>>, HlistNode<(u64, u64)>>();
+        assert_type_eq::<ProgOut<H, FoldChildrenLProg<OpLift<Sum>, u64>>, u64>();
+    }
+
+    #[test]
+    fn prog_out_matches_the_value_level_result() {
+        let prog = then(
+            map_children_prog(op_lift(AddTen)),
+            map_children_prog(op_lift(Widen)),
+        );
+        assert_eq!(
+            run_on::<H, _>(prog, Tagged::new((1, 2))),
+            Tagged::new((11_u64, 12_u64))
+        );
+
+        let prog = fold_children_l_prog(op_lift(Sum), 0_u64);
+        assert_eq!(run_on::<H, _>(prog, Tagged::new((1, 2))), 3);
+    }
+}
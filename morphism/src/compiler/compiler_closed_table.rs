@@ -6,10 +6,7 @@
 use core::marker::PhantomData;
 
 use crate::{HlistFlatGetAt, OpOnce, OpTy};
-use crate::{
-    U0, U1, U10, U11, U12, U13, U14, U15, U16, U17, U18, U19, U2, U20, U21, U22, U23, U3, U4, U5,
-    U6, U7, U8, U9,
-};
+use crate::{UInt, B0, B1, U0, U1};
 
 /// Closed-table selection (flat semantics), **bounded** to a small, explicit index set.
 ///
@@ -19,9 +16,12 @@ use crate::{
 ///   obligation tree and overflow.
 ///
 /// This selector avoids that failure mode by **not having a generic `Ix` impl**. Instead, it provides
-/// concrete impls for a bounded set (`U0..U23`). If `Ix` is not fixed, rustc can't expand the heavy
+/// concrete impls for a bounded set (`U0..U255`). If `Ix` is not fixed, rustc can't expand the heavy
 /// arithmetic/branching machinery, and typechecking stays tractable.
 ///
+/// `declare_domain!`'s key pool per role block is the same `U0..U255`, so every declared key
+/// index is selectable.
+///
 /// Semantics (flat):
 /// - tables are viewed as a flat logical list
 /// - chunked encoding `(E0..E10, Tail)` is treated as `E0..E10 ++ Tail[..]`
@@ -61,28 +61,99 @@ macro_rules! impl_closed_get_at_via_hlist_flat {
     };
 }
 
-// U0..U23: delegate to HlistFlatGetAt with flat semantics.
+// Index set: every nat below `2^(1 + levels)`, generated by bit-level doubling.
+//
+// Starting from `U1 = UInt<B1, UTerm>`, each level prepends one low bit to every nat of the
+// previous level (`n ↦ 2n`, `n ↦ 2n + 1`). Nats are canonical (no leading zeros), so this
+// enumerates each index exactly once, as a concrete type.
+macro_rules! impl_closed_get_at_doubling {
+    ([]; $( $N:ty ),*) => {};
+    ([$_level:tt $( $levels:tt )*]; $( $N:ty ),*) => {
+        $(
+            impl_closed_get_at_via_hlist_flat!(UInt<B0, $N>);
+            impl_closed_get_at_via_hlist_flat!(UInt<B1, $N>);
+        )*
+        impl_closed_get_at_doubling!([$( $levels )*]; $( UInt<B0, $N>, UInt<B1, $N> ),*);
+    };
+}
+
+// U0..U255: delegate to HlistFlatGetAt with flat semantics.
+// To raise the bound, add a level (each level doubles the index set).
 impl_closed_get_at_via_hlist_flat!(U0);
 impl_closed_get_at_via_hlist_flat!(U1);
-impl_closed_get_at_via_hlist_flat!(U2);
-impl_closed_get_at_via_hlist_flat!(U3);
-impl_closed_get_at_via_hlist_flat!(U4);
-impl_closed_get_at_via_hlist_flat!(U5);
-impl_closed_get_at_via_hlist_flat!(U6);
-impl_closed_get_at_via_hlist_flat!(U7);
-impl_closed_get_at_via_hlist_flat!(U8);
-impl_closed_get_at_via_hlist_flat!(U9);
-impl_closed_get_at_via_hlist_flat!(U10);
-impl_closed_get_at_via_hlist_flat!(U11);
-impl_closed_get_at_via_hlist_flat!(U12);
-impl_closed_get_at_via_hlist_flat!(U13);
-impl_closed_get_at_via_hlist_flat!(U14);
-impl_closed_get_at_via_hlist_flat!(U15);
-impl_closed_get_at_via_hlist_flat!(U16);
-impl_closed_get_at_via_hlist_flat!(U17);
-impl_closed_get_at_via_hlist_flat!(U18);
-impl_closed_get_at_via_hlist_flat!(U19);
-impl_closed_get_at_via_hlist_flat!(U20);
-impl_closed_get_at_via_hlist_flat!(U21);
-impl_closed_get_at_via_hlist_flat!(U22);
-impl_closed_get_at_via_hlist_flat!(U23);
+impl_closed_get_at_doubling!([_ _ _ _ _ _ _]; U1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{U23, U24, U35};
+
+    #[test]
+    fn selects_past_the_old_u23_ceiling() {
+        #[rustfmt::skip]
+        let table = (
+            0u8, 1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8,
+            (11u8, 12u8, 13u8, 14u8, 15u8, 16u8, 17u8, 18u8, 19u8, 20u8, 21u8,
+            (22u8, 23u8, 24u8, 25u8, 26u8, 27u8, 28u8, 29u8, 30u8, 31u8, 32u8,
+            (33u8, 34u8, "last"))),
+        );
+
+        assert_eq!(ClosedTableGetAt::<U23>::new().run(table), 23);
+        assert_eq!(ClosedTableGetAt::<U24>::new().run(table), 24);
+        assert_eq!(ClosedTableGetAt::<U35>::new().run(table), "last");
+    }
+
+    /// Fills a chunked table with `0, 1, 2, ..` in flat order.
+    trait Numbered {
+        fn numbered(next: &mut usize) -> Self;
+    }
+
+    impl Numbered for usize {
+        fn numbered(next: &mut usize) -> Self {
+            *next += 1;
+            *next - 1
+        }
+    }
+
+    macro_rules! impl_numbered_tuple {
+        ($( $T:ident ),*) => {
+            impl<$( $T: Numbered ),*> Numbered for ($( $T, )*) {
+                fn numbered(next: &mut usize) -> Self {
+                    ($( $T::numbered(next), )*)
+                }
+            }
+        };
+    }
+
+    impl_numbered_tuple!(A, B, C);
+    impl_numbered_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+    // `chunks!([_ _ ..] Tail)`: one `(usize x 11, ..)` chunk per `_`, ending in `Tail`.
+    macro_rules! chunks {
+        ([] $Tail:ty) => { $Tail };
+        ([_ $( $rest:tt )*] $Tail:ty) => {
+            (
+                usize, usize, usize, usize, usize, usize, usize, usize, usize, usize, usize,
+                chunks!([$( $rest )*] $Tail),
+            )
+        };
+    }
+
+    // 23 chunks of 11 plus a tail of 3: exactly the 256 indices `U0..U255`.
+    type Full = chunks!([_ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _ _] (usize, usize, usize));
+
+    #[test]
+    fn selects_chunk_boundaries_up_to_u255() {
+        let table = Full::numbered(&mut 0);
+
+        assert_eq!(ClosedTableGetAt::<crate::nat!(0)>::new().run(table), 0);
+        assert_eq!(ClosedTableGetAt::<crate::nat!(10)>::new().run(table), 10);
+        assert_eq!(ClosedTableGetAt::<crate::nat!(11)>::new().run(table), 11);
+        assert_eq!(ClosedTableGetAt::<crate::nat!(128)>::new().run(table), 128);
+        assert_eq!(ClosedTableGetAt::<crate::nat!(241)>::new().run(table), 241);
+        assert_eq!(ClosedTableGetAt::<crate::nat!(242)>::new().run(table), 242);
+        assert_eq!(ClosedTableGetAt::<crate::nat!(253)>::new().run(table), 253);
+        assert_eq!(ClosedTableGetAt::<crate::nat!(254)>::new().run(table), 254);
+        assert_eq!(ClosedTableGetAt::<crate::nat!(255)>::new().run(table), 255);
+    }
+}
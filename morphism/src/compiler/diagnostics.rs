@@ -0,0 +1,475 @@
+//! Compile-time diagnostics: marker traits that turn table misses into readable type errors.
+//!
+//! A program that the closed tables cannot handle fails deep inside the dispatch machinery,
+//! and rustc reports the innermost unsatisfied bound. The traits here sit at the table
+//! boundaries so that bound is one with a message written for it:
+//!
+//! - `TableEntry<K, Name>`: the domain table has a slot for key `K` ("key `IX_SHOUT<2>` has no
+//!   entry in its domain's table")
+//! - `HasLoweringRule` / `HasReifyRule`: never implemented; reached through the
+//!   `NoLoweringRule<K>` / `NoReifyRule<K>` witnesses a table puts in the slot of a key it
+//!   deliberately does not handle ("key `IX_SHOUT<2>` has no lowering rule")
+//! - `RunsOnChild<K, I, C>`: a mapped step runs on child `#I` of type `C` ("`AddTen` cannot run
+//!   on child #3 (type `String`)"), checked when `hlist_map` is reified
+//! - `KernelCheck` (in `compiler/verify`): the kernel-only walk's `R_SYNTAX` role impl here is a
+//!   witness ("lowered program still contains the syntax key `IX_IF<0>`")
+//!
+//! Keys are named by their `KeyIdent::Name` (`IX_SHOUT<2>`: the key as declared, with its index
+//! in its role block), not by their `Key<Domain<..>, UInt<..>, UInt<..>>` encoding.
+//!
+//! Each check is `#[diagnostic::do_not_recommend]` on its blanket impl, so rustc stops at the
+//! marker bound instead of descending into the machinery behind it.
+//!
+//! A fired witness still produces a value, of the uninhabited type `Rejected` (that is what the
+//! failed impl promises). Every later stage passes `Rejected` through (kernel-only walk, optimizer
+//! spine, reify, the compiled op), so the witness message is the only error, not the first of a
+//! wall. `Rejected` is private to this module: no program outside the compiler's own error path
+//! has that type.
+
+use core::marker::PhantomData;
+
+use crate::compiler::compiler_closed_table::ClosedTableGetAt;
+use crate::compiler::optimize::{Peephole, PeepholeHead, ThenOnto, Unchain};
+use crate::compiler::verify::{KernelCheck, KernelOnly, KernelRole, KernelVerdict};
+use crate::{EvalProg, Key, KeyIdent, OpOnce, OpTy, ReifyTable, Tagged, R_SYNTAX};
+
+use self::rejected::Rejected;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Table slots
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A closed table's handler for key `K`, named `Name` (selected by the key's index).
+#[diagnostic::on_unimplemented(
+    message = "key `{Name}` has no entry in its domain's table",
+    label = "the table `{Self}` is shorter than the key's index",
+    note = "`{Name}` is the key as declared in `declare_domain!`, with its index in its role block",
+    note = "write full tables against the `*SemanticsTable` skeleton that `declare_domain!` emits",
+    note = "keys a table does not handle take a `NoLoweringRule<K>` / `NoReifyRule<K>` slot"
+)]
+pub trait TableEntry<K, Name> {
+    type Handler;
+
+    fn entry(self) -> Self::Handler;
+}
+
+#[diagnostic::do_not_recommend]
+impl<Table, D, R, Ix, Name, Handler> TableEntry<Key<D, R, Ix>, Name> for Table
+where
+    ClosedTableGetAt<Ix>: OpOnce<Table, OutVal = Handler>,
+{
+    type Handler = Handler;
+
+    #[inline]
+    fn entry(self) -> Handler {
+        ClosedTableGetAt::<Ix>::new().run(self)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Unsupported keys
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Never implemented: the bound a `NoLoweringRule` slot fails with (on the key's `Name`).
+#[diagnostic::on_unimplemented(
+    message = "key `{Self}` has no lowering rule",
+    label = "this program contains a key its domain does not lower",
+    note = "the domain's lowering table has a `NoLoweringRule` in this key's slot",
+    note = "`{Self}` is the key as declared in `declare_domain!`, with its index in its role block"
+)]
+pub trait HasLoweringRule {}
+
+/// Never implemented: the bound a `NoReifyRule` slot fails with (on the key's `Name`).
+#[diagnostic::on_unimplemented(
+    message = "key `{Self}` has no reify rule",
+    label = "this key reached reify without being lowered away",
+    note = "the domain's reify table has a `NoReifyRule` in this key's slot; frontend keys must lower to kernel keys",
+    note = "`{Self}` is the key as declared in `declare_domain!`, with its index in its role block"
+)]
+pub trait HasReifyRule {}
+
+/// Table slot for a key the domain does not lower (e.g. for this `Input`).
+///
+/// Dispatching to it is a type error naming the key (`HasLoweringRule`); it never runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoLoweringRule<K>(PhantomData<fn() -> K>);
+
+impl<K> NoLoweringRule<K> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<D, R, Ix, Payload> OpOnce<Payload> for NoLoweringRule<Key<D, R, Ix>>
+where
+    D: KeyIdent<Key<D, R, Ix>>,
+    <D as KeyIdent<Key<D, R, Ix>>>::Name: HasLoweringRule,
+{
+    type OutVal = Rejected;
+
+    fn run(self, _: Payload) -> Rejected {
+        unreachable!("`HasLoweringRule` has no impls")
+    }
+}
+
+/// Table slot for a key the domain does not reify (frontend keys in a kernel-only table).
+///
+/// Dispatching to it is a type error naming the key (`HasReifyRule`); it never runs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoReifyRule<K>(PhantomData<fn() -> K>);
+
+impl<K> NoReifyRule<K> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<D, R, Ix, Payload> OpTy<Payload> for NoReifyRule<Key<D, R, Ix>>
+where
+    D: KeyIdent<Key<D, R, Ix>>,
+    <D as KeyIdent<Key<D, R, Ix>>>::Name: HasReifyRule,
+{
+    type OutTy = Rejected;
+}
+
+impl<D, R, Ix, Payload> OpOnce<Payload> for NoReifyRule<Key<D, R, Ix>>
+where
+    D: KeyIdent<Key<D, R, Ix>>,
+    <D as KeyIdent<Key<D, R, Ix>>>::Name: HasReifyRule,
+{
+    type OutVal = Rejected;
+
+    fn run(self, _: Payload) -> Rejected {
+        unreachable!("`HasReifyRule` has no impls")
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// After a witness fired: `Rejected` passes through every stage
+// ─────────────────────────────────────────────────────────────────────────────
+
+mod rejected {
+    /// The program (and compiled op) a fired witness produces; uninhabited, never constructed.
+    ///
+    /// Public only so it can appear in the witnesses' `OutVal`; the module is private, so no
+    /// other crate can name it, implement traits for it, or build programs that contain it.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Rejected {}
+}
+
+// Kernel-only walk: a syntax key fails `KernelCheck` and rejects its subtree; a program a
+// lowering witness already rejected has nothing left to check.
+impl<Name: KernelCheck> KernelRole<Name> for R_SYNTAX {
+    type Verdict = Rejected;
+}
+
+impl KernelVerdict for Rejected {
+    type And<V: KernelVerdict> = Rejected;
+    type Keep<T> = Rejected;
+
+    fn keep<T>(_: T) -> Rejected {
+        unreachable!("`KernelCheck` has no impls")
+    }
+}
+
+impl KernelOnly for Rejected {
+    type Verdict = Rejected;
+}
+
+// Optimize: a `Rejected` spine element absorbs the whole spine.
+impl<Rest> ThenOnto<Rest> for Rejected {
+    type Out = Rejected;
+
+    fn then_onto(self, _: Rest) -> Rejected {
+        match self {}
+    }
+}
+
+impl Peephole for Rejected {
+    type Out = Rejected;
+
+    fn peephole(self) -> Rejected {
+        self
+    }
+}
+
+impl<X> PeepholeHead for (X, Rejected) {
+    type Out = Rejected;
+
+    fn peephole_head(self) -> Rejected {
+        match self.1 {}
+    }
+}
+
+impl Unchain for Rejected {
+    type Out = Rejected;
+
+    fn unchain(self) -> Rejected {
+        self
+    }
+}
+
+// Reify: a `Rejected` program reifies to the `Rejected` op.
+impl<Input> OpOnce<Rejected> for ReifyTable<Input> {
+    type OutVal = Rejected;
+
+    fn run(self, prog: Rejected) -> Rejected {
+        match prog {}
+    }
+}
+
+// The compiled op of a rejected program: runs on anything, never constructed.
+impl<Args> OpTy<Args> for Rejected {
+    type OutTy = Rejected;
+}
+
+impl<Args> OpOnce<Args> for Rejected {
+    type OutVal = Rejected;
+
+    fn run(self, _: Args) -> Rejected {
+        match self {}
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Mapped children
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The step `Tagged<K, Self>` of a map runs on child `#I` (flat index) of type `C`.
+///
+/// Implemented on the step's payload so that a lifted op is named directly (`AddTen`, not
+/// `Tagged<Key<..>, AddTen>`).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot run on child #{I} (type `{C}`)",
+    label = "mapped over children that include `{C}`",
+    note = "every child of a mapped node needs an `OpOnce` impl on the step (lifted ops: `impl OpOnce<{C}> for ..`)"
+)]
+pub trait RunsOnChild<K, const I: usize, C> {}
+
+#[diagnostic::do_not_recommend]
+impl<K, P, const I: usize, C> RunsOnChild<K, I, C> for P where EvalProg<Tagged<K, P>>: OpOnce<C> {}
+
+/// Children past the numbered chunks: the same check as `RunsOnChild`, without the index.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot run on a child past #{LAST} (type `{C}`)",
+    label = "mapped over children that include `{C}`",
+    note = "every child of a mapped node needs an `OpOnce` impl on the step (lifted ops: `impl OpOnce<{C}> for ..`)"
+)]
+pub trait RunsOnLaterChild<K, const LAST: usize, C> {}
+
+#[diagnostic::do_not_recommend]
+impl<K, P, const LAST: usize, C> RunsOnLaterChild<K, LAST, C> for P where
+    EvalProg<Tagged<K, P>>: OpOnce<C>
+{
+}
+
+/// The step `Tagged<K, Self>` runs on every child of `Children` (chunk `CHUNK` of a chunked
+/// HList: flat indices start at `11 * CHUNK`).
+///
+/// Chunks `0..16` (children `#0..#175`) are checked with their index (`RunsOnChild`); chunk `16`
+/// stands for every later chunk, checked through `RunsOnLaterChildren`.
+pub trait RunsOnChildren<K, const CHUNK: usize, Children> {}
+
+impl<K, P, Children> RunsOnChildren<K, 16, Children> for P where P: RunsOnLaterChildren<K, Children> {}
+
+/// The step `Tagged<K, Self>` runs on every child of a chunk past the numbered ones.
+pub trait RunsOnLaterChildren<K, Children> {}
+
+macro_rules! impl_runs_on_later_children {
+    ( $( $A:ident ),* ) => {
+        impl<K, P, $( $A, )*> RunsOnLaterChildren<K, ( $( $A, )* )> for P
+        where
+            $( P: RunsOnLaterChild<K, 175, $A>, )*
+        {
+        }
+    };
+}
+
+impl_runs_on_later_children!();
+impl_runs_on_later_children!(A0);
+impl_runs_on_later_children!(A0, A1);
+impl_runs_on_later_children!(A0, A1, A2);
+impl_runs_on_later_children!(A0, A1, A2, A3);
+impl_runs_on_later_children!(A0, A1, A2, A3, A4);
+impl_runs_on_later_children!(A0, A1, A2, A3, A4, A5);
+impl_runs_on_later_children!(A0, A1, A2, A3, A4, A5, A6);
+impl_runs_on_later_children!(A0, A1, A2, A3, A4, A5, A6, A7);
+impl_runs_on_later_children!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
+impl_runs_on_later_children!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_runs_on_later_children!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+
+impl<K, P, A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, Tail>
+    RunsOnLaterChildren<K, (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, Tail)> for P
+where
+    P: RunsOnLaterChildren<K, (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10)>,
+    P: RunsOnLaterChildren<K, Tail>,
+{
+}
+
+macro_rules! impl_runs_on_children {
+    ( $chunk:literal; $( $A:ident $i:literal ),* ) => {
+        impl<K, P, $( $A, )*> RunsOnChildren<K, $chunk, ( $( $A, )* )> for P
+        where
+            $( P: RunsOnChild<K, { 11 * $chunk + $i }, $A>, )*
+        {
+        }
+    };
+}
+
+macro_rules! impl_runs_on_chunk {
+    ( $chunk:literal => $next:literal ) => {
+        impl_runs_on_children!($chunk;);
+        impl_runs_on_children!($chunk; A0 0);
+        impl_runs_on_children!($chunk; A0 0, A1 1);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2, A3 3);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2, A3 3, A4 4);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2, A3 3, A4 4, A5 5);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2, A3 3, A4 4, A5 5, A6 6);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2, A3 3, A4 4, A5 5, A6 6, A7 7);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2, A3 3, A4 4, A5 5, A6 6, A7 7, A8 8);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2, A3 3, A4 4, A5 5, A6 6, A7 7, A8 8, A9 9);
+        impl_runs_on_children!($chunk; A0 0, A1 1, A2 2, A3 3, A4 4, A5 5, A6 6, A7 7, A8 8, A9 9, A10 10);
+
+        // Arity 12: eleven children and a tail HList (the next chunk).
+        impl<K, P, A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, Tail>
+            RunsOnChildren<K, $chunk, (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, Tail)> for P
+        where
+            P: RunsOnChildren<K, $chunk, (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10)>,
+            P: RunsOnChildren<K, $next, Tail>,
+        {
+        }
+    };
+}
+
+impl_runs_on_chunk!(0 => 1);
+impl_runs_on_chunk!(1 => 2);
+impl_runs_on_chunk!(2 => 3);
+impl_runs_on_chunk!(3 => 4);
+impl_runs_on_chunk!(4 => 5);
+impl_runs_on_chunk!(5 => 6);
+impl_runs_on_chunk!(6 => 7);
+impl_runs_on_chunk!(7 => 8);
+impl_runs_on_chunk!(8 => 9);
+impl_runs_on_chunk!(9 => 10);
+impl_runs_on_chunk!(10 => 11);
+impl_runs_on_chunk!(11 => 12);
+impl_runs_on_chunk!(12 => 13);
+impl_runs_on_chunk!(13 => 14);
+impl_runs_on_chunk!(14 => 15);
+impl_runs_on_chunk!(15 => 16);
+
+/// A map step program runs on every child of the HList `Children` (see `RunsOnChild`).
+pub trait MapsChildren<Children> {}
+
+impl<K, P, Children> MapsChildren<Children> for Tagged<K, P> where P: RunsOnChildren<K, 0, Children> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        compile, map_children_prog, op_lift, shape, then, Ext, HlistNode, LowerDomain, NodeShape,
+        NullaryToken, ReifyDomain, U0, U2,
+    };
+
+    crate::declare_domain! {
+        domain struct CountDomain: Ext<U2, U0>;
+
+        semantics {
+            KX_INCR,
+            // Declared but handled by neither table.
+            KX_TWICE,
+        }
+    }
+
+    impl NodeShape<KX_INCR> for CountDomain {
+        type Shape = shape::Nullary;
+    }
+
+    impl NodeShape<KX_TWICE> for CountDomain {
+        type Shape = shape::Nullary;
+    }
+
+    type IncrKey = KX_INCR;
+    type TwiceKey = KX_TWICE;
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct LowerIncr;
+
+    impl OpOnce<NullaryToken> for LowerIncr {
+        type OutVal = Tagged<IncrKey, NullaryToken>;
+        fn run(self, t: NullaryToken) -> Self::OutVal {
+            Tagged::new(t)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct IncrOp;
+
+    impl OpOnce<u32> for IncrOp {
+        type OutVal = u32;
+        fn run(self, x: u32) -> u32 {
+            x + 1
+        }
+    }
+
+    impl OpOnce<NullaryToken> for IncrOp {
+        type OutVal = IncrOp;
+        fn run(self, _: NullaryToken) -> IncrOp {
+            IncrOp
+        }
+    }
+
+    impl<Input> LowerDomain<Input> for CountDomain {
+        type Table = (LowerIncr, NoLoweringRule<TwiceKey>);
+        fn lower_table() -> Self::Table {
+            (LowerIncr, NoLoweringRule::new())
+        }
+    }
+
+    impl<Input> ReifyDomain<Input> for CountDomain {
+        type Table = (IncrOp, NoReifyRule<TwiceKey>);
+        fn reify_table() -> Self::Table {
+            (IncrOp, NoReifyRule::new())
+        }
+    }
+
+    #[test]
+    fn witness_slots_leave_other_keys_dispatchable() {
+        let incr: Tagged<IncrKey, NullaryToken> = Tagged::new(NullaryToken);
+        assert_eq!(compile::<u32, _>(then(incr, incr)).run(1), 3);
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddOne;
+
+    impl OpOnce<u8> for AddOne {
+        type OutVal = u8;
+        fn run(self, x: u8) -> u8 {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn child_checks_cover_chunked_hlists() {
+        type Tail = (u8, u8, u8);
+        type H = HlistNode<(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, Tail)>;
+        let input = Tagged::new((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, (11, 12, 13)));
+        let out = compile::<H, _>(map_children_prog(op_lift(AddOne))).run(input);
+        assert_eq!(out.0 .10, 11);
+        assert_eq!(out.0 .11, (12, 13, 14));
+    }
+
+    /// Eleven children and a tail chunk.
+    type Chunk<Tail> = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, Tail);
+
+    #[test]
+    fn child_checks_continue_past_the_numbered_chunks() {
+        // 17 full chunks and a last one: children #187.. are checked by `RunsOnLaterChild`.
+        type C4<T> = Chunk<Chunk<Chunk<Chunk<T>>>>;
+        type H = HlistNode<C4<C4<C4<C4<Chunk<(u8, u8)>>>>>>;
+        let _ = compile::<H, _>(map_children_prog(op_lift(AddOne)));
+    }
+}
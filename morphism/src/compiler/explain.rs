@@ -0,0 +1,211 @@
+//! Lowering trace ("explain mode") for `Compile`.
+//!
+//! `Compile::<P>::explain(prog)` compiles as usual and also returns a `LowerTrace`: one
+//! `LowerStep` per closed-table dispatch made while lowering, in call order, with the handler
+//! that was selected and (for dispatchers) the branch it took.
+//!
+//! Recording is a thread-local side channel written by the shared table dispatcher
+//! (`ByTable`). It is only active for the duration of `explain`; plain `compile` records nothing.
+//!
+//! The whole recorder sits behind the `explain` cargo feature. Without it, `enter` / `branch` are
+//! empty and inline away, so dispatch (including the runtime compiles inside `EvalProg` and
+//! `FoldEverythingStepL`) pays nothing for a debugging aid; `Compile::explain` and the trace types
+//! only exist with the feature on.
+
+#[cfg(feature = "explain")]
+use core::cell::RefCell;
+#[cfg(feature = "explain")]
+use core::fmt;
+
+#[cfg(feature = "explain")]
+use crate::short_type_name;
+use crate::NatToUsize;
+
+#[cfg(feature = "explain")]
+/// One closed-table dispatch during lowering.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LowerStep {
+    /// Nesting depth (handlers recurse through `LowerTable`).
+    pub depth: usize,
+    /// Domain type of the dispatched key, e.g. `Domain<U2>`.
+    pub domain: String,
+    /// Key index within the domain's table.
+    pub index: usize,
+    /// Selected table entry, e.g. `LowerMapChildren<HlistNode<(u8, u16)>>`.
+    pub handler: String,
+    /// Dispatcher branch taken by the handler, e.g. `"HlistNode"` (dispatchers only).
+    pub branch: Option<&'static str>,
+    /// The `Input` type lowering was specialised for.
+    pub input: String,
+}
+
+#[cfg(feature = "explain")]
+/// The lowering steps of one `explain` call, in call order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LowerTrace {
+    pub steps: Vec<LowerStep>,
+}
+
+#[cfg(feature = "explain")]
+impl fmt::Display for LowerTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            write!(
+                f,
+                "{:indent$}{}[{}] -> {}",
+                "",
+                step.domain,
+                step.index,
+                step.handler,
+                indent = 2 * step.depth
+            )?;
+            if let Some(branch) = step.branch {
+                write!(f, " (branch: {branch})")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Recorder (thread-local, active only inside `record`; `explain` feature only)
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "explain")]
+#[derive(Default)]
+struct Recorder {
+    trace: LowerTrace,
+    /// Indices (into `trace.steps`) of dispatches that are still running.
+    open: Vec<usize>,
+}
+
+#[cfg(feature = "explain")]
+std::thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "explain")]
+/// Run `f` with recording on; nested calls get their own trace.
+pub(crate) fn record<T>(f: impl FnOnce() -> T) -> (T, LowerTrace) {
+    let outer = RECORDER.with(|r| r.replace(Some(Recorder::default())));
+    let out = f();
+    let recorder = RECORDER.with(|r| r.replace(outer));
+    (out, recorder.map(|r| r.trace).unwrap_or_default())
+}
+
+#[cfg(feature = "explain")]
+/// Closes the step opened by `enter` (on drop, so unwinding keeps the stack consistent).
+pub(crate) struct StepGuard(bool);
+
+#[cfg(feature = "explain")]
+impl Drop for StepGuard {
+    fn drop(&mut self) {
+        if self.0 {
+            RECORDER.with(|r| {
+                if let Some(rec) = r.borrow_mut().as_mut() {
+                    rec.open.pop();
+                }
+            });
+        }
+    }
+}
+
+#[cfg(feature = "explain")]
+/// Open a step for a dispatch of key index `Ix` of domain `D` to `Handler` (no-op unless recording).
+pub(crate) fn enter<Input, D, Ix: NatToUsize, Handler>() -> StepGuard {
+    RECORDER.with(|r| {
+        let mut r = r.borrow_mut();
+        let Some(rec) = r.as_mut() else {
+            return StepGuard(false);
+        };
+        rec.trace.steps.push(LowerStep {
+            depth: rec.open.len(),
+            domain: short_type_name::<D>(),
+            index: Ix::VALUE,
+            handler: short_type_name::<Handler>(),
+            branch: None,
+            input: short_type_name::<Input>(),
+        });
+        rec.open.push(rec.trace.steps.len() - 1);
+        StepGuard(true)
+    })
+}
+
+#[cfg(feature = "explain")]
+/// Record which branch a dispatcher handler took (annotates the innermost open step).
+#[inline]
+pub(crate) fn branch(name: &'static str) {
+    RECORDER.with(|r| {
+        if let Some(rec) = r.borrow_mut().as_mut() {
+            if let Some(&ix) = rec.open.last() {
+                rec.trace.steps[ix].branch = Some(name);
+            }
+        }
+    });
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// No-op hooks (feature off)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Stands in for the recorder's step guard; nothing to close.
+#[cfg(not(feature = "explain"))]
+pub(crate) struct StepGuard;
+
+/// No-op without the `explain` feature (same signature, so the dispatcher has one call site).
+#[cfg(not(feature = "explain"))]
+#[allow(clippy::extra_unused_type_parameters)]
+#[inline(always)]
+pub(crate) fn enter<Input, D, Ix: NatToUsize, Handler>() -> StepGuard {
+    StepGuard
+}
+
+/// No-op without the `explain` feature.
+#[cfg(not(feature = "explain"))]
+#[inline(always)]
+pub(crate) fn branch(_name: &'static str) {}
+
+#[cfg(all(test, feature = "explain"))]
+mod tests {
+    use crate::{map_children_prog, op_lift, Compile, HlistNode, OpOnce, Tagged};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    impl OpOnce<u8> for AddTen {
+        type OutVal = u8;
+        fn run(self, x: u8) -> u8 {
+            x + 10
+        }
+    }
+
+    impl OpOnce<u16> for AddTen {
+        type OutVal = u16;
+        fn run(self, x: u16) -> u16 {
+            x + 10
+        }
+    }
+
+    #[test]
+    fn explain_records_dispatches_and_branches() {
+        type H = HlistNode<(u8, u16)>;
+        let (compiled, trace) = Compile::<H>::explain(map_children_prog(op_lift(AddTen)));
+        assert_eq!(compiled.run(Tagged::new((1, 2))), Tagged::new((11, 12)));
+
+        let first = &trace.steps[0];
+        assert_eq!((first.depth, first.index), (0, 2), "{trace}");
+        assert_eq!(first.domain, "Domain<U2>");
+        assert!(first.handler.starts_with("LowerMapChildren<"), "{trace}");
+        assert_eq!(first.branch, Some("HlistNode"));
+
+        let nested = &trace.steps[1..];
+        assert!(nested.iter().all(|s| s.depth > 0), "{trace}");
+        assert!(
+            nested
+                .iter()
+                .any(|s| s.handler.starts_with("LowerMapHlistNode")),
+            "{trace}"
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Closed-table lowering: Binding domain (de Bruijn variables over a cons-list environment).
+//!
+//! `D_BINDING` is a frontend domain: every key lowers into product plumbing (`fst`, `snd`,
+//! `fanout`, `const_move`), so no `D_BINDING` keys reach reify.
+//!
+//! Current closed lowering coverage in this module:
+//! - `IX_LAM` (U0) (`fanout(id, const_move(())) >>> body`)
+//! - `IX_VAR` (U1) (`snd` chain `>>> fst`, via `EnvLookup`)
+//! - `IX_LET` (U2) (`fanout(bound, id) >>> body`)
+//!
+//! Bodies are lowered against the environment they run on, not the node's input: `(input, ())`
+//! under `lam`, `(bound(env), env)` under `let_`. Frontend nodes inside a body (`if_prog`,
+//! `map_children_prog`, ..) therefore dispatch on the environment type.
+
+use core::marker::PhantomData;
+
+use crate::compiler::lower::{LowerDomain, LowerTable};
+use crate::{const_move, fanout, id, then, ConstMove, EnvLookup, Fanout, Id, Then};
+use crate::{BindingSemanticsTable, Domain, D_BINDING};
+use crate::{Compile, OpOnce, OpTy, ProgOut};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<Input> LowerDomain<Input> for Domain<D_BINDING> {
+    type Table = BindingSemanticsTable<LowerLam<Input>, LowerVar, LowerLet<Input>>;
+
+    #[inline]
+    fn lower_table() -> Self::Table {
+        (LowerLam::new(), LowerVar, LowerLet::new())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U0: IX_LAM
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lowering op for `IX_LAM` payload `body`: open the environment `(input, ())` and lower `body`
+/// against it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerLam<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> LowerLam<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, Body> OpOnce<Body> for LowerLam<Input>
+where
+    LowerTable<(Input, ())>: OpOnce<Body>,
+{
+    type OutVal =
+        Then<Fanout<Id, ConstMove<()>>, <LowerTable<(Input, ())> as OpOnce<Body>>::OutVal>;
+
+    #[inline]
+    fn run(self, body: Body) -> Self::OutVal {
+        then(
+            fanout(id(), const_move(())),
+            LowerTable::<(Input, ())>::new().run(body),
+        )
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U1: IX_VAR
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lowering op for `IX_VAR` payload `PhantomData<Ix>`: the lookup path for `Ix`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerVar;
+
+impl<Ix: EnvLookup> OpOnce<PhantomData<fn() -> Ix>> for LowerVar {
+    type OutVal = Ix::Out;
+
+    #[inline]
+    fn run(self, _: PhantomData<fn() -> Ix>) -> Self::OutVal {
+        Ix::lookup()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U2: IX_LET
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lowering op for `IX_LET` payload `(bound, body)`: push `bound(env)` onto `env`.
+///
+/// `bound` is lowered against `env` (the node's input) and `body` against the extended
+/// environment `(ProgOut<env, bound>, env)`, so lifted ops in `bound` need an `OpTy` impl.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerLet<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> LowerLet<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, Bound, Body> OpOnce<(Bound, Body)> for LowerLet<Input>
+where
+    LowerTable<Input>: OpOnce<Bound>,
+    Compile<Input>: OpOnce<Bound>,
+    <Compile<Input> as OpOnce<Bound>>::OutVal: OpTy<Input>,
+    LowerTable<(ProgOut<Input, Bound>, Input)>: OpOnce<Body>,
+{
+    type OutVal = Then<
+        Fanout<<LowerTable<Input> as OpOnce<Bound>>::OutVal, Id>,
+        <LowerTable<(ProgOut<Input, Bound>, Input)> as OpOnce<Body>>::OutVal,
+    >;
+
+    #[inline]
+    fn run(self, (bound, body): (Bound, Body)) -> Self::OutVal {
+        then(
+            fanout(LowerTable::<Input>::new().run(bound), id()),
+            LowerTable::<(ProgOut<Input, Bound>, Input)>::new().run(body),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fanout, lam, let_, lower, op_lift, pretty, then, var, Compile, OpOnce, OpTy};
+    use crate::{id, if_prog, map_children_prog, HlistNode, Tagged};
+    use crate::{False, OpLift, Then, True, Var, U0, U1, U2};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Inc;
+
+    impl OpOnce<i32> for Inc {
+        type OutVal = i32;
+        fn run(self, x: i32) -> Self::OutVal {
+            x + 1
+        }
+    }
+
+    impl OpTy<i32> for Inc {
+        type OutTy = i32;
+    }
+
+    // Inside a scope every program sees the environment: `inc_var0` is `var[U0] + 1`.
+    fn inc_var0() -> Then<Var<U0>, OpLift<Inc>> {
+        then(var::<U0>(), op_lift(Inc))
+    }
+
+    #[test]
+    fn let_shares_an_intermediate_value_between_later_stages() {
+        // x ↦ let y = x + 1 in (y, x)
+        let prog = lam(let_(inc_var0(), fanout(var::<U0>(), var::<U1>())));
+        assert_eq!(
+            pretty(&prog).to_string(),
+            "lam(let(var[U0] >>> lift(Inc), fanout(var[U0], var[U1])))"
+        );
+        assert_eq!(
+            pretty(&lower::<i32, _>(prog)).to_string(),
+            "fanout(id, const_move(())) >>> fanout(fst >>> lift(Inc), id) >>> fanout(fst, snd >>> fst)"
+        );
+        assert_eq!(Compile::<i32>::new().run(prog).run(41), (42, 41));
+    }
+
+    #[test]
+    fn var_indexes_the_environment_innermost_first() {
+        // x ↦ let y = x + 1 in let z = y + 1 in (x, z)
+        let prog = lam(let_(
+            inc_var0(),
+            let_(inc_var0(), fanout(var::<U2>(), var::<U0>())),
+        ));
+        assert_eq!(Compile::<i32>::new().run(prog).run(1), (1, 3));
+    }
+
+    /// `True` on an environment (a pair), `False` on a node.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct IsEnv;
+
+    impl<X, Rest> OpTy<(X, Rest)> for IsEnv {
+        type OutTy = True;
+    }
+
+    impl<C> OpTy<HlistNode<C>> for IsEnv {
+        type OutTy = False;
+    }
+
+    #[test]
+    fn bodies_are_lowered_against_the_environment() {
+        // The body runs on `(node, ())`: the `if` keeps `var[U0]`, and `map_children_prog` (which
+        // could not run on the environment) is dropped with the other arm.
+        let prog = lam(if_prog(IsEnv, var::<U0>(), map_children_prog(op_lift(Inc))));
+        assert_eq!(
+            pretty(&lower::<HlistNode<(i32, i32)>, _>(prog)).to_string(),
+            "fanout(id, const_move(())) >>> fst"
+        );
+        let out = Compile::<HlistNode<(i32, i32)>>::new()
+            .run(prog)
+            .run(Tagged::new((1, 2)));
+        assert_eq!(out, Tagged::new((1, 2)));
+
+        // Under `let_`, the body's environment holds the bound value in front.
+        let prog = lam(let_(
+            var::<U0>(),
+            if_prog(IsEnv, fanout(var::<U0>(), var::<U1>()), id()),
+        ));
+        let out = Compile::<HlistNode<(i32, i32)>>::new()
+            .run(prog)
+            .run(Tagged::new((1, 2)));
+        assert_eq!(out, (Tagged::new((1, 2)), Tagged::new((1, 2))));
+    }
+}
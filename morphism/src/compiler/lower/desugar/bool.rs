@@ -0,0 +1,258 @@
+//! Closed-table lowering: Bool domain (boolean programs, evaluated at compile time).
+//!
+//! Every boolean program lowers to a constant: `const_move(True)` or `const_move(False)`.
+//! Children are lowered first and read back through `LoweredBool`; the connectives apply the
+//! type-level ops in `base::boolean` (`Not`, `And`, `Or`, `Xor`).
+//!
+//! Current closed lowering coverage in this module:
+//! - `IX_TRUE` / `IX_FALSE` (syntax literals; routed directly, not through the table)
+//! - `IX_PRED` (U0) (evaluates the predicate on `Input`)
+//! - `IX_NOT` (U1), `IX_AND` (U2), `IX_OR` (U3), `IX_XOR` (U4)
+
+use core::marker::PhantomData;
+
+use crate::compiler::lower::{LowerDomain, LowerTable};
+use crate::{const_move, ConstMove, False, LoweredBool, OpOnce, OpTy, OpTyOut, True};
+use crate::{And, Not, Or, Xor};
+use crate::{BoolSemanticsTable, Domain, D_BOOL};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Literals: `True` / `False` are syntax nodes (R_SYNTAX), outside the semantics routing
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<Input> OpOnce<True> for LowerTable<Input> {
+    type OutVal = ConstMove<True>;
+
+    #[inline]
+    fn run(self, lit: True) -> Self::OutVal {
+        const_move(lit)
+    }
+}
+
+impl<Input> OpOnce<False> for LowerTable<Input> {
+    type OutVal = ConstMove<False>;
+
+    #[inline]
+    fn run(self, lit: False) -> Self::OutVal {
+        const_move(lit)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<Input> LowerDomain<Input> for Domain<D_BOOL> {
+    type Table = LowerTableBool<Input>;
+
+    #[inline]
+    fn lower_table() -> Self::Table {
+        (
+            LowerPred::new(),
+            LowerNot::new(),
+            LowerAnd::new(),
+            LowerOr::new(),
+            LowerXor::new(),
+        )
+    }
+}
+
+/// Lowering table for BoolDomain semantics keys (U0..U4).
+pub type LowerTableBool<Input> = BoolSemanticsTable<
+    LowerPred<Input>, // U0: IX_PRED
+    LowerNot<Input>,  // U1: IX_NOT
+    LowerAnd<Input>,  // U2: IX_AND
+    LowerOr<Input>,   // U3: IX_OR
+    LowerXor<Input>,  // U4: IX_XOR
+>;
+
+/// Value of a lowered boolean child program.
+type LoweredValue<Input, P> = <<LowerTable<Input> as OpOnce<P>>::OutVal as LoweredBool>::Value;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U0: IX_PRED
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lowering op for `IX_PRED` payload: `const_move(Pred(Input))`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerPred<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> LowerPred<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, Pred> OpOnce<Pred> for LowerPred<Input>
+where
+    Pred: OpTy<Input>,
+    OpTyOut<Pred, Input>: Default,
+    ConstMove<OpTyOut<Pred, Input>>: LoweredBool,
+{
+    type OutVal = ConstMove<OpTyOut<Pred, Input>>;
+
+    #[inline]
+    fn run(self, _: Pred) -> Self::OutVal {
+        const_move(Default::default())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U1: IX_NOT
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lowering op for `IX_NOT` payload: `const_move(!lower(p))`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerNot<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> LowerNot<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, P> OpOnce<P> for LowerNot<Input>
+where
+    LowerTable<Input>: OpOnce<P>,
+    <LowerTable<Input> as OpOnce<P>>::OutVal: LoweredBool,
+    Not: OpTy<LoweredValue<Input, P>>,
+    OpTyOut<Not, LoweredValue<Input, P>>: Default,
+{
+    type OutVal = ConstMove<OpTyOut<Not, LoweredValue<Input, P>>>;
+
+    #[inline]
+    fn run(self, _: P) -> Self::OutVal {
+        const_move(Default::default())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U2..U4: IX_AND, IX_OR, IX_XOR
+// ─────────────────────────────────────────────────────────────────────────────
+
+macro_rules! lower_bool_binary {
+    ( $( $(#[$doc:meta])* $Lower:ident => $Op:ident ),+ $(,)? ) => {
+        $(
+            $(#[$doc])*
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+            pub struct $Lower<Input>(PhantomData<fn() -> Input>);
+
+            impl<Input> $Lower<Input> {
+                #[inline]
+                pub const fn new() -> Self {
+                    Self(PhantomData)
+                }
+            }
+
+            impl<Input, P, Q> OpOnce<(P, Q)> for $Lower<Input>
+            where
+                LowerTable<Input>: OpOnce<P> + OpOnce<Q>,
+                <LowerTable<Input> as OpOnce<P>>::OutVal: LoweredBool,
+                <LowerTable<Input> as OpOnce<Q>>::OutVal: LoweredBool,
+                $Op<LoweredValue<Input, Q>>: OpTy<LoweredValue<Input, P>>,
+                OpTyOut<$Op<LoweredValue<Input, Q>>, LoweredValue<Input, P>>: Default,
+            {
+                type OutVal =
+                    ConstMove<OpTyOut<$Op<LoweredValue<Input, Q>>, LoweredValue<Input, P>>>;
+
+                #[inline]
+                fn run(self, _: (P, Q)) -> Self::OutVal {
+                    const_move(Default::default())
+                }
+            }
+        )+
+    };
+}
+
+lower_bool_binary!(
+    /// Lowering op for `IX_AND` payload: `const_move(lower(p) & lower(q))`.
+    LowerAnd => And,
+    /// Lowering op for `IX_OR` payload: `const_move(lower(p) | lower(q))`.
+    LowerOr => Or,
+    /// Lowering op for `IX_XOR` payload: `const_move(lower(p) ^ lower(q))`.
+    LowerXor => Xor,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        and_prog, assert_type_eq, false_prog, holds, id, if_prog, lower, map_children_prog,
+        not_prog, op_lift, or_prog, pred_prog, true_prog, xor_prog, BoolOf, Compile, HlistIsEmpty,
+        HlistNode, KeyIsOp, NewTypeNode, NotProg, OpOnce, OpTy, PredProg, ProgOut, Tagged,
+        IX_HLISTNODE, IX_NEWTYPENODE,
+    };
+    use crate::{ConstMove, False, True};
+
+    type IsHlist = KeyIsOp<IX_HLISTNODE>;
+
+    #[test]
+    fn boolean_programs_lower_to_constants() {
+        let is_empty_hlist = and_prog(pred_prog(IsHlist::default()), pred_prog(HlistIsEmpty));
+        let _: ConstMove<True> = lower::<HlistNode<()>, _>(is_empty_hlist);
+        let _: ConstMove<False> = lower::<HlistNode<(u8,)>, _>(is_empty_hlist);
+
+        type NotNewType = NotProg<PredProg<KeyIsOp<IX_NEWTYPENODE>>>;
+        assert_type_eq::<BoolOf<NewTypeNode<u8>, NotNewType>, False>();
+        assert_type_eq::<BoolOf<HlistNode<()>, NotNewType>, True>();
+        let _: ConstMove<True> = lower::<u8, _>(or_prog(false_prog(), true_prog()));
+        let _: ConstMove<False> = lower::<u8, _>(xor_prog(true_prog(), true_prog()));
+
+        let compiled = Compile::<HlistNode<()>>::new().run(is_empty_hlist);
+        assert_type_eq::<ProgOut<HlistNode<()>, ConstMove<True>>, True>();
+        assert_eq!(compiled.run(Tagged::new(())), True::default());
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Inc;
+
+    impl OpOnce<i32> for Inc {
+        type OutVal = i32;
+        fn run(self, x: i32) -> Self::OutVal {
+            x + 1
+        }
+    }
+
+    impl OpTy<i32> for Inc {
+        type OutTy = i32;
+    }
+
+    #[test]
+    fn boolean_programs_drive_if_prog() {
+        let has_children = and_prog(
+            pred_prog(IsHlist::default()),
+            not_prog(pred_prog(HlistIsEmpty)),
+        );
+        let prog = if_prog(holds(&has_children), map_children_prog(op_lift(Inc)), id());
+
+        let out = Compile::<HlistNode<(i32, i32)>>::new()
+            .run(prog)
+            .run(Tagged::new((1, 2)));
+        assert_eq!(out, Tagged::new((2, 3)));
+        let out = Compile::<HlistNode<()>>::new()
+            .run(prog)
+            .run(Tagged::new(()));
+        assert_eq!(out, Tagged::new(()));
+    }
+
+    #[test]
+    fn holds_leaves_the_program_to_its_owner() {
+        use core::cell::Cell;
+
+        struct CountsDrops<'a>(&'a Cell<u32>);
+
+        impl Drop for CountsDrops<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let prog = pred_prog(CountsDrops(&drops));
+        let _ = holds(&prog);
+        assert_eq!(drops.get(), 0);
+        drop(prog);
+        assert_eq!(drops.get(), 1);
+    }
+}
@@ -0,0 +1,227 @@
+//! Closed-table lowering: Control domain (static branching).
+//!
+//! `D_CONTROL` is a frontend domain: every key lowers away (no `D_CONTROL` keys reach reify).
+//!
+//! Current closed lowering coverage in this module:
+//! - `IX_IF` (U0) (evaluates the predicate on `Input`; lowers the selected arm only)
+//! - `IX_REPEAT` (U1) (lowers the body once; unrolls it into a balanced `ThenBalanced` tree)
+
+use core::marker::PhantomData;
+
+use crate::compiler::explain;
+use crate::compiler::lower::{LowerDomain, LowerTable};
+use crate::{ControlSemanticsTable, Domain, D_CONTROL};
+use crate::{False, OpOnce, OpTy, OpTyOut, True, UnrollRepeat};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<Input> LowerDomain<Input> for Domain<D_CONTROL> {
+    type Table = ControlSemanticsTable<LowerIf<Input>, LowerRepeat<Input>>;
+
+    #[inline]
+    fn lower_table() -> Self::Table {
+        (LowerIf::new(), LowerRepeat::new())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U0: IX_IF (dispatcher)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lowering op for `IX_IF` payload `(pred, then_prog, else_prog)`.
+///
+/// Lowered program:
+/// - `lower(then_prog)` if `Pred: OpTy<Input>` yields `True`
+/// - `lower(else_prog)` if it yields `False`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerIf<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> LowerIf<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, Pred, T, E> OpOnce<(Pred, T, E)> for LowerIf<Input>
+where
+    Pred: OpTy<Input>,
+    OpTyOut<Pred, Input>: LowerBranch<Input, T, E>,
+{
+    type OutVal = <OpTyOut<Pred, Input> as LowerBranch<Input, T, E>>::Out;
+
+    #[inline]
+    fn run(self, (_, then_prog, else_prog): (Pred, T, E)) -> Self::OutVal {
+        <OpTyOut<Pred, Input>>::lower_branch(then_prog, else_prog)
+    }
+}
+
+/// Lower the arm selected by a predicate result (`Self = True / False`).
+pub trait LowerBranch<Input, T, E> {
+    type Out;
+    fn lower_branch(then_prog: T, else_prog: E) -> Self::Out;
+}
+
+impl<Input, T, E> LowerBranch<Input, T, E> for True
+where
+    LowerTable<Input>: OpOnce<T>,
+{
+    type Out = <LowerTable<Input> as OpOnce<T>>::OutVal;
+
+    #[inline]
+    fn lower_branch(then_prog: T, _: E) -> Self::Out {
+        explain::branch("then");
+        LowerTable::<Input>::new().run(then_prog)
+    }
+}
+
+impl<Input, T, E> LowerBranch<Input, T, E> for False
+where
+    LowerTable<Input>: OpOnce<E>,
+{
+    type Out = <LowerTable<Input> as OpOnce<E>>::OutVal;
+
+    #[inline]
+    fn lower_branch(_: T, else_prog: E) -> Self::Out {
+        explain::branch("else");
+        LowerTable::<Input>::new().run(else_prog)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U1: IX_REPEAT
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lowering op for `IX_REPEAT` payload `(prog, n)`.
+///
+/// Lowered program: `N::unroll(lower(prog))`, i.e. `N` copies of the lowered body in a balanced
+/// `ThenBalanced` tree (`id` for `N = 0`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerRepeat<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> LowerRepeat<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, P, N> OpOnce<(P, N)> for LowerRepeat<Input>
+where
+    LowerTable<Input>: OpOnce<P>,
+    N: UnrollRepeat<<LowerTable<Input> as OpOnce<P>>::OutVal>,
+{
+    type OutVal = <N as UnrollRepeat<<LowerTable<Input> as OpOnce<P>>::OutVal>>::Out;
+
+    #[inline]
+    fn run(self, (prog, _): (P, N)) -> Self::OutVal {
+        N::unroll(LowerTable::<Input>::new().run(prog))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        id, if_prog, lower, map_children_prog, op_lift, optimize, pretty, Compile, HlistNode,
+        IfProg, KeyIsOp, MapChildrenProg, NewTypeNode, OpLift, OpOnce, OpTy, Tagged, IX_HLISTNODE,
+    };
+    use crate::{repeat_n, then, then_balanced, Id, ThenBalanced, U0, U4, U5};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Inc;
+
+    impl OpOnce<i32> for Inc {
+        type OutVal = i32;
+        fn run(self, x: i32) -> Self::OutVal {
+            x + 1
+        }
+    }
+
+    impl OpTy<i32> for Inc {
+        type OutTy = i32;
+    }
+
+    type MapIfHlist = IfProg<KeyIsOp<IX_HLISTNODE>, MapChildrenProg<OpLift<Inc>>, Id>;
+
+    fn map_if_hlist() -> MapIfHlist {
+        if_prog(KeyIsOp::default(), map_children_prog(op_lift(Inc)), id())
+    }
+
+    #[test]
+    fn if_prog_lowers_to_the_arm_selected_by_the_input_type() {
+        assert_eq!(
+            pretty(&lower::<NewTypeNode<i32>, _>(map_if_hlist())).to_string(),
+            "id"
+        );
+        assert_eq!(
+            pretty(&lower::<HlistNode<(i32, i32)>, _>(map_if_hlist())).to_string(),
+            "unwrap[hlist] >>> hlist_map(lift(Inc)) >>> wrap[hlist]"
+        );
+
+        let out = Compile::<HlistNode<(i32, i32)>>::new()
+            .run(map_if_hlist())
+            .run(Tagged::new((1, 2)));
+        assert_eq!(out, Tagged::new((2, 3)));
+        let out = Compile::<NewTypeNode<i32>>::new()
+            .run(map_if_hlist())
+            .run(Tagged::new(7));
+        assert_eq!(out, Tagged::new(7));
+    }
+
+    #[cfg(feature = "explain")]
+    #[test]
+    fn if_prog_records_the_branch_taken() {
+        let (_, trace) = Compile::<NewTypeNode<i32>>::explain(map_if_hlist());
+        let step = &trace.steps[0];
+        assert_eq!(
+            step.handler,
+            "LowerIf<Tagged<Key<Domain<U4>, U0, U0>, i32>>"
+        );
+        assert_eq!(step.branch, Some("else"));
+    }
+
+    #[test]
+    fn repeat_n_unrolls_into_a_balanced_then_tree() {
+        type L = OpLift<Inc>;
+        let lowered: ThenBalanced<ThenBalanced<L, L>, ThenBalanced<L, L>> =
+            lower::<i32, _>(repeat_n::<U4, _>(op_lift(Inc)));
+        assert_eq!(
+            lowered,
+            then_balanced(
+                then_balanced(op_lift(Inc), op_lift(Inc)),
+                then_balanced(op_lift(Inc), op_lift(Inc))
+            )
+        );
+
+        let five = repeat_n::<U5, _>(op_lift(Inc));
+        assert_eq!(pretty(&five).to_string(), "repeat_n(lift(Inc), U5)");
+        assert_eq!(
+            pretty(&lower::<i32, _>(five)).to_string(),
+            "lift(Inc) >>> lift(Inc) >>> lift(Inc) >>> lift(Inc) >>> lift(Inc)"
+        );
+        assert_eq!(Compile::<i32>::new().run(five).run(41), 46);
+
+        let none: Id = lower::<i32, _>(repeat_n::<U0, _>(op_lift(Inc)));
+        assert_eq!(Compile::<i32>::new().run(none).run(41), 41);
+    }
+
+    #[test]
+    fn repeat_n_stays_balanced_through_the_optimizer() {
+        type L = OpLift<Inc>;
+        type Four = ThenBalanced<ThenBalanced<L, L>, ThenBalanced<L, L>>;
+
+        // The unrolled tree is one spine element: it is neither flattened nor re-associated.
+        let optimized: Four = optimize(lower::<i32, _>(repeat_n::<U4, _>(op_lift(Inc))));
+        assert_eq!(optimized, lower::<i32, _>(repeat_n::<U4, _>(op_lift(Inc))));
+
+        let prog = then(repeat_n::<U4, _>(op_lift(Inc)), op_lift(Inc));
+        let optimized: crate::Then<Four, L> = optimize(lower::<i32, _>(prog));
+        assert_eq!(
+            pretty(&optimized).to_string(),
+            "lift(Inc) >>> lift(Inc) >>> lift(Inc) >>> lift(Inc) >>> lift(Inc)"
+        );
+        assert_eq!(Compile::<i32>::new().run(prog).run(0), 5);
+    }
+}
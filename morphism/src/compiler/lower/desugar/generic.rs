@@ -3,6 +3,8 @@
 //! `D_GENERIC` is the structural-generic frontend domain for Rust ADT structure:
 //! - `NewTypeNode<S>`: newtype wrapper
 //! - `HlistNode<Children>`: heterogeneous list (product)
+//! - `SumNode<Either<L, R>>`: binary sum (its key lives in `D_SUM`; `map_children` and
+//!   `fold_everything_l` dispatch on it)
 //!
 //! This module wires closed lowering for the full current `D_GENERIC` key set.
 //! All keys must lower away completely (no `D_GENERIC` keys reach reify).
@@ -10,62 +12,60 @@
 //! Current closed lowering coverage in this module:
 //! - `IX_MAP_NEWTYPENODE` (U0)
 //! - `IX_MAP_HLISTNODE` (U1)
-//! - `IX_MAP_CHILDREN` (U2) (dispatches by input shape: NewTypeNode/HlistNode)
+//! - `IX_MAP_CHILDREN` (U2) (dispatches by input shape: NewTypeNode/HlistNode/SumNode)
 //! - `IX_FOLD_NEWTYPENODE_L` (U3)
 //! - `IX_FOLD_CHILDREN_L` (U4) (dispatches by input shape)
 //! - `IX_FOLD_HLISTNODE_L` (U5) (restricted to `OpLift<Op>` step programs)
+//! - `IX_FOLD_EVERYTHING_L` (U6) (dispatches by input shape, SumNode included; recurses per child
+//!   at runtime)
 //!
 //! Note: Some implementations are intentionally restricted (e.g. HList fold steps must be `OpLift<Op>`,
-//! dispatchers only apply to the two structural node shapes) to keep compile times stable.
+//! dispatchers only apply to the structural node shapes) to keep compile times stable.
 
 use core::marker::PhantomData;
 
-use crate::compiler::lower::LowerTable;
-use crate::compiler::table::LowerByTable;
+use crate::compiler::explain;
+use crate::compiler::lower::{LowerDomain, LowerTable};
 use crate::{
-    apply, const_move, fold_newtypenode_l_prog, hlist_compose_balanced_l_prog, hlist_map_prog,
-    hlist_to_segments_l_prog, map_in_hlist_node_prog, map_newtypenode_prog, partial_r, then,
-    unwrap_tagged, wrap_tagged, ConstMove, FoldNewTypeNodeLProg, HlistMapProg, HlistNode,
-    MapInHlistNodeProg, MapNewTypeNodeProg, NewTypeNode, OpLift, OpOnce, PartialR, Then,
-    UnwrapTagged, WrapTagged, IX_HLISTNODE, IX_NEWTYPENODE,
+    apply, choose, const_move, fold_newtypenode_l_prog, hlist_compose_balanced_l_prog,
+    hlist_fold_everything_step_l_prog, hlist_map_prog, hlist_to_segments_l_prog,
+    map_in_hlist_node_prog, map_newtypenode_prog, partial_l, partial_r, then, unwrap_tagged,
+    wrap_tagged, Choose, ConstMove, Either, FoldNewTypeNodeLProg, HlistFoldEverythingStepLProg,
+    HlistMapProg, HlistNode, MapInHlistNodeProg, MapNewTypeNodeProg, NewTypeNode, OpLift, OpOnce,
+    PartialL, PartialR, SumNode, Then, UnwrapTagged, WrapTagged, IX_HLISTNODE, IX_NEWTYPENODE,
+    IX_SUMNODE,
 };
-use crate::{Domain, Key, Tagged, D_GENERIC, R_SEMANTICS};
+use crate::{Domain, GenericSemanticsTable, D_GENERIC};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: LowerTable dispatches to this domain's table
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_GENERIC>, R_SEMANTICS, Ix>, Payload>>
-    for LowerTable<Input>
-where
-    LowerByTable<Input, LowerTableGeneric<Input>>:
-        OpOnce<Tagged<Key<Domain<D_GENERIC>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <LowerByTable<Input, LowerTableGeneric<Input>> as OpOnce<
-        Tagged<Key<Domain<D_GENERIC>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> LowerDomain<Input> for Domain<D_GENERIC> {
+    type Table = LowerTableGeneric<Input>;
 
     #[inline]
-    fn run(self, prog: Tagged<Key<Domain<D_GENERIC>, R_SEMANTICS, Ix>, Payload>) -> Self::OutVal {
-        LowerByTable::<Input, _>::new(lower_table_generic::<Input>()).run(prog)
+    fn lower_table() -> Self::Table {
+        lower_table_generic::<Input>()
     }
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Table definition (U0..U5) + constructor
+// Table definition (U0..U6) + constructor
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Lowering table for GenericDomain semantics keys.
 ///
-/// IMPORTANT: this uses a flat tuple encoding since all indices are U0..U5.
-pub type LowerTableGeneric<Input> = (
+/// IMPORTANT: this uses a flat tuple encoding since all indices are U0..U6.
+pub type LowerTableGeneric<Input> = GenericSemanticsTable<
     LowerMapNewTypeNode<Input>,   // U0: IX_MAP_NEWTYPENODE
     LowerMapHlistNode<Input>,     // U1: IX_MAP_HLISTNODE
     LowerMapChildren<Input>,      // U2: IX_MAP_CHILDREN
     LowerFoldNewTypeNodeL<Input>, // U3: IX_FOLD_NEWTYPENODE_L
     LowerFoldChildrenL<Input>,    // U4: IX_FOLD_CHILDREN_L
     LowerFoldHlistNodeL<Input>,   // U5: IX_FOLD_HLISTNODE_L
-);
+    LowerFoldEverythingL<Input>,  // U6: IX_FOLD_EVERYTHING_L
+>;
 
 #[inline]
 pub const fn lower_table_generic<Input>() -> LowerTableGeneric<Input> {
@@ -76,6 +76,7 @@ pub const fn lower_table_generic<Input>() -> LowerTableGeneric<Input> {
         LowerFoldNewTypeNodeL::new(),
         LowerFoldChildrenL::new(),
         LowerFoldHlistNodeL::new(),
+        LowerFoldEverythingL::new(),
     )
 }
 
@@ -176,6 +177,7 @@ where
 
     #[inline]
     fn run(self, op: OpLift<Op>) -> Self::OutVal {
+        explain::branch("NewTypeNode");
         let lowered_op = LowerTable::<NewTypeNode<S>>::new().run(op);
         let specialized = map_newtypenode_prog(lowered_op);
         LowerTable::<NewTypeNode<S>>::new().run(specialized)
@@ -195,12 +197,44 @@ where
 
     #[inline]
     fn run(self, op: OpLift<Op>) -> Self::OutVal {
+        explain::branch("HlistNode");
         let lowered_op = LowerTable::<HlistNode<Children>>::new().run(op);
         let specialized = map_in_hlist_node_prog(lowered_op);
         LowerTable::<HlistNode<Children>>::new().run(specialized)
     }
 }
 
+// SumNode<Either<L, R>>: map the side that is present (each side gets its own copy of the op)
+impl<L, R, Op> OpOnce<OpLift<Op>> for LowerMapChildren<SumNode<Either<L, R>>>
+where
+    LowerTable<SumNode<Either<L, R>>>: OpOnce<OpLift<Op>>,
+    <LowerTable<SumNode<Either<L, R>>> as OpOnce<OpLift<Op>>>::OutVal: Clone,
+{
+    type OutVal = Then<
+        Then<
+            UnwrapTagged<IX_SUMNODE>,
+            Choose<
+                <LowerTable<SumNode<Either<L, R>>> as OpOnce<OpLift<Op>>>::OutVal,
+                <LowerTable<SumNode<Either<L, R>>> as OpOnce<OpLift<Op>>>::OutVal,
+            >,
+        >,
+        WrapTagged<IX_SUMNODE>,
+    >;
+
+    #[inline]
+    fn run(self, op: OpLift<Op>) -> Self::OutVal {
+        explain::branch("SumNode");
+        let lowered_op = LowerTable::<SumNode<Either<L, R>>>::new().run(op);
+        then(
+            then(
+                unwrap_tagged::<IX_SUMNODE>(),
+                choose(lowered_op.clone(), lowered_op),
+            ),
+            wrap_tagged::<IX_SUMNODE>(),
+        )
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Generic fold family (U3..U5)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -247,6 +281,7 @@ where
 
     #[inline]
     fn run(self, (f_prog, acc): (FProg, Acc)) -> Self::OutVal {
+        explain::branch("NewTypeNode");
         let specialized = fold_newtypenode_l_prog(f_prog, acc);
         LowerTable::<NewTypeNode<S>>::new().run(specialized)
     }
@@ -260,6 +295,7 @@ where
 
     #[inline]
     fn run(self, (f_prog, acc): (FProg, Acc)) -> Self::OutVal {
+        explain::branch("HlistNode");
         LowerFoldHlistNodeL::<HlistNode<Children>>::new().run((f_prog, acc))
     }
 }
@@ -303,13 +339,89 @@ impl<Children, Op, Acc> OpOnce<(OpLift<Op>, Acc)> for LowerFoldHlistNodeL<HlistN
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// U6: IX_FOLD_EVERYTHING_L (dispatcher)
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Lowering op for `IX_FOLD_EVERYTHING_L` payload.
+///
+/// Lowered programs:
+/// - `NewTypeNode<S>`: `then(unwrap_newtypenode, partial_l(acc, f))` (the leaf is atomic)
+/// - `HlistNode<Children>`: the HList fold pipeline with `hlist_fold_everything_step_l_prog(f)`
+///   as step, so each child is folded recursively under its own container representation
+/// - `SumNode<Either<L, R>>`: `then(unwrap_sumnode, partial_l(acc, step))` with the same step,
+///   which folds whichever side is present
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerFoldEverythingL<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> LowerFoldEverythingL<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+// NewTypeNode<S>: the leaf step sees `(acc, payload)`
+impl<S, FProg, Acc> OpOnce<(FProg, Acc)> for LowerFoldEverythingL<NewTypeNode<S>>
+where
+    LowerTable<(Acc, S)>: OpOnce<FProg>,
+{
+    type OutVal = Then<
+        UnwrapTagged<IX_NEWTYPENODE>,
+        PartialL<Acc, <LowerTable<(Acc, S)> as OpOnce<FProg>>::OutVal>,
+    >;
+
+    #[inline]
+    fn run(self, (f_prog, acc): (FProg, Acc)) -> Self::OutVal {
+        explain::branch("NewTypeNode");
+        let f_lowered = LowerTable::<(Acc, S)>::new().run(f_prog);
+        then(unwrap_tagged::<IX_NEWTYPENODE>(), partial_l(acc, f_lowered))
+    }
+}
+
+// SumNode<Either<L, R>>: fold the side that is present, like a single HList child
+impl<L, R, FProg, Acc> OpOnce<(FProg, Acc)> for LowerFoldEverythingL<SumNode<Either<L, R>>> {
+    type OutVal =
+        Then<UnwrapTagged<IX_SUMNODE>, PartialL<Acc, HlistFoldEverythingStepLProg<FProg>>>;
+
+    #[inline]
+    fn run(self, (f_prog, acc): (FProg, Acc)) -> Self::OutVal {
+        explain::branch("SumNode");
+        then(
+            unwrap_tagged::<IX_SUMNODE>(),
+            partial_l(acc, hlist_fold_everything_step_l_prog(f_prog)),
+        )
+    }
+}
+
+// HlistNode<Children>: fold children left, recursing into each child
+impl<Children, FProg, Acc> OpOnce<(FProg, Acc)> for LowerFoldEverythingL<HlistNode<Children>> {
+    type OutVal = Then<
+        UnwrapTagged<IX_HLISTNODE>,
+        Then<
+            crate::HlistToSegmentsLProg<HlistFoldEverythingStepLProg<FProg>>,
+            Then<crate::HlistComposeBalancedLProg, PartialR<crate::ApplyProg, Acc>>,
+        >,
+    >;
+
+    #[inline]
+    fn run(self, (f_prog, acc): (FProg, Acc)) -> Self::OutVal {
+        explain::branch("HlistNode");
+        let fold_pipeline = then(
+            hlist_to_segments_l_prog(hlist_fold_everything_step_l_prog(f_prog)),
+            then(hlist_compose_balanced_l_prog(), partial_r(apply(), acc)),
+        );
+        then(unwrap_tagged::<IX_HLISTNODE>(), fold_pipeline)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::Compile;
     use crate::{
-        fold_children_l_prog, fold_newtypenode_l_prog, map_newtypenode_prog, op_lift, OpOnce,
-        Tagged,
+        fold_children_l_prog, fold_everything_l_prog, fold_newtypenode_l_prog, map_children_prog,
+        map_newtypenode_prog, op_lift, OpOnce, Tagged,
     };
 
     #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -368,4 +480,81 @@ mod tests {
         let out = Compile::<H>::new().run(prog).run(input);
         assert_eq!(out, 12usize);
     }
+
+    #[test]
+    fn compile_closed_v2_fold_everything_l_counts_leaves_of_nested_product() {
+        // Leaves: 1, 2, 3, 4, 5 (singleton tuples are products too).
+        let input = (1u8, (2u16, (3u32,)), 4u64, ((5u8,),));
+        let prog = fold_everything_l_prog(op_lift(CountL), 0usize);
+        let out = Compile::<HlistNode<_>>::new()
+            .run(prog)
+            .run(Tagged::new(input));
+        assert_eq!(out, 5usize);
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct GatherStrings;
+
+    impl OpOnce<(Vec<String>, String)> for GatherStrings {
+        type OutVal = Vec<String>;
+        #[inline]
+        fn run(self, (mut acc, s): (Vec<String>, String)) -> Self::OutVal {
+            acc.push(s);
+            acc
+        }
+    }
+
+    impl OpOnce<(Vec<String>, u32)> for GatherStrings {
+        type OutVal = Vec<String>;
+        #[inline]
+        fn run(self, (acc, _x): (Vec<String>, u32)) -> Self::OutVal {
+            acc
+        }
+    }
+
+    impl OpOnce<(Vec<String>, (u32, String))> for GatherStrings {
+        type OutVal = Vec<String>;
+        #[inline]
+        fn run(self, (mut acc, (_n, s)): (Vec<String>, (u32, String))) -> Self::OutVal {
+            acc.push(format!("opaque:{s}"));
+            acc
+        }
+    }
+
+    #[test]
+    fn compile_closed_v2_fold_everything_l_is_depth_first_and_stops_at_newtypenode() {
+        type Opaque = Tagged<IX_NEWTYPENODE, (u32, String)>;
+        let input = (
+            String::from("a"),
+            (7u32, (String::from("b"), String::from("c"))),
+            Opaque::new((8, String::from("d"))),
+            String::from("e"),
+        );
+        let prog = fold_everything_l_prog(op_lift(GatherStrings), Vec::new());
+        let out = Compile::<HlistNode<_>>::new()
+            .run(prog)
+            .run(Tagged::new(input));
+        assert_eq!(out, ["a", "b", "c", "opaque:d", "e"]);
+    }
+
+    #[test]
+    fn compile_closed_v2_fold_everything_l_descends_into_sums() {
+        // Leaves: 1, 2, 3, 4 (`None` is `Either::Right(())`, an empty product).
+        let input = (1u8, Some((2u16, 3u32)), None::<u8>, Ok::<u8, String>(4));
+        let prog = fold_everything_l_prog(op_lift(CountL), 0usize);
+        let out = Compile::<HlistNode<_>>::new()
+            .run(prog)
+            .run(Tagged::new(input));
+        assert_eq!(out, 4usize);
+    }
+
+    #[test]
+    fn compile_closed_v2_map_children_maps_the_present_side_of_a_sum() {
+        type S = SumNode<Either<i32, i32>>;
+        let prog = map_children_prog(op_lift(Inc));
+        let out = Compile::<S>::new()
+            .run(prog)
+            .run(Tagged::new(Either::Right(1)));
+        assert_eq!(out, Tagged::new(Either::Right(2)));
+    }
 }
@@ -2,8 +2,14 @@
 //!
 //! These files contain actual compiler work (rules/rewrites):
 //! - generic.rs: big desugaring; mostly the right direction
+//! - binding.rs: `lam` / `var` / `let_` (de Bruijn environment lowered to `fst` / `snd` / `fanout`)
+//! - bool.rs: boolean programs (evaluated to `const_move(True / False)`)
+//! - control.rs: static branching (`IX_IF` lowers to the arm its predicate selects)
 //!
 //! Note: hlist.rs has been removed. HList lowering is now handled by kernel/hlist.rs
 //! (only kernel keys remain, which are mostly identity constructors).
 
+pub mod binding;
+pub mod bool;
+pub mod control;
 pub mod generic;
@@ -1,33 +1,22 @@
-//! Closed-table lowering: Combinators domain (full table `U0..U9`).
+//! Closed-table lowering: Combinators domain (full table `U0..U20`).
 //!
 //! Routes recursion through `LowerTable` for closed-world lowering.
 
-use crate::compiler::lower::LowerTable;
-use crate::compiler::table::LowerByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_COMBINATORS, R_SEMANTICS};
+use crate::compiler::lower::{LowerDomain, LowerTable};
+use crate::D_COMBINATORS;
+use crate::{CombinatorsSemanticsTable, Domain, OpOnce};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: LowerTable dispatches to this domain's table
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // Combinators-domain lowering: partially recursive (THEN and friends) via a table.
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_COMBINATORS>, R_SEMANTICS, Ix>, Payload>>
-    for LowerTable<Input>
-where
-    LowerByTable<Input, LowerTableCombinators<Input>>:
-        OpOnce<Tagged<Key<Domain<D_COMBINATORS>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <LowerByTable<Input, LowerTableCombinators<Input>> as OpOnce<
-        Tagged<Key<Domain<D_COMBINATORS>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> LowerDomain<Input> for Domain<D_COMBINATORS> {
+    type Table = LowerTableCombinators<Input>;
 
     #[inline]
-    fn run(
-        self,
-        prog: Tagged<Key<Domain<D_COMBINATORS>, R_SEMANTICS, Ix>, Payload>,
-    ) -> Self::OutVal {
-        LowerByTable::<Input, _>::new(lower_table_combinators::<Input>()).run(prog)
+    fn lower_table() -> Self::Table {
+        lower_table_combinators::<Input>()
     }
 }
 
@@ -38,25 +27,38 @@ where
 use core::marker::PhantomData;
 
 use crate::{
-    apply, bimap, const_move, fanout, fst, id, partial_l, partial_r, snd, then, ApplyProg, Bimap,
-    ConstMove, Fanout, Fst, Id, NullaryToken, PartialL, PartialR, Snd, Then,
+    apply, assoc, bimap, choose, const_move, dup, either, fanout, first, fst, id, left, partial_l,
+    partial_r, right, second, snd, swap, then, then_balanced, unassoc, ApplyProg, Assoc, Bimap,
+    Choose, ConstMove, Dup, EitherProg, Fanout, First, Fst, Id, LeftProg, NullaryToken, PartialL,
+    PartialR, RightProg, Second, Snd, Swap, Then, ThenBalanced, Unassoc,
 };
 
-/// Lowering table for CombinatorsDomain (indices `U0..U9`).
+/// Lowering table for CombinatorsDomain (indices `U0..U20`, chunked at 11 + tail).
 ///
 /// Dense encoding: only kernel keys that survive lowering.
-pub type LowerTableCombinators<Input> = (
-    LowerId,              // U0: IX_ID
-    LowerThen<Input>,     // U1: IX_THEN
-    LowerPartialL<Input>, // U2: IX_PARTIAL_L
-    LowerPartialR<Input>, // U3: IX_PARTIAL_R
-    LowerFst,             // U4: IX_FST
-    LowerSnd,             // U5: IX_SND
-    LowerFanout<Input>,   // U6: IX_FANOUT
-    LowerConstMove,       // U7: IX_CONST_MOVE
-    LowerBimap<Input>,    // U8: IX_BIMAP
-    LowerApply,           // U9: IX_APPLY
-);
+pub type LowerTableCombinators<Input> = CombinatorsSemanticsTable<
+    LowerId,                  // U0: IX_ID
+    LowerThen<Input>,         // U1: IX_THEN
+    LowerPartialL<Input>,     // U2: IX_PARTIAL_L
+    LowerPartialR<Input>,     // U3: IX_PARTIAL_R
+    LowerFst,                 // U4: IX_FST
+    LowerSnd,                 // U5: IX_SND
+    LowerFanout<Input>,       // U6: IX_FANOUT
+    LowerConstMove,           // U7: IX_CONST_MOVE
+    LowerBimap<Input>,        // U8: IX_BIMAP
+    LowerApply,               // U9: IX_APPLY
+    LowerSwap,                // U10: IX_SWAP
+    LowerAssoc,               // U11: IX_ASSOC
+    LowerUnassoc,             // U12: IX_UNASSOC
+    LowerFirst<Input>,        // U13: IX_FIRST
+    LowerSecond<Input>,       // U14: IX_SECOND
+    LowerDup,                 // U15: IX_DUP
+    LowerChoose<Input>,       // U16: IX_CHOOSE
+    LowerEither<Input>,       // U17: IX_EITHER
+    LowerLeft<Input>,         // U18: IX_LEFT
+    LowerRight<Input>,        // U19: IX_RIGHT
+    LowerThenBalanced<Input>, // U20: IX_THEN_BALANCED
+>;
 
 #[inline]
 pub const fn lower_table_combinators<Input>() -> LowerTableCombinators<Input> {
@@ -71,10 +73,23 @@ pub const fn lower_table_combinators<Input>() -> LowerTableCombinators<Input> {
         LowerConstMove,
         LowerBimap::new(),
         LowerApply,
+        LowerSwap,
+        (
+            LowerAssoc,
+            LowerUnassoc,
+            LowerFirst::new(),
+            LowerSecond::new(),
+            LowerDup,
+            LowerChoose::new(),
+            LowerEither::new(),
+            LowerLeft::new(),
+            LowerRight::new(),
+            LowerThenBalanced::new(),
+        ),
     )
 }
 
-// U0/U4/U5/U7/U9: nullary identity (wrap payload back into same key)
+// U0/U4/U5/U7/U9/U10/U11/U12/U15: nullary identity (wrap payload back into same key)
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct LowerId;
@@ -121,7 +136,43 @@ impl OpOnce<NullaryToken> for LowerApply {
     }
 }
 
-// Recursive lowering: THEN/FANOUT/BIMAP/PARTIAL*
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerSwap;
+impl OpOnce<NullaryToken> for LowerSwap {
+    type OutVal = Swap;
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        swap()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerAssoc;
+impl OpOnce<NullaryToken> for LowerAssoc {
+    type OutVal = Assoc;
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        assoc()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerUnassoc;
+impl OpOnce<NullaryToken> for LowerUnassoc {
+    type OutVal = Unassoc;
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        unassoc()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerDup;
+impl OpOnce<NullaryToken> for LowerDup {
+    type OutVal = Dup;
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        dup()
+    }
+}
+
+// Recursive lowering: THEN/THEN_BALANCED/FANOUT/BIMAP/PARTIAL*/FIRST/SECOND/CHOOSE/EITHER/LEFT/RIGHT
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct LowerThen<Input>(PhantomData<fn() -> Input>);
@@ -147,6 +198,32 @@ where
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerThenBalanced<Input>(PhantomData<fn() -> Input>);
+impl<Input> LowerThenBalanced<Input> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, F, G> OpOnce<(F, G)> for LowerThenBalanced<Input>
+where
+    LowerTable<Input>: OpOnce<F>,
+    LowerTable<Input>: OpOnce<G>,
+{
+    type OutVal = ThenBalanced<
+        <LowerTable<Input> as OpOnce<F>>::OutVal,
+        <LowerTable<Input> as OpOnce<G>>::OutVal,
+    >;
+
+    fn run(self, (f, g): (F, G)) -> Self::OutVal {
+        then_balanced(
+            LowerTable::<Input>::new().run(f),
+            LowerTable::<Input>::new().run(g),
+        )
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct LowerFanout<Input>(PhantomData<fn() -> Input>);
 impl<Input> LowerFanout<Input> {
@@ -242,3 +319,129 @@ where
         )
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerFirst<Input>(PhantomData<fn() -> Input>);
+impl<Input> LowerFirst<Input> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, F> OpOnce<F> for LowerFirst<Input>
+where
+    LowerTable<Input>: OpOnce<F>,
+{
+    type OutVal = First<<LowerTable<Input> as OpOnce<F>>::OutVal>;
+
+    fn run(self, f: F) -> Self::OutVal {
+        first(LowerTable::<Input>::new().run(f))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerSecond<Input>(PhantomData<fn() -> Input>);
+impl<Input> LowerSecond<Input> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, G> OpOnce<G> for LowerSecond<Input>
+where
+    LowerTable<Input>: OpOnce<G>,
+{
+    type OutVal = Second<<LowerTable<Input> as OpOnce<G>>::OutVal>;
+
+    fn run(self, g: G) -> Self::OutVal {
+        second(LowerTable::<Input>::new().run(g))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerChoose<Input>(PhantomData<fn() -> Input>);
+impl<Input> LowerChoose<Input> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, F, G> OpOnce<(F, G)> for LowerChoose<Input>
+where
+    LowerTable<Input>: OpOnce<F>,
+    LowerTable<Input>: OpOnce<G>,
+{
+    type OutVal =
+        Choose<<LowerTable<Input> as OpOnce<F>>::OutVal, <LowerTable<Input> as OpOnce<G>>::OutVal>;
+
+    fn run(self, (f, g): (F, G)) -> Self::OutVal {
+        choose(
+            LowerTable::<Input>::new().run(f),
+            LowerTable::<Input>::new().run(g),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerEither<Input>(PhantomData<fn() -> Input>);
+impl<Input> LowerEither<Input> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, F, G> OpOnce<(F, G)> for LowerEither<Input>
+where
+    LowerTable<Input>: OpOnce<F>,
+    LowerTable<Input>: OpOnce<G>,
+{
+    type OutVal = EitherProg<
+        <LowerTable<Input> as OpOnce<F>>::OutVal,
+        <LowerTable<Input> as OpOnce<G>>::OutVal,
+    >;
+
+    fn run(self, (f, g): (F, G)) -> Self::OutVal {
+        either(
+            LowerTable::<Input>::new().run(f),
+            LowerTable::<Input>::new().run(g),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerLeft<Input>(PhantomData<fn() -> Input>);
+impl<Input> LowerLeft<Input> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, F> OpOnce<F> for LowerLeft<Input>
+where
+    LowerTable<Input>: OpOnce<F>,
+{
+    type OutVal = LeftProg<<LowerTable<Input> as OpOnce<F>>::OutVal>;
+
+    fn run(self, f: F) -> Self::OutVal {
+        left(LowerTable::<Input>::new().run(f))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerRight<Input>(PhantomData<fn() -> Input>);
+impl<Input> LowerRight<Input> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, G> OpOnce<G> for LowerRight<Input>
+where
+    LowerTable<Input>: OpOnce<G>,
+{
+    type OutVal = RightProg<<LowerTable<Input> as OpOnce<G>>::OutVal>;
+
+    fn run(self, g: G) -> Self::OutVal {
+        right(LowerTable::<Input>::new().run(g))
+    }
+}
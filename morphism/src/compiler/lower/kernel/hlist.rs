@@ -5,29 +5,21 @@
 //!
 //! Only kernel keys that survive lowering are present here (dense `U0..U0`).
 
-use crate::compiler::lower::LowerTable;
-use crate::compiler::table::LowerByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_HLIST, R_SEMANTICS};
+use crate::compiler::lower::LowerDomain;
+use crate::D_HLIST;
+use crate::{Domain, HlistSemanticsTable, OpOnce};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: LowerTable dispatches to this domain's table
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // HList semantics lowering: dispatch using the hlist-domain table.
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_HLIST>, R_SEMANTICS, Ix>, Payload>>
-    for LowerTable<Input>
-where
-    LowerByTable<Input, LowerTableHlist>:
-        OpOnce<Tagged<Key<Domain<D_HLIST>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <LowerByTable<Input, LowerTableHlist> as OpOnce<
-        Tagged<Key<Domain<D_HLIST>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> LowerDomain<Input> for Domain<D_HLIST> {
+    type Table = LowerTableHlist;
 
     #[inline]
-    fn run(self, prog: Tagged<Key<Domain<D_HLIST>, R_SEMANTICS, Ix>, Payload>) -> Self::OutVal {
-        LowerByTable::<Input, _>::new((LowerHlistPushBack,)).run(prog)
+    fn lower_table() -> Self::Table {
+        (LowerHlistPushBack,)
     }
 }
 
@@ -46,9 +38,9 @@ use crate::{
 /// Lowering table for HlistDomain semantics keys (indices `U0..U0`).
 ///
 /// Dense encoding: only kernel keys that survive lowering.
-pub type LowerTableHlist = (
+pub type LowerTableHlist = HlistSemanticsTable<
     LowerHlistPushBack, // U0: IX_HLIST_PUSH_BACK
-);
+>;
 
 // U0: nullary push_back (identity)
 
@@ -5,32 +5,21 @@
 //! - `IX_HLIST_TO_SEGMENTS_L` and `IX_HLIST_TO_SEGMENTS_R` recursively lower their `StepProg` payload to ensure
 //!   nested frontend keys don't leak into reify.
 
-use crate::compiler::lower::LowerTable;
-use crate::compiler::table::LowerByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_HLIST_FOLD, R_SEMANTICS};
+use crate::compiler::lower::LowerDomain;
+use crate::D_HLIST_FOLD;
+use crate::{Domain, HlistFoldSemanticsTable, OpOnce};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: LowerTable dispatches to this domain's table
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // HList fold semantics lowering: dispatch using the hlist-fold-domain table.
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_HLIST_FOLD>, R_SEMANTICS, Ix>, Payload>>
-    for LowerTable<Input>
-where
-    LowerByTable<Input, LowerTableHlistFold<Input>>:
-        OpOnce<Tagged<Key<Domain<D_HLIST_FOLD>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <LowerByTable<Input, LowerTableHlistFold<Input>> as OpOnce<
-        Tagged<Key<Domain<D_HLIST_FOLD>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> LowerDomain<Input> for Domain<D_HLIST_FOLD> {
+    type Table = LowerTableHlistFold<Input>;
 
     #[inline]
-    fn run(
-        self,
-        prog: Tagged<Key<Domain<D_HLIST_FOLD>, R_SEMANTICS, Ix>, Payload>,
-    ) -> Self::OutVal {
-        LowerByTable::<Input, _>::new(lower_table_hlist_fold::<Input>()).run(prog)
+    fn lower_table() -> Self::Table {
+        lower_table_hlist_fold::<Input>()
     }
 }
 
@@ -44,22 +33,25 @@ use crate::NullaryToken;
 use crate::{
     // constructors
     hlist_compose_balanced_l_prog,
+    hlist_fold_everything_step_l_prog,
     hlist_map_prog,
     hlist_to_segments_l_prog,
     // semantic aliases
     HlistComposeBalancedLProg,
+    HlistFoldEverythingStepLProg,
     HlistMapProg,
     HlistToSegmentsLProg,
 };
 
-/// Lowering table for HlistFoldDomain semantics keys (indices `U0..U2`).
+/// Lowering table for HlistFoldDomain semantics keys (indices `U0..U3`).
 ///
 /// Dense encoding: kernel keys that survive lowering.
-pub type LowerTableHlistFold<Input> = (
-    LowerHlistToSegmentsL<Input>, // U0: IX_HLIST_TO_SEGMENTS_L
-    LowerHlistComposeBalancedL,   // U1: IX_HLIST_COMPOSE_BALANCED_L
-    LowerHlistMapRt,              // U2: IX_HLIST_MAP (runtime bridge key)
-);
+pub type LowerTableHlistFold<Input> = HlistFoldSemanticsTable<
+    LowerHlistToSegmentsL<Input>,    // U0: IX_HLIST_TO_SEGMENTS_L
+    LowerHlistComposeBalancedL,      // U1: IX_HLIST_COMPOSE_BALANCED_L
+    LowerHlistMapRt,                 // U2: IX_HLIST_MAP (runtime bridge key)
+    LowerHlistFoldEverythingStepLRt, // U3: IX_HLIST_FOLD_EVERYTHING_STEP_L (runtime bridge key)
+>;
 
 #[inline]
 pub const fn lower_table_hlist_fold<Input>() -> LowerTableHlistFold<Input> {
@@ -67,6 +59,7 @@ pub const fn lower_table_hlist_fold<Input>() -> LowerTableHlistFold<Input> {
         LowerHlistToSegmentsL::new(),
         LowerHlistComposeBalancedL,
         LowerHlistMapRt,
+        LowerHlistFoldEverythingStepLRt,
     )
 }
 
@@ -120,3 +113,16 @@ impl<StepProg> OpOnce<StepProg> for LowerHlistMapRt {
         hlist_map_prog(step)
     }
 }
+
+// U3: IX_HLIST_FOLD_EVERYTHING_STEP_L (runtime bridge key - identity pass-through)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowerHlistFoldEverythingStepLRt;
+
+impl<FProg> OpOnce<FProg> for LowerHlistFoldEverythingStepLRt {
+    type OutVal = HlistFoldEverythingStepLProg<FProg>;
+
+    #[inline]
+    fn run(self, f_prog: FProg) -> Self::OutVal {
+        hlist_fold_everything_step_l_prog(f_prog)
+    }
+}
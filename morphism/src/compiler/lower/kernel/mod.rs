@@ -9,7 +9,7 @@
 //!   but we keep them here to make closed-table wiring explicit and uniform.
 
 pub mod combinators;
-pub mod tagged;
-pub mod op;
 pub mod hlist;
 pub mod hlist_fold;
+pub mod op;
+pub mod tagged;
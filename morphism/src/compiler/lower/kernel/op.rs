@@ -2,29 +2,21 @@
 //!
 //! Lowering is identity (pass-through): `OpLift(op) -> OpLift(op)`.
 
-use crate::compiler::lower::LowerTable;
-use crate::compiler::table::LowerByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_OP, R_SEMANTICS};
+use crate::compiler::lower::LowerDomain;
+use crate::D_OP;
+use crate::{Domain, OpOnce, OpSemanticsTable};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: LowerTable dispatches to this domain's table
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // Op-lift lowering: identity (pass-through).
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_OP>, R_SEMANTICS, Ix>, Payload>>
-    for LowerTable<Input>
-where
-    LowerByTable<Input, (LowerOpLift,)>:
-        OpOnce<Tagged<Key<Domain<D_OP>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <LowerByTable<Input, (LowerOpLift,)> as OpOnce<
-        Tagged<Key<Domain<D_OP>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> LowerDomain<Input> for Domain<D_OP> {
+    type Table = OpSemanticsTable<LowerOpLift>;
 
     #[inline]
-    fn run(self, prog: Tagged<Key<Domain<D_OP>, R_SEMANTICS, Ix>, Payload>) -> Self::OutVal {
-        LowerByTable::<Input, _>::new(lower_table_op()).run(prog)
+    fn lower_table() -> Self::Table {
+        lower_table_op()
     }
 }
 
@@ -4,8 +4,7 @@
 
 use core::marker::PhantomData;
 
-use crate::compiler::lower::LowerTable;
-use crate::compiler::table::LowerByTable;
+use crate::compiler::lower::LowerDomain;
 use crate::{
     // constructors
     unwrap_tagged,
@@ -15,26 +14,19 @@ use crate::{
     UnwrapTagged,
     WrapTagged,
 };
-use crate::{Domain, Key, Tagged, D_TAGGED, R_SEMANTICS};
+use crate::{Domain, TaggedSemanticsTable, D_TAGGED};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: LowerTable dispatches to this domain's table
+// Routing: LowerTable dispatches to this domain's table (via `LowerDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // Tagged-domain lowering: dispatch using the tagged-domain table (wrap/unwrap).
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_TAGGED>, R_SEMANTICS, Ix>, Payload>>
-    for LowerTable<Input>
-where
-    LowerByTable<Input, (LowerWrapTagged, LowerUnwrapTagged)>:
-        OpOnce<Tagged<Key<Domain<D_TAGGED>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <LowerByTable<Input, (LowerWrapTagged, LowerUnwrapTagged)> as OpOnce<
-        Tagged<Key<Domain<D_TAGGED>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> LowerDomain<Input> for Domain<D_TAGGED> {
+    type Table = TaggedSemanticsTable<LowerWrapTagged, LowerUnwrapTagged>;
 
     #[inline]
-    fn run(self, prog: Tagged<Key<Domain<D_TAGGED>, R_SEMANTICS, Ix>, Payload>) -> Self::OutVal {
-        LowerByTable::<Input, _>::new(lower_table_tagged()).run(prog)
+    fn lower_table() -> Self::Table {
+        lower_table_tagged()
     }
 }
 
@@ -62,6 +54,6 @@ impl<K> OpOnce<PhantomData<fn() -> K>> for LowerUnwrapTagged {
 
 /// Lowering table for Tagged-domain (indices U0..U1).
 #[inline]
-pub const fn lower_table_tagged() -> (LowerWrapTagged, LowerUnwrapTagged) {
+pub const fn lower_table_tagged() -> TaggedSemanticsTable<LowerWrapTagged, LowerUnwrapTagged> {
     (LowerWrapTagged, LowerUnwrapTagged)
 }
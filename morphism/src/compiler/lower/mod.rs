@@ -1,10 +1,14 @@
 use core::marker::PhantomData;
 
-/// Closed-table lowerer: supports a small subset of domains via per-domain tables.
+use crate::compiler::table::LowerByTable;
+use crate::{Key, OpOnce, Tagged, R_SEMANTICS};
+
+/// Closed-table lowerer: routes each semantics key to its domain's table.
 ///
-/// Domain-specific routing impls are co-located with their table definitions:
+/// Routing is a single impl over `Key<D, R_SEMANTICS, Ix>` where `D: LowerDomain<Input>`.
+/// Built-in domains implement `LowerDomain` next to their table definitions:
 /// - `lower/kernel/*.rs`: kernel domains (op, tagged, combinators, hlist, hlist_fold)
-/// - `lower/desugar/*.rs`: frontend domains (generic)
+/// - `lower/desugar/*.rs`: frontend domains (generic, control, bool)
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct LowerTable<Input>(PhantomData<fn() -> Input>);
 
@@ -15,5 +19,39 @@ impl<Input> LowerTable<Input> {
     }
 }
 
+/// Lowering extension point: a domain supplies the closed table that lowers its keys.
+///
+/// `Table` is a handler tuple indexed by key index (flat semantics, chunked at 11 + tail),
+/// each handler mapping a key's payload to a lowered program. Handlers that lower child
+/// programs recurse through `LowerTable<Input>`.
+///
+/// Implement this for the domain type of your keys (`Key<YourDomain, R_SEMANTICS, Ix>`).
+/// Downstream crates implement it for a local domain marker type; checking stays closed
+/// per domain (a key outside its domain's table fails to type-check).
+#[diagnostic::on_unimplemented(
+    message = "domain `{Self}` has no lowering table for input `{Input}`",
+    label = "no `LowerDomain<{Input}>` impl for this domain",
+    note = "implement `LowerDomain<Input>` for the domain type next to its table"
+)]
+pub trait LowerDomain<Input> {
+    type Table;
+
+    fn lower_table() -> Self::Table;
+}
+
+impl<Input, D, Ix, Payload> OpOnce<Tagged<Key<D, R_SEMANTICS, Ix>, Payload>> for LowerTable<Input>
+where
+    D: LowerDomain<Input>,
+    LowerByTable<Input, D::Table>: OpOnce<Tagged<Key<D, R_SEMANTICS, Ix>, Payload>>,
+{
+    type OutVal =
+        <LowerByTable<Input, D::Table> as OpOnce<Tagged<Key<D, R_SEMANTICS, Ix>, Payload>>>::OutVal;
+
+    #[inline]
+    fn run(self, prog: Tagged<Key<D, R_SEMANTICS, Ix>, Payload>) -> Self::OutVal {
+        LowerByTable::<Input, _>::new(D::lower_table()).run(prog)
+    }
+}
+
+pub(crate) mod desugar;
 pub(crate) mod kernel;
-pub(crate) mod desugar;
\ No newline at end of file
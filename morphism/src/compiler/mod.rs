@@ -4,7 +4,28 @@ pub use compile::*;
 mod eval_prog;
 pub use eval_prog::*;
 
+mod explain;
+#[cfg(feature = "explain")]
+pub use explain::{LowerStep, LowerTrace};
+
 pub(crate) mod compiler_closed_table;
+
+mod diagnostics;
+pub use diagnostics::*;
+
 pub(crate) mod lower;
+pub use lower::{LowerDomain, LowerTable};
+
+pub(crate) mod optimize;
+pub use optimize::{optimize, KeyIsOp, Optimize};
+
 pub(crate) mod reify;
+pub use reify::{ReifyDomain, ReifyTable};
+
 pub(crate) mod table;
+
+mod verify;
+pub use verify::{
+    verify_kernel, KernelCheck, KernelChildren, KernelOnly, KernelRole, KernelVerdict, Passed,
+    Verified,
+};
@@ -0,0 +1,186 @@
+//! Fusion rules: merge consecutive HList passes into one.
+//!
+//! - `hlist_map(lift(f)) >>> hlist_map(lift(g))` → `hlist_map(lift(f >>> g))`
+//! - `hlist_map(lift(f)) >>> to_segments_l(lift(step))` → `to_segments_l(lift(bimap(id, f) >>> step))`
+//!
+//! Both rules compose the runtime ops (`ThenOp`, `BimapOp`) under a single `OpLift`, so the fused
+//! stage is again a lifted stage and chains of maps fuse pairwise into one pass. Stages that are
+//! not lifted ops are left alone: their programs are compiled per element and may depend on the
+//! element type, which a composed program would no longer see.
+
+use super::{
+    KLiftedMap, KLiftedSegments, KOther, KUnwrap, KWrap, NoRule, PeepholeHead, RewritePair,
+    RuleWith,
+};
+use crate::{
+    hlist_map_prog, hlist_to_segments_l_prog, op_lift, BimapOp, HlistMapProg, HlistToSegmentsLProg,
+    IdOp, OpLift, ThenOp,
+};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Rule selection
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl RuleWith<KLiftedMap> for KLiftedMap {
+    type Rule = FuseMaps;
+}
+
+impl RuleWith<KLiftedSegments> for KLiftedMap {
+    type Rule = FuseMapFold;
+}
+
+impl RuleWith<KOther> for KLiftedMap {
+    type Rule = NoRule;
+}
+
+impl<K> RuleWith<KWrap<K>> for KLiftedMap {
+    type Rule = NoRule;
+}
+
+impl<K> RuleWith<KUnwrap<K>> for KLiftedMap {
+    type Rule = NoRule;
+}
+
+impl<KindY> RuleWith<KindY> for KLiftedSegments {
+    type Rule = NoRule;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// map >>> map
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// `hlist_map(lift(f)) >>> hlist_map(lift(g))` → `hlist_map(lift(f >>> g))`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FuseMaps;
+
+impl<F, G, Rest> RewritePair<HlistMapProg<OpLift<F>>, HlistMapProg<OpLift<G>>, Rest> for FuseMaps
+where
+    (HlistMapProg<OpLift<ThenOp<F, G>>>, Rest): PeepholeHead,
+{
+    type Out = <(HlistMapProg<OpLift<ThenOp<F, G>>>, Rest) as PeepholeHead>::Out;
+
+    #[inline]
+    fn rewrite(f: HlistMapProg<OpLift<F>>, g: HlistMapProg<OpLift<G>>, rest: Rest) -> Self::Out {
+        let fused = ThenOp::new(f.into_inner().into_inner(), g.into_inner().into_inner());
+        (hlist_map_prog(op_lift(fused)), rest).peephole_head()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// map >>> fold
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Fold step pre-composed with a map: `(acc, x) ↦ step(acc, f(x))`.
+pub type MappedStep<F, Step> = ThenOp<BimapOp<IdOp, F>, Step>;
+
+/// `hlist_map(lift(f)) >>> to_segments_l(lift(step))` → `to_segments_l(lift(bimap(id, f) >>> step))`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FuseMapFold;
+
+impl<F, Step, Rest> RewritePair<HlistMapProg<OpLift<F>>, HlistToSegmentsLProg<OpLift<Step>>, Rest>
+    for FuseMapFold
+where
+    (HlistToSegmentsLProg<OpLift<MappedStep<F, Step>>>, Rest): PeepholeHead,
+{
+    type Out = <(HlistToSegmentsLProg<OpLift<MappedStep<F, Step>>>, Rest) as PeepholeHead>::Out;
+
+    #[inline]
+    fn rewrite(
+        f: HlistMapProg<OpLift<F>>,
+        step: HlistToSegmentsLProg<OpLift<Step>>,
+        rest: Rest,
+    ) -> Self::Out {
+        let pre = BimapOp {
+            f: IdOp,
+            g: f.into_inner().into_inner(),
+        };
+        let fused = ThenOp::new(pre, step.into_inner().into_inner());
+        (hlist_to_segments_l_prog(op_lift(fused)), rest).peephole_head()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compile, fold_children_l_prog, hlist_map_prog, hlist_to_segments_l_prog, map_children_prog,
+        op_lift, optimize, pretty, then, unwrap_tagged, HlistNode, OpOnce, Tagged, IX_HLISTNODE,
+    };
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    impl<T: core::ops::Add<Output = T> + From<u8>> OpOnce<T> for AddTen {
+        type OutVal = T;
+        fn run(self, x: T) -> T {
+            x + T::from(10)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Double;
+
+    impl<T: core::ops::Add<Output = T> + Copy> OpOnce<T> for Double {
+        type OutVal = T;
+        fn run(self, x: T) -> T {
+            x + x
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Sum;
+
+    impl<X: Into<u32>> OpOnce<(u32, X)> for Sum {
+        type OutVal = u32;
+        fn run(self, (acc, x): (u32, X)) -> u32 {
+            acc + x.into()
+        }
+    }
+
+    #[test]
+    fn fuses_map_chains_into_one_map() {
+        let prog = then(
+            hlist_map_prog(op_lift(AddTen)),
+            then(
+                hlist_map_prog(op_lift(Double)),
+                hlist_map_prog(op_lift(AddTen)),
+            ),
+        );
+        let fused = optimize(prog);
+        assert_eq!(
+            pretty(&fused).to_string(),
+            "hlist_map(lift(ThenOp<AddTen, ThenOp<Double, AddTen>>))"
+        );
+    }
+
+    #[test]
+    fn fuses_map_into_fold_step() {
+        let prog = then(
+            unwrap_tagged::<IX_HLISTNODE>(),
+            then(
+                hlist_map_prog(op_lift(Double)),
+                hlist_to_segments_l_prog(op_lift(Sum)),
+            ),
+        );
+        assert_eq!(
+            pretty(&optimize(prog)).to_string(),
+            "unwrap[hlist] >>> to_segments_l(lift(ThenOp<BimapOp<IdOp, Double>, Sum>))"
+        );
+    }
+
+    #[test]
+    fn fused_programs_compute_the_same_result() {
+        type H = HlistNode<(u8, u16)>;
+        let mapped = then(
+            map_children_prog(op_lift(AddTen)),
+            map_children_prog(op_lift(Double)),
+        );
+        let out = compile::<H, _>(mapped).run(Tagged::new((1, 2)));
+        assert_eq!(out, Tagged::new((22, 24)));
+
+        let folded = then(
+            map_children_prog(op_lift(Double)),
+            fold_children_l_prog(op_lift(Sum), 0_u32),
+        );
+        assert_eq!(compile::<H, _>(folded).run(Tagged::new((1, 2))), 6);
+    }
+}
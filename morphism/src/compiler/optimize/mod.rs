@@ -0,0 +1,353 @@
+//! Optimizer: peephole rewrites over lowered (kernel) programs, between lowering and reify.
+//!
+//! `Compile` runs `lower → Optimize → reify`. `Optimize` rewrites the program's `then` spine:
+//!
+//! 1. flatten the spine into a cons list `(X0, (X1, .. ()))`, dropping `id`s (`ThenOnto`)
+//! 2. rewrite adjacent pairs, right to left (`Peephole`): each element is classified by its key
+//!    (`KindOp`), the pair of kinds selects a rule (`RuleWith`), and the rule rewrites the pair
+//!    (`RewritePair`); a rewritten head is checked again against its new neighbour
+//! 3. rebuild a right-associated `then` chain (`Unchain`)
+//!
+//! Only the top-level spine is rewritten; payloads (step programs, `bimap` arms) are left as-is.
+//! A `then_balanced` node is one spine element, not part of the spine: the balanced trees that
+//! `repeat_n` unrolls to keep their `O(log N)` depth instead of flattening into an `N`-long chain.
+//! Classification uses `KeyEqOp`, so keys of other crates' domains are simply `KOther`.
+//!
+//! Rules live next to their kinds: `fusion.rs` (map/map and map/fold fusion), `simplify.rs`
+//! (`wrap`/`unwrap` cancellation).
+
+mod fusion;
+mod simplify;
+
+use core::marker::PhantomData;
+
+use crate::{id, then, Id, IX_HLIST_MAP, IX_HLIST_TO_SEGMENTS_L, IX_UNWRAP_TAGGED};
+use crate::{
+    ConstCOp, If, IfApply, KeyEqOp, OpOnce, OpTy, OpTyOut, Tagged, Then, IX_ID, IX_OP_LIFT,
+    IX_THEN, IX_WRAP_TAGGED,
+};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Entry point
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// The optimizer pass: `lowered program -> lowered program` (see module docs).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Optimize;
+
+impl<Prog> OpOnce<Prog> for Optimize
+where
+    Prog: ThenOnto<()>,
+    Prog::Out: Peephole,
+    <Prog::Out as Peephole>::Out: Unchain,
+{
+    type OutVal = <<Prog::Out as Peephole>::Out as Unchain>::Out;
+
+    #[inline]
+    fn run(self, prog: Prog) -> Self::OutVal {
+        prog.then_onto(()).peephole().unchain()
+    }
+}
+
+/// Optimize a lowered program (the middle step of `compile`).
+///
+/// Useful to inspect what the optimizer did, e.g. `pretty(&optimize(lower::<P, _>(prog)))`.
+#[inline]
+pub fn optimize<Prog>(prog: Prog) -> <Optimize as OpOnce<Prog>>::OutVal
+where
+    Optimize: OpOnce<Prog>,
+{
+    Optimize.run(prog)
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Spine <-> cons list
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Flatten a program's `then` spine in front of the cons list `Rest`.
+pub trait ThenOnto<Rest> {
+    type Out;
+    fn then_onto(self, rest: Rest) -> Self::Out;
+}
+
+impl<K, P, Rest> ThenOnto<Rest> for Tagged<K, P>
+where
+    SpineRoleOp: OpTy<K>,
+    Tagged<K, P>: ThenOntoBy<OpTyOut<SpineRoleOp, K>, Rest>,
+{
+    type Out = <Tagged<K, P> as ThenOntoBy<OpTyOut<SpineRoleOp, K>, Rest>>::Out;
+
+    #[inline]
+    fn then_onto(self, rest: Rest) -> Self::Out {
+        self.then_onto_by(rest)
+    }
+}
+
+/// Role of a node in the spine: a `then` to flatten.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SpineThen;
+
+/// Role of a node in the spine: an `id` to drop.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SpineId;
+
+/// Role of a node in the spine: an element to keep.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SpineElem;
+
+/// Key -> spine role (`then_balanced` is an element, see module docs).
+pub type SpineRoleOp =
+    IfApply<KeyEqOp<IX_THEN>, ConstCOp<SpineThen>, If<KeyEqOp<IX_ID>, SpineId, SpineElem>>;
+
+/// `ThenOnto`, split on the node's spine role.
+pub trait ThenOntoBy<Role, Rest> {
+    type Out;
+    fn then_onto_by(self, rest: Rest) -> Self::Out;
+}
+
+impl<F, G, Rest> ThenOntoBy<SpineThen, Rest> for Then<F, G>
+where
+    G: ThenOnto<Rest>,
+    F: ThenOnto<G::Out>,
+{
+    type Out = <F as ThenOnto<G::Out>>::Out;
+
+    #[inline]
+    fn then_onto_by(self, rest: Rest) -> Self::Out {
+        let (f, g) = self.into_inner();
+        f.then_onto(g.then_onto(rest))
+    }
+}
+
+impl<Rest> ThenOntoBy<SpineId, Rest> for Id {
+    type Out = Rest;
+
+    #[inline]
+    fn then_onto_by(self, rest: Rest) -> Self::Out {
+        rest
+    }
+}
+
+impl<K, P, Rest> ThenOntoBy<SpineElem, Rest> for Tagged<K, P> {
+    type Out = (Tagged<K, P>, Rest);
+
+    #[inline]
+    fn then_onto_by(self, rest: Rest) -> Self::Out {
+        (self, rest)
+    }
+}
+
+/// Rebuild a right-associated `then` chain from a cons list (`()` alone becomes `id`).
+pub trait Unchain {
+    type Out;
+    fn unchain(self) -> Self::Out;
+}
+
+impl Unchain for () {
+    type Out = Id;
+
+    #[inline]
+    fn unchain(self) -> Self::Out {
+        id()
+    }
+}
+
+impl<X> Unchain for (X, ()) {
+    type Out = X;
+
+    #[inline]
+    fn unchain(self) -> Self::Out {
+        self.0
+    }
+}
+
+impl<X, Y, Rest> Unchain for (X, (Y, Rest))
+where
+    (Y, Rest): Unchain,
+{
+    type Out = Then<X, <(Y, Rest) as Unchain>::Out>;
+
+    #[inline]
+    fn unchain(self) -> Self::Out {
+        let (x, rest) = self;
+        then(x, rest.unchain())
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Peephole driver
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Rewrite a cons list right to left (the tail first, then the head against it).
+pub trait Peephole {
+    type Out;
+    fn peephole(self) -> Self::Out;
+}
+
+impl Peephole for () {
+    type Out = ();
+
+    #[inline]
+    fn peephole(self) -> Self::Out {}
+}
+
+impl<X, Rest> Peephole for (X, Rest)
+where
+    Rest: Peephole,
+    (X, Rest::Out): PeepholeHead,
+{
+    type Out = <(X, Rest::Out) as PeepholeHead>::Out;
+
+    #[inline]
+    fn peephole(self) -> Self::Out {
+        let (x, rest) = self;
+        (x, rest.peephole()).peephole_head()
+    }
+}
+
+/// Rewrite the first pair of an already-rewritten cons list.
+pub trait PeepholeHead {
+    type Out;
+    fn peephole_head(self) -> Self::Out;
+}
+
+impl PeepholeHead for () {
+    type Out = ();
+
+    #[inline]
+    fn peephole_head(self) -> Self::Out {}
+}
+
+impl<X> PeepholeHead for (X, ()) {
+    type Out = (X, ());
+
+    #[inline]
+    fn peephole_head(self) -> Self::Out {
+        self
+    }
+}
+
+impl<X, Y, Rest> PeepholeHead for (X, (Y, Rest))
+where
+    KindOp: OpTy<X> + OpTy<Y>,
+    OpTyOut<KindOp, X>: RuleWith<OpTyOut<KindOp, Y>>,
+    RuleOf<X, Y>: RewritePair<X, Y, Rest>,
+{
+    type Out = <RuleOf<X, Y> as RewritePair<X, Y, Rest>>::Out;
+
+    #[inline]
+    fn peephole_head(self) -> Self::Out {
+        let (x, (y, rest)) = self;
+        <RuleOf<X, Y> as RewritePair<X, Y, Rest>>::rewrite(x, y, rest)
+    }
+}
+
+/// The rule selected for the adjacent pair `X >>> Y`.
+pub type RuleOf<X, Y> = <OpTyOut<KindOp, X> as RuleWith<OpTyOut<KindOp, Y>>>::Rule;
+
+/// Rule for a pair of kinds (implemented on the first element's kind).
+pub trait RuleWith<KindY> {
+    type Rule;
+}
+
+/// A rewrite of the adjacent pair `X >>> Y` in front of the (rewritten) cons list `Rest`.
+///
+/// Rules that produce a new head re-run `PeepholeHead` on it.
+pub trait RewritePair<X, Y, Rest> {
+    type Out;
+    fn rewrite(x: X, y: Y, rest: Rest) -> Self::Out;
+}
+
+/// Leave the pair as it is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoRule;
+
+impl<X, Y, Rest> RewritePair<X, Y, Rest> for NoRule {
+    type Out = (X, (Y, Rest));
+
+    #[inline]
+    fn rewrite(x: X, y: Y, rest: Rest) -> Self::Out {
+        (x, (y, rest))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Kinds: classification of spine elements by key
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Any element no rule looks at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KOther;
+
+/// `hlist_map(lift(f))`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KLiftedMap;
+
+/// `to_segments_l(lift(step))`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KLiftedSegments;
+
+/// `wrap_tagged::<K>()`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KWrap<K>(PhantomData<fn() -> K>);
+
+/// `unwrap_tagged::<K>()`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KUnwrap<K>(PhantomData<fn() -> K>);
+
+/// Element -> kind.
+pub type KindOp = IfApply<
+    KeyIsOp<IX_HLIST_MAP>,
+    LiftedPayloadOp<KLiftedMap>,
+    IfApply<
+        KeyIsOp<IX_HLIST_TO_SEGMENTS_L>,
+        LiftedPayloadOp<KLiftedSegments>,
+        IfApply<
+            KeyIsOp<IX_WRAP_TAGGED>,
+            WrapKindOp,
+            IfApply<KeyIsOp<IX_UNWRAP_TAGGED>, UnwrapKindOp, ConstCOp<KOther>>,
+        >,
+    >,
+>;
+
+/// Node predicate: `Tagged<K2, _> ↦ (K2 == K)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyIsOp<K>(PhantomData<fn() -> K>);
+
+impl<K, K2, P> OpTy<Tagged<K2, P>> for KeyIsOp<K>
+where
+    KeyEqOp<K>: OpTy<K2>,
+{
+    type OutTy = OpTyOut<KeyEqOp<K>, K2>;
+}
+
+/// `Kind` if the node's payload is an `OpLift`, else `KOther`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LiftedPayloadOp<Kind>(PhantomData<fn() -> Kind>);
+
+impl<Kind, K, K2, P> OpTy<Tagged<K, Tagged<K2, P>>> for LiftedPayloadOp<Kind>
+where
+    If<KeyEqOp<IX_OP_LIFT>, Kind, KOther>: OpTy<K2>,
+{
+    type OutTy = OpTyOut<If<KeyEqOp<IX_OP_LIFT>, Kind, KOther>, K2>;
+}
+
+/// `wrap_tagged::<K>()` -> `KWrap<K>`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WrapKindOp;
+
+impl<K2, K> OpTy<Tagged<K2, PhantomData<fn() -> K>>> for WrapKindOp {
+    type OutTy = KWrap<K>;
+}
+
+/// `unwrap_tagged::<K>()` -> `KUnwrap<K>`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnwrapKindOp;
+
+impl<K2, K> OpTy<Tagged<K2, PhantomData<fn() -> K>>> for UnwrapKindOp {
+    type OutTy = KUnwrap<K>;
+}
+
+impl<KindY> RuleWith<KindY> for KOther {
+    type Rule = NoRule;
+}
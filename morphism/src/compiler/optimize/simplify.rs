@@ -0,0 +1,148 @@
+//! Simplification rules: cancel tag round trips.
+//!
+//! - `wrap_tagged::<K>() >>> unwrap_tagged::<K>()` → (nothing)
+//! - `unwrap_tagged::<K>() >>> wrap_tagged::<K>()` → (nothing)
+//!
+//! Both pairs are identities. They show up between chained stages: each lowered stage unwraps
+//! its node on entry and wraps it again on exit. Removing them makes the neighbouring stages
+//! adjacent, so map stages of a pipeline fuse (`fusion.rs`). `id`s are dropped earlier, while
+//! flattening the spine.
+
+use super::{KLiftedMap, KLiftedSegments, KOther, KUnwrap, KWrap, NoRule, RewritePair, RuleWith};
+use core::marker::PhantomData;
+
+use crate::{False, KeyEqOp, OpTy, OpTyOut, True};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Rule selection
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Rules for a tag op `Self` followed by `Other`: `Inverse` cancels when the keys match.
+macro_rules! tag_rules {
+    ( $Kind:ident, $Inverse:ident, $Same:ident ) => {
+        impl<K, K2> RuleWith<$Inverse<K2>> for $Kind<K>
+        where
+            KeyEqOp<K>: OpTy<K2>,
+        {
+            type Rule = CancelIf<OpTyOut<KeyEqOp<K>, K2>>;
+        }
+
+        impl<K, K2> RuleWith<$Same<K2>> for $Kind<K> {
+            type Rule = NoRule;
+        }
+
+        impl<K> RuleWith<KOther> for $Kind<K> {
+            type Rule = NoRule;
+        }
+
+        impl<K> RuleWith<KLiftedMap> for $Kind<K> {
+            type Rule = NoRule;
+        }
+
+        impl<K> RuleWith<KLiftedSegments> for $Kind<K> {
+            type Rule = NoRule;
+        }
+    };
+}
+
+tag_rules!(KWrap, KUnwrap, KWrap);
+tag_rules!(KUnwrap, KWrap, KUnwrap);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Cancellation
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Drop both elements of the pair when `Eq` is `True` (the keys match), else keep them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CancelIf<Eq>(PhantomData<fn() -> Eq>);
+
+impl<X, Y, Rest> RewritePair<X, Y, Rest> for CancelIf<True> {
+    type Out = Rest;
+
+    #[inline]
+    fn rewrite(_: X, _: Y, rest: Rest) -> Self::Out {
+        rest
+    }
+}
+
+impl<X, Y, Rest> RewritePair<X, Y, Rest> for CancelIf<False> {
+    type Out = (X, (Y, Rest));
+
+    #[inline]
+    fn rewrite(x: X, y: Y, rest: Rest) -> Self::Out {
+        (x, (y, rest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hlist_map_prog, id, lower, map_children_prog, op_lift, optimize, pretty, then,
+        unwrap_tagged, wrap_tagged, Container, HlistNode, OpOnce, IX_HLISTNODE, IX_NEWTYPENODE,
+    };
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    impl<T: core::ops::Add<Output = T> + From<u8>> OpOnce<T> for AddTen {
+        type OutVal = T;
+        fn run(self, x: T) -> T {
+            x + T::from(10)
+        }
+    }
+
+    #[test]
+    fn removes_ids_and_tag_round_trips() {
+        let prog = then(
+            id(),
+            then(
+                wrap_tagged::<IX_HLISTNODE>(),
+                then(unwrap_tagged::<IX_HLISTNODE>(), id()),
+            ),
+        );
+        assert_eq!(pretty(&optimize(prog)).to_string(), "id");
+
+        let prog = then(
+            unwrap_tagged::<IX_HLISTNODE>(),
+            then(
+                wrap_tagged::<IX_HLISTNODE>(),
+                hlist_map_prog(op_lift(AddTen)),
+            ),
+        );
+        assert_eq!(
+            pretty(&optimize(prog)).to_string(),
+            "hlist_map(lift(AddTen))"
+        );
+    }
+
+    #[test]
+    fn keeps_round_trips_through_different_keys() {
+        let prog = then(
+            wrap_tagged::<IX_HLISTNODE>(),
+            unwrap_tagged::<IX_NEWTYPENODE>(),
+        );
+        assert_eq!(
+            pretty(&optimize(prog)).to_string(),
+            "wrap[hlist] >>> unwrap[newtype]"
+        );
+    }
+
+    #[test]
+    fn chained_container_maps_become_one_pass() {
+        let prog = then(
+            then(id(), map_children_prog(op_lift(AddTen))),
+            map_children_prog(op_lift(AddTen)),
+        );
+        let lowered = lower::<HlistNode<(u8, u16)>, _>(prog);
+        assert_eq!(
+            pretty(&optimize(lowered)).to_string(),
+            "unwrap[hlist] >>> hlist_map(lift(ThenOp<AddTen, AddTen>)) >>> wrap[hlist]"
+        );
+
+        let out: (u8, u16) = Container::new((1_u8, 2_u16))
+            .map(AddTen)
+            .map(AddTen)
+            .collect();
+        assert_eq!(out, (21, 22));
+    }
+}
@@ -1,33 +1,22 @@
-//! Closed-table reify: Combinators domain (full table `U0..U9`).
+//! Closed-table reify: Combinators domain (full table `U0..U20`).
 //!
 //! Dense encoding: only kernel keys that survive lowering.
 
-use crate::compiler::reify::ReifyTable;
-use crate::compiler::table::ReifyByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_COMBINATORS, R_SEMANTICS};
+use crate::compiler::reify::ReifyDomain;
+use crate::D_COMBINATORS;
+use crate::{CombinatorsSemanticsTable, Domain, OpOnce, Tagged};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: ReifyTable dispatches to this domain's table
+// Routing: ReifyTable dispatches to this domain's table (via `ReifyDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
-// Combinators-domain keys: wired to the combinators table (U0..U9).
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_COMBINATORS>, R_SEMANTICS, Ix>, Payload>>
-    for ReifyTable<Input>
-where
-    ReifyByTable<Input, ReifyTableCombinatorsFull<Input>>:
-        OpOnce<Tagged<Key<Domain<D_COMBINATORS>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <ReifyByTable<Input, ReifyTableCombinatorsFull<Input>> as OpOnce<
-        Tagged<Key<Domain<D_COMBINATORS>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+// Combinators-domain keys: wired to the combinators table (U0..U20).
+impl<Input> ReifyDomain<Input> for Domain<D_COMBINATORS> {
+    type Table = ReifyTableCombinatorsFull<Input>;
 
     #[inline]
-    fn run(
-        self,
-        prog: Tagged<Key<Domain<D_COMBINATORS>, R_SEMANTICS, Ix>, Payload>,
-    ) -> Self::OutVal {
-        ReifyByTable::<Input, _>::new(reify_table_combinators_full::<Input>()).run(prog)
+    fn reify_table() -> Self::Table {
+        reify_table_combinators_full::<Input>()
     }
 }
 
@@ -40,23 +29,34 @@ use core::marker::PhantomData;
 use crate::{
     // semantics (runtime)
     ApplyOp,
+    AssocOp,
     BimapOp,
+    ChooseOp,
     ConstMOp,
+    DupOp,
+    Either,
+    EitherOp,
     FanoutOp,
+    FirstOp,
     FstOp,
     IdOp,
+    LeftOp,
     // payload/ast
     NullaryToken,
     PartialLOp,
     PartialROp,
+    RightOp,
+    SecondOp,
     SndOp,
+    SwapOp,
     ThenOp,
+    UnassocOp,
 };
 
-/// Full combinators reify table type (`U0..U9`).
+/// Full combinators reify table type (`U0..U20`, chunked at 11 + tail).
 ///
 /// Dense encoding: only kernel keys that survive lowering.
-pub type ReifyTableCombinatorsFull<Input> = (
+pub type ReifyTableCombinatorsFull<Input> = CombinatorsSemanticsTable<
     CompileId,              // U0: IX_ID
     CompileThen<Input>,     // U1: IX_THEN
     CompilePartialL<Input>, // U2: IX_PARTIAL_L
@@ -67,7 +67,18 @@ pub type ReifyTableCombinatorsFull<Input> = (
     CompileConstMove,       // U7: IX_CONST_MOVE
     CompileBimap<Input>,    // U8: IX_BIMAP
     CompileApply,           // U9: IX_APPLY
-);
+    CompileSwap,            // U10: IX_SWAP
+    CompileAssoc,           // U11: IX_ASSOC
+    CompileUnassoc,         // U12: IX_UNASSOC
+    CompileFirst<Input>,    // U13: IX_FIRST
+    CompileSecond<Input>,   // U14: IX_SECOND
+    CompileDup,             // U15: IX_DUP
+    CompileChoose<Input>,   // U16: IX_CHOOSE
+    CompileEither<Input>,   // U17: IX_EITHER
+    CompileLeft<Input>,     // U18: IX_LEFT
+    CompileRight<Input>,    // U19: IX_RIGHT
+    CompileThen<Input>,     // U20: IX_THEN_BALANCED (reified like IX_THEN)
+>;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // U0: IX_ID
@@ -278,7 +289,207 @@ impl OpOnce<NullaryToken> for CompileApply {
     }
 }
 
-/// Construct the full combinators table (`U0..U9`) for the given input type.
+// ─────────────────────────────────────────────────────────────────────────────
+// U10/U11/U12: IX_SWAP / IX_ASSOC / IX_UNASSOC (nullary)
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileSwap;
+impl OpOnce<NullaryToken> for CompileSwap {
+    type OutVal = SwapOp;
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        SwapOp
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileAssoc;
+impl OpOnce<NullaryToken> for CompileAssoc {
+    type OutVal = AssocOp;
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        AssocOp
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileUnassoc;
+impl OpOnce<NullaryToken> for CompileUnassoc {
+    type OutVal = UnassocOp;
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        UnassocOp
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U13/U14: IX_FIRST / IX_SECOND
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileFirst<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> CompileFirst<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A, B, KF, PF, F> OpOnce<Tagged<KF, PF>> for CompileFirst<(A, B)>
+where
+    crate::compiler::reify::ReifyTable<A>: OpOnce<Tagged<KF, PF>, OutVal = F>,
+{
+    type OutVal = FirstOp<F>;
+
+    #[inline]
+    fn run(self, f_prog: Tagged<KF, PF>) -> Self::OutVal {
+        let f = crate::compiler::reify::ReifyTable::<A>::new().run(f_prog);
+        FirstOp { f }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileSecond<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> CompileSecond<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A, B, KG, PG, G> OpOnce<Tagged<KG, PG>> for CompileSecond<(A, B)>
+where
+    crate::compiler::reify::ReifyTable<B>: OpOnce<Tagged<KG, PG>, OutVal = G>,
+{
+    type OutVal = SecondOp<G>;
+
+    #[inline]
+    fn run(self, g_prog: Tagged<KG, PG>) -> Self::OutVal {
+        let g = crate::compiler::reify::ReifyTable::<B>::new().run(g_prog);
+        SecondOp { g }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U15: IX_DUP
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileDup;
+impl OpOnce<NullaryToken> for CompileDup {
+    type OutVal = DupOp;
+    fn run(self, _: NullaryToken) -> Self::OutVal {
+        DupOp
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U16/U17: IX_CHOOSE / IX_EITHER
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileChoose<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> CompileChoose<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A, B, KF, PF, KG, PG, F, G> OpOnce<(Tagged<KF, PF>, Tagged<KG, PG>)>
+    for CompileChoose<Either<A, B>>
+where
+    crate::compiler::reify::ReifyTable<A>: OpOnce<Tagged<KF, PF>, OutVal = F>,
+    crate::compiler::reify::ReifyTable<B>: OpOnce<Tagged<KG, PG>, OutVal = G>,
+{
+    type OutVal = ChooseOp<F, G>;
+
+    #[inline]
+    fn run(self, (f_prog, g_prog): (Tagged<KF, PF>, Tagged<KG, PG>)) -> Self::OutVal {
+        let f = crate::compiler::reify::ReifyTable::<A>::new().run(f_prog);
+        let g = crate::compiler::reify::ReifyTable::<B>::new().run(g_prog);
+        ChooseOp { f, g }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileEither<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> CompileEither<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A, B, KF, PF, KG, PG, F, G> OpOnce<(Tagged<KF, PF>, Tagged<KG, PG>)>
+    for CompileEither<Either<A, B>>
+where
+    crate::compiler::reify::ReifyTable<A>: OpOnce<Tagged<KF, PF>, OutVal = F>,
+    crate::compiler::reify::ReifyTable<B>: OpOnce<Tagged<KG, PG>, OutVal = G>,
+{
+    type OutVal = EitherOp<F, G>;
+
+    #[inline]
+    fn run(self, (f_prog, g_prog): (Tagged<KF, PF>, Tagged<KG, PG>)) -> Self::OutVal {
+        let f = crate::compiler::reify::ReifyTable::<A>::new().run(f_prog);
+        let g = crate::compiler::reify::ReifyTable::<B>::new().run(g_prog);
+        EitherOp { f, g }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// U18/U19: IX_LEFT / IX_RIGHT
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileLeft<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> CompileLeft<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A, B, KF, PF, F> OpOnce<Tagged<KF, PF>> for CompileLeft<Either<A, B>>
+where
+    crate::compiler::reify::ReifyTable<A>: OpOnce<Tagged<KF, PF>, OutVal = F>,
+{
+    type OutVal = LeftOp<F>;
+
+    #[inline]
+    fn run(self, f_prog: Tagged<KF, PF>) -> Self::OutVal {
+        let f = crate::compiler::reify::ReifyTable::<A>::new().run(f_prog);
+        LeftOp { f }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileRight<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> CompileRight<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A, B, KG, PG, G> OpOnce<Tagged<KG, PG>> for CompileRight<Either<A, B>>
+where
+    crate::compiler::reify::ReifyTable<B>: OpOnce<Tagged<KG, PG>, OutVal = G>,
+{
+    type OutVal = RightOp<G>;
+
+    #[inline]
+    fn run(self, g_prog: Tagged<KG, PG>) -> Self::OutVal {
+        let g = crate::compiler::reify::ReifyTable::<B>::new().run(g_prog);
+        RightOp { g }
+    }
+}
+
+/// Construct the full combinators table (`U0..U20`) for the given input type.
 #[inline]
 pub const fn reify_table_combinators_full<Input>() -> ReifyTableCombinatorsFull<Input> {
     (
@@ -292,13 +503,29 @@ pub const fn reify_table_combinators_full<Input>() -> ReifyTableCombinatorsFull<
         CompileConstMove,
         CompileBimap::new(),
         CompileApply,
+        CompileSwap,
+        (
+            CompileAssoc,
+            CompileUnassoc,
+            CompileFirst::new(),
+            CompileSecond::new(),
+            CompileDup,
+            CompileChoose::new(),
+            CompileEither::new(),
+            CompileLeft::new(),
+            CompileRight::new(),
+            CompileThen::new(),
+        ),
     )
 }
 
 #[cfg(test)]
 mod tests {
     use crate::compiler::Compile;
-    use crate::{fanout, op_lift, OpOnce};
+    use crate::{assert_type_eq, Dup, OpLift, ProgOut, Second, Swap, Then};
+    use crate::{assoc, dup, first, second, swap, then, unassoc};
+    use crate::{choose, either, left, right, Either};
+    use crate::{fanout, id, op_lift, OpOnce, OpTy};
 
     #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
     struct Inc;
@@ -310,6 +537,10 @@ mod tests {
         }
     }
 
+    impl OpTy<i32> for Inc {
+        type OutTy = i32;
+    }
+
     #[test]
     fn compile_closed_v2_fanout_uses_contiguous_u11_selection() {
         // Forces combinators-domain dispatch at IX_FANOUT = U11, which must NOT select the tail pointer slot.
@@ -317,4 +548,36 @@ mod tests {
         let out = Compile::<i32>::new().run(prog).run(41);
         assert_eq!(out, (42, 42));
     }
+
+    #[test]
+    fn product_plumbing_is_expressible_as_syntax() {
+        // x ↦ (x, x + 1), then swapped: (x + 1, x)
+        let prog = then(dup(), then(second(op_lift(Inc)), swap()));
+        assert_eq!(Compile::<i32>::new().run(prog).run(41), (42, 41));
+        assert_type_eq::<ProgOut<i32, Then<Dup, Then<Second<OpLift<Inc>>, Swap>>>, (i32, i32)>();
+
+        let prog = then(assoc(), then(second(first(op_lift(Inc))), unassoc()));
+        let out = Compile::<((u8, i32), char)>::new()
+            .run(prog)
+            .run(((1, 2), 'c'));
+        assert_eq!(out, ((1, 3), 'c'));
+    }
+
+    #[test]
+    fn sum_plumbing_is_expressible_as_syntax() {
+        type In = Either<i32, i32>;
+
+        let out = Compile::<In>::new()
+            .run(choose(op_lift(Inc), id()))
+            .run(Either::Left(1));
+        assert_eq!(out, Either::Left(2));
+
+        // left(f) >>> right(f) >>> either(id, id): increment whichever side is present.
+        let prog = then(
+            left(op_lift(Inc)),
+            then(right(op_lift(Inc)), either(id(), id())),
+        );
+        assert_eq!(Compile::<In>::new().run(prog).run(Either::Left(1)), 2);
+        assert_eq!(Compile::<In>::new().run(prog).run(Either::Right(10)), 11);
+    }
 }
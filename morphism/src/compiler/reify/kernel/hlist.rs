@@ -4,29 +4,21 @@
 //!
 //! Only kernel keys that survive lowering are present here (dense U0..U0).
 
-use crate::compiler::reify::ReifyTable;
-use crate::compiler::table::ReifyByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_HLIST, R_SEMANTICS};
+use crate::compiler::reify::ReifyDomain;
+use crate::D_HLIST;
+use crate::{Domain, HlistSemanticsTable, OpOnce};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: ReifyTable dispatches to this domain's table
+// Routing: ReifyTable dispatches to this domain's table (via `ReifyDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // HList semantics keys: dispatch using the hlist-domain table.
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_HLIST>, R_SEMANTICS, Ix>, Payload>>
-    for ReifyTable<Input>
-where
-    ReifyByTable<Input, ReifyTableHlist<Input>>:
-        OpOnce<Tagged<Key<Domain<D_HLIST>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <ReifyByTable<Input, ReifyTableHlist<Input>> as OpOnce<
-        Tagged<Key<Domain<D_HLIST>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> ReifyDomain<Input> for Domain<D_HLIST> {
+    type Table = ReifyTableHlist<Input>;
 
     #[inline]
-    fn run(self, prog: Tagged<Key<Domain<D_HLIST>, R_SEMANTICS, Ix>, Payload>) -> Self::OutVal {
-        ReifyByTable::<Input, _>::new(reify_table_hlist::<Input>()).run(prog)
+    fn reify_table() -> Self::Table {
+        reify_table_hlist::<Input>()
     }
 }
 
@@ -41,9 +33,9 @@ use crate::{HlistPushBack, NullaryToken};
 /// Reify table for HlistDomain semantics keys (indices `U0..U0`).
 ///
 /// Dense encoding: only kernel keys that survive lowering.
-pub type ReifyTableHlist<Input> = (
+pub type ReifyTableHlist<Input> = HlistSemanticsTable<
     CompileHlistPushBack<Input>, // U0: IX_HLIST_PUSH_BACK
-);
+>;
 
 #[inline]
 pub const fn reify_table_hlist<Input>() -> ReifyTableHlist<Input> {
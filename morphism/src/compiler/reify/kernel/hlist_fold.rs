@@ -2,34 +2,23 @@
 //!
 //! Routes recursion through `ReifyTable` for closed-world reification.
 //!
-//! Only kernel keys that survive lowering are present here (dense U0..U2).
+//! Only kernel keys that survive lowering are present here (dense U0..U3).
 
-use crate::compiler::reify::ReifyTable;
-use crate::compiler::table::ReifyByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_HLIST_FOLD, R_SEMANTICS};
+use crate::compiler::reify::ReifyDomain;
+use crate::D_HLIST_FOLD;
+use crate::{Domain, HlistFoldSemanticsTable, OpOnce};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: ReifyTable dispatches to this domain's table
+// Routing: ReifyTable dispatches to this domain's table (via `ReifyDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // HList fold semantics keys: dispatch using the hlist-fold-domain table.
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_HLIST_FOLD>, R_SEMANTICS, Ix>, Payload>>
-    for ReifyTable<Input>
-where
-    ReifyByTable<Input, ReifyTableHlistFold<Input>>:
-        OpOnce<Tagged<Key<Domain<D_HLIST_FOLD>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <ReifyByTable<Input, ReifyTableHlistFold<Input>> as OpOnce<
-        Tagged<Key<Domain<D_HLIST_FOLD>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> ReifyDomain<Input> for Domain<D_HLIST_FOLD> {
+    type Table = ReifyTableHlistFold<Input>;
 
     #[inline]
-    fn run(
-        self,
-        prog: Tagged<Key<Domain<D_HLIST_FOLD>, R_SEMANTICS, Ix>, Payload>,
-    ) -> Self::OutVal {
-        ReifyByTable::<Input, _>::new(reify_table_hlist_fold::<Input>()).run(prog)
+    fn reify_table() -> Self::Table {
+        reify_table_hlist_fold::<Input>()
     }
 }
 
@@ -40,16 +29,20 @@ where
 use core::marker::PhantomData;
 
 use crate::hlist::map::HlistMap;
-use crate::{EvalProg, HlistComposeBalancedL, HlistToSegmentsL, NullaryToken};
+use crate::{
+    EvalProg, FoldEverythingStepL, HlistComposeBalancedL, HlistToSegmentsL, MapsChildren,
+    NullaryToken,
+};
 
-/// Reify table for HlistFoldDomain semantics keys (indices `U0..U2`).
+/// Reify table for HlistFoldDomain semantics keys (indices `U0..U3`).
 ///
 /// Dense encoding: only kernel keys that survive lowering.
-pub type ReifyTableHlistFold<Input> = (
-    CompileHlistToSegmentsL<Input>,      // U0: IX_HLIST_TO_SEGMENTS_L
-    CompileHlistComposeBalancedL<Input>, // U1: IX_HLIST_COMPOSE_BALANCED_L
-    CompileHlistMapRt<Input>,            // U2: IX_HLIST_MAP (runtime bridge key)
-);
+pub type ReifyTableHlistFold<Input> = HlistFoldSemanticsTable<
+    CompileHlistToSegmentsL<Input>,           // U0: IX_HLIST_TO_SEGMENTS_L
+    CompileHlistComposeBalancedL<Input>,      // U1: IX_HLIST_COMPOSE_BALANCED_L
+    CompileHlistMapRt<Input>,                 // U2: IX_HLIST_MAP (runtime bridge key)
+    CompileHlistFoldEverythingStepLRt<Input>, // U3: IX_HLIST_FOLD_EVERYTHING_STEP_L (runtime bridge key)
+>;
 
 #[inline]
 pub const fn reify_table_hlist_fold<Input>() -> ReifyTableHlistFold<Input> {
@@ -57,6 +50,7 @@ pub const fn reify_table_hlist_fold<Input>() -> ReifyTableHlistFold<Input> {
         CompileHlistToSegmentsL::new(),
         CompileHlistComposeBalancedL::new(),
         CompileHlistMapRt::new(),
+        CompileHlistFoldEverythingStepLRt::new(),
     )
 }
 
@@ -115,7 +109,12 @@ impl<Input> CompileHlistMapRt<Input> {
     }
 }
 
-impl<Input, StepProg> OpOnce<StepProg> for CompileHlistMapRt<Input> {
+// `MapsChildren` checks the step against each child up front, so a child the step cannot run
+// on is reported by index (`RunsOnChild`) rather than from inside `HlistMap`.
+impl<Input, StepProg> OpOnce<StepProg> for CompileHlistMapRt<Input>
+where
+    StepProg: MapsChildren<Input>,
+{
     type OutVal = HlistMap<EvalProg<StepProg>>;
 
     #[inline]
@@ -123,3 +122,23 @@ impl<Input, StepProg> OpOnce<StepProg> for CompileHlistMapRt<Input> {
         HlistMap::new(EvalProg::new(step))
     }
 }
+
+// U3: IX_HLIST_FOLD_EVERYTHING_STEP_L (runtime bridge key - children compile on demand)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CompileHlistFoldEverythingStepLRt<Input>(PhantomData<fn() -> Input>);
+
+impl<Input> CompileHlistFoldEverythingStepLRt<Input> {
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<Input, FProg> OpOnce<FProg> for CompileHlistFoldEverythingStepLRt<Input> {
+    type OutVal = FoldEverythingStepL<FProg>;
+
+    #[inline]
+    fn run(self, f_prog: FProg) -> Self::OutVal {
+        FoldEverythingStepL::new(f_prog)
+    }
+}
@@ -5,7 +5,7 @@
 //! - tagged.rs: kernel tagged operations
 
 pub mod combinators;
-pub mod op;
 pub mod hlist;
 pub mod hlist_fold;
-pub mod tagged;
\ No newline at end of file
+pub mod op;
+pub mod tagged;
@@ -2,29 +2,21 @@
 //!
 //! `IX_OP_LIFT` is the opaque escape hatch: it reifies by unwrapping the payload op as-is.
 
-use crate::compiler::reify::ReifyTable;
-use crate::compiler::table::ReifyByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_OP, R_SEMANTICS};
+use crate::compiler::reify::ReifyDomain;
+use crate::D_OP;
+use crate::{Domain, OpOnce, OpSemanticsTable};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: ReifyTable dispatches to this domain's table
+// Routing: ReifyTable dispatches to this domain's table (via `ReifyDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // Op-lift keys: dispatch using the op-domain table (single entry).
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_OP>, R_SEMANTICS, Ix>, Payload>>
-    for ReifyTable<Input>
-where
-    ReifyByTable<Input, (CompileOpLift<Input>,)>:
-        OpOnce<Tagged<Key<Domain<D_OP>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal = <ReifyByTable<Input, (CompileOpLift<Input>,)> as OpOnce<
-        Tagged<Key<Domain<D_OP>, R_SEMANTICS, Ix>, Payload>,
-    >>::OutVal;
+impl<Input> ReifyDomain<Input> for Domain<D_OP> {
+    type Table = OpSemanticsTable<CompileOpLift<Input>>;
 
     #[inline]
-    fn run(self, prog: Tagged<Key<Domain<D_OP>, R_SEMANTICS, Ix>, Payload>) -> Self::OutVal {
-        ReifyByTable::<Input, _>::new(reify_table_op::<Input>()).run(prog)
+    fn reify_table() -> Self::Table {
+        reify_table_op::<Input>()
     }
 }
 
@@ -4,30 +4,21 @@
 //! (two entries). This avoids the "missing impl cascade" while establishing the core
 //! mechanics we will later scale to other domains.
 
-use crate::compiler::reify::ReifyTable;
-use crate::compiler::table::ReifyByTable;
-use crate::{Domain, Key, OpOnce, Tagged};
-use crate::{D_TAGGED, R_SEMANTICS};
+use crate::compiler::reify::ReifyDomain;
+use crate::D_TAGGED;
+use crate::{Domain, OpOnce, TaggedSemanticsTable};
 
 // ─────────────────────────────────────────────────────────────────────────────
-// Routing: ReifyTable dispatches to this domain's table
+// Routing: ReifyTable dispatches to this domain's table (via `ReifyDomain`)
 // ─────────────────────────────────────────────────────────────────────────────
 
 // Tagged-domain keys: dispatch using the tagged-domain table (Wrap/Unwrap).
-impl<Input, Ix, Payload> OpOnce<Tagged<Key<Domain<D_TAGGED>, R_SEMANTICS, Ix>, Payload>>
-    for ReifyTable<Input>
-where
-    ReifyByTable<Input, (CompileWrapTagged<Input>, CompileUnwrapTagged<Input>)>:
-        OpOnce<Tagged<Key<Domain<D_TAGGED>, R_SEMANTICS, Ix>, Payload>>,
-{
-    type OutVal =
-        <ReifyByTable<Input, (CompileWrapTagged<Input>, CompileUnwrapTagged<Input>)> as OpOnce<
-            Tagged<Key<Domain<D_TAGGED>, R_SEMANTICS, Ix>, Payload>,
-        >>::OutVal;
+impl<Input> ReifyDomain<Input> for Domain<D_TAGGED> {
+    type Table = TaggedSemanticsTable<CompileWrapTagged<Input>, CompileUnwrapTagged<Input>>;
 
     #[inline]
-    fn run(self, prog: Tagged<Key<Domain<D_TAGGED>, R_SEMANTICS, Ix>, Payload>) -> Self::OutVal {
-        ReifyByTable::<Input, _>::new(reify_table_tagged::<Input>()).run(prog)
+    fn reify_table() -> Self::Table {
+        reify_table_tagged::<Input>()
     }
 }
 
@@ -1,8 +1,13 @@
 use core::marker::PhantomData;
 
-/// Closed-table reifier: supports a small subset of domains via per-domain tables.
+use crate::compiler::table::ReifyByTable;
+use crate::{Key, OpOnce, Tagged};
+
+/// Closed-table reifier: routes each (lowered) semantics key to its domain's table.
 ///
-/// Domain-specific routing impls are co-located with their table definitions in `reify/kernel/*.rs`:
+/// Routing is a single impl over `Key<D, R, Ix>` where `D: ReifyDomain<Input>`. Roles are not
+/// checked here: `Compile` walks the lowered program first (`KernelOnly`, see `compiler/verify`).
+/// Built-in domains implement `ReifyDomain` next to their table definitions in `reify/kernel/*.rs`:
 /// - `op.rs`: D_OP domain
 /// - `tagged.rs`: D_TAGGED domain
 /// - `combinators.rs`: D_COMBINATORS domain
@@ -18,4 +23,105 @@ impl<Input> ReifyTable<Input> {
     }
 }
 
+/// Reify extension point: a domain supplies the closed table that turns its kernel keys
+/// into runnable ops.
+///
+/// `Table` is a handler tuple indexed like the domain's `LowerDomain` table; each handler
+/// maps a lowered payload to an `OpOnce<Input>` (recursing through `ReifyTable<Input>` for
+/// child programs). Only domains whose keys survive lowering need this.
+#[diagnostic::on_unimplemented(
+    message = "domain `{Self}` has no reify table for input `{Input}`",
+    label = "no `ReifyDomain<{Input}>` impl for this domain",
+    note = "implement `ReifyDomain<Input>` for the domain type next to its table",
+    note = "frontend domains (everything lowers away) have no reify table; their keys must not reach reify"
+)]
+pub trait ReifyDomain<Input> {
+    type Table;
+
+    fn reify_table() -> Self::Table;
+}
+
+// Each node's domain must have a reify table, and its slot a real handler (not `NoReifyRule`).
+impl<Input, D, R, Ix, Payload> OpOnce<Tagged<Key<D, R, Ix>, Payload>> for ReifyTable<Input>
+where
+    D: ReifyDomain<Input>,
+    ReifyByTable<Input, D::Table>: OpOnce<Tagged<Key<D, R, Ix>, Payload>>,
+{
+    type OutVal = <ReifyByTable<Input, D::Table> as OpOnce<Tagged<Key<D, R, Ix>, Payload>>>::OutVal;
+
+    #[inline]
+    fn run(self, prog: Tagged<Key<D, R, Ix>, Payload>) -> Self::OutVal {
+        ReifyByTable::<Input, _>::new(D::reify_table()).run(prog)
+    }
+}
+
 pub(crate) mod kernel;
+
+#[cfg(test)]
+mod tests {
+    use crate::{compile, shape, then, Ext, LowerDomain, NodeShape, NullaryToken};
+    use crate::{OpOnce, ReifyDomain, Tagged, U0, U1};
+
+    crate::declare_domain! {
+        /// A domain the compiler has never heard of, plugged in through the extension traits.
+        domain struct UserDomain: Ext<U1, U0>;
+
+        semantics {
+            KX_INCR,
+        }
+    }
+
+    impl NodeShape<KX_INCR> for UserDomain {
+        type Shape = shape::Nullary;
+    }
+
+    type Incr = Tagged<KX_INCR, NullaryToken>;
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct LowerIncr;
+
+    impl OpOnce<NullaryToken> for LowerIncr {
+        type OutVal = Incr;
+        fn run(self, t: NullaryToken) -> Self::OutVal {
+            Tagged::new(t)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct IncrOp;
+
+    impl OpOnce<u32> for IncrOp {
+        type OutVal = u32;
+        fn run(self, x: u32) -> u32 {
+            x + 1
+        }
+    }
+
+    impl OpOnce<NullaryToken> for IncrOp {
+        type OutVal = IncrOp;
+        fn run(self, _: NullaryToken) -> IncrOp {
+            IncrOp
+        }
+    }
+
+    impl<Input> LowerDomain<Input> for UserDomain {
+        type Table = (LowerIncr,);
+        fn lower_table() -> Self::Table {
+            (LowerIncr,)
+        }
+    }
+
+    impl<Input> ReifyDomain<Input> for UserDomain {
+        type Table = (IncrOp,);
+        fn reify_table() -> Self::Table {
+            (IncrOp,)
+        }
+    }
+
+    #[test]
+    fn user_domain_compiles_alongside_builtin_domains() {
+        let incr: Incr = Tagged::new(NullaryToken);
+        let prog = then(incr, then(incr, incr));
+        assert_eq!(compile::<u32, _>(prog).run(1), 4);
+    }
+}
@@ -1,12 +1,14 @@
 use core::marker::PhantomData;
 
-use crate::compiler::compiler_closed_table::ClosedTableGetAt;
-use crate::{Key, OpOnce, OpTy, OpTyOut, Tagged};
+use crate::compiler::diagnostics::TableEntry;
+use crate::compiler::explain;
+use crate::{Key, KeyIdent, NatToUsize, OpOnce, OpTy, OpTyOut, Tagged};
 
 /// Generic closed-table dispatcher: program AST -> result, via a handler table.
 ///
 /// This is the shared implementation for both lowering and reification.
-/// Table selection uses flat indexing (`ClosedTableGetAt<Ix>`) where `U11` means the 12th element.
+/// Table selection uses flat indexing (`ClosedTableGetAt<Ix>`, through `TableEntry`) where `U11`
+/// means the 12th element.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ByTable<Input, Table> {
     table: Table,
@@ -32,7 +34,8 @@ impl<Input, Table> ByTable<Input, Table> {
 impl<Input, D, R, Ix, Payload, Table, HandlerOp> OpTy<Tagged<Key<D, R, Ix>, Payload>>
     for ByTable<Input, Table>
 where
-    ClosedTableGetAt<Ix>: OpTy<Table, OutTy = HandlerOp>,
+    D: KeyIdent<Key<D, R, Ix>>,
+    Table: TableEntry<Key<D, R, Ix>, <D as KeyIdent<Key<D, R, Ix>>>::Name, Handler = HandlerOp>,
     HandlerOp: OpTy<Payload>,
 {
     type OutTy = OpTyOut<HandlerOp, Payload>;
@@ -42,14 +45,17 @@ where
 impl<Input, D, R, Ix, Payload, Table, HandlerOp> OpOnce<Tagged<Key<D, R, Ix>, Payload>>
     for ByTable<Input, Table>
 where
-    ClosedTableGetAt<Ix>: OpOnce<Table, OutVal = HandlerOp>,
+    D: KeyIdent<Key<D, R, Ix>>,
+    Table: TableEntry<Key<D, R, Ix>, <D as KeyIdent<Key<D, R, Ix>>>::Name, Handler = HandlerOp>,
     HandlerOp: OpOnce<Payload>,
+    Ix: NatToUsize,
 {
     type OutVal = <HandlerOp as OpOnce<Payload>>::OutVal;
 
     #[inline]
     fn run(self, prog: Tagged<Key<D, R, Ix>, Payload>) -> Self::OutVal {
-        let handler_op = ClosedTableGetAt::<Ix>::new().run(self.table);
+        let handler_op = self.table.entry();
+        let _step = explain::enter::<Input, D, Ix, HandlerOp>();
         handler_op.run(prog.into_inner())
     }
 }
@@ -0,0 +1,230 @@
+//! Kernel-only check: every node that reaches reify must be a kernel node.
+//!
+//! `Compile` walks the lowered program before optimizing it (`KernelOnly`): each node's key must
+//! have role `R_SEMANTICS`, and the walk descends into the node's child programs as its domain's
+//! `NodeShape` names them (`kit/shape.rs`). A frontend key that survives lowering is reported by
+//! its `KeyIdent::Name` (the key as declared, with its index), wherever it sits in the program:
+//!
+//! - wrong role: `KernelCheck` ("lowered program still contains the syntax key `IX_IF<0>`")
+//! - no payload shape for the key: `NodeShape` ("domain `D` declares no payload shape ..")
+//!
+//! Reify then routes each node through its domain's table, which rejects the rest:
+//!
+//! - no reify table for the domain: `ReifyDomain` ("domain `D` has no reify table ..")
+//! - a `NoReifyRule` slot: `HasReifyRule` ("key `IX_SHOUT<2>` has no reify rule")
+//!
+//! ## Contents
+//!
+//! - `KernelRole<Name>`: the role check; `KernelCheck` (on the key's name) carries the message
+//! - `KernelOnly`: the walk, with its `KernelVerdict` (`Passed`, or `Rejected` after a failed
+//!   check); `verify_kernel(prog)` passes a program through it (`Verified<Prog>`)
+//! - `KernelChildren<K, Payload>`: the child programs the walk descends into, per payload shape
+//!
+//! Runtime-bridge keys (`hlist_map`, `hlist_fold_everything_step_l`) keep their step programs
+//! unlowered until `EvalProg` compiles them per element (shape `Step`). The walk does not descend
+//! into steps; each step is walked after its own lowering, by that inner `Compile`.
+
+use crate::shape::{
+    Branches, Custom, IndexParam, KeyParam, Nullary, Prog, ProgPair, ProgValue, Step, Value,
+    ValueProg,
+};
+use crate::ProgChildren;
+use crate::{Key, KeyIdent, NodeShape, ShapeOf, Tagged, R_SEMANTICS};
+
+/// Never implemented: the bound a syntax key fails with (on the key's `KeyIdent::Name`).
+#[diagnostic::on_unimplemented(
+    message = "lowered program still contains the syntax key `{Self}`",
+    label = "lowering left a non-kernel node in this program",
+    note = "only `R_SEMANTICS` keys may reach reify; the domain's lowering table must rewrite this key",
+    note = "`{Self}` is the key as declared in `declare_domain!`, with its index in its role block"
+)]
+pub trait KernelCheck {}
+
+/// Role `Self` for a key named `Name`: `R_SEMANTICS` passes; `R_SYNTAX` requires
+/// `Name: KernelCheck` (never implemented), so it fails with that message and yields `Rejected`
+/// (its impl sits with the other witnesses in `compiler/diagnostics`).
+pub trait KernelRole<Name> {
+    type Verdict: KernelVerdict;
+}
+
+impl<Name> KernelRole<Name> for R_SEMANTICS {
+    type Verdict = Passed;
+}
+
+/// Outcome of the kernel-only walk over a subtree: `Passed`, or `Rejected` once a syntax key
+/// failed `KernelCheck`.
+///
+/// Failing keys yield a type instead of an unsatisfied bound so that `Compile` reports the key
+/// once, not once per where-clause that mentions the walk; `Rejected` then passes through the
+/// optimizer and reify like any other fired witness.
+pub trait KernelVerdict {
+    /// The verdict over this subtree and another: `V` if this one passed, `Rejected` otherwise.
+    type And<V: KernelVerdict>: KernelVerdict;
+
+    /// `T` if the walk passed, `Rejected` otherwise.
+    type Keep<T>;
+
+    fn keep<T>(prog: T) -> Self::Keep<T>;
+}
+
+/// Every key of the walked subtree is a kernel key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Passed;
+
+impl KernelVerdict for Passed {
+    type And<V: KernelVerdict> = V;
+    type Keep<T> = T;
+
+    #[inline]
+    fn keep<T>(prog: T) -> T {
+        prog
+    }
+}
+
+/// Child programs of a node `Tagged<K, Payload>` that the kernel walk descends into
+/// (implemented on payload shapes): every child program except a `Step`.
+pub trait KernelChildren<K, Payload> {
+    type Children;
+}
+
+macro_rules! kernel_children_as_prog_children {
+    ( $( $S:ty ),* ) => {
+        $(
+            impl<K, Payload> KernelChildren<K, Payload> for $S
+            where
+                $S: ProgChildren<K, Payload>,
+            {
+                type Children = <$S as ProgChildren<K, Payload>>::Children;
+            }
+        )*
+    };
+}
+
+kernel_children_as_prog_children!(
+    Nullary, Prog, ProgPair, ProgValue, ValueProg, Branches, Value, KeyParam, IndexParam
+);
+
+// Lowered and walked by the inner `Compile` of `EvalProg`, per element.
+impl<K, P> KernelChildren<K, P> for Step {
+    type Children = ();
+}
+
+impl<K, Payload, S: KernelChildren<K, Payload>> KernelChildren<K, Payload> for Custom<S> {
+    type Children = S::Children;
+}
+
+/// The kernel-only walk (see module docs): `Verdict` is `Passed` if every node of the (lowered)
+/// program has a kernel key.
+///
+/// Implemented for `Tagged` nodes and for tuples of programs, like `ProgMetrics`.
+pub trait KernelOnly {
+    type Verdict: KernelVerdict;
+}
+
+/// Verdict of a key's role check.
+type RoleVerdict<D, R, Ix> = <R as KernelRole<<D as KeyIdent<Key<D, R, Ix>>>::Name>>::Verdict;
+
+/// Child programs the walk descends into below a node `Tagged<K, Payload>` of domain `D`.
+type WalkedChildren<D, K, Payload> = <ShapeOf<D, K> as KernelChildren<K, Payload>>::Children;
+
+impl<D, R, Ix, Payload> KernelOnly for Tagged<Key<D, R, Ix>, Payload>
+where
+    D: KeyIdent<Key<D, R, Ix>> + NodeShape<Key<D, R, Ix>>,
+    R: KernelRole<<D as KeyIdent<Key<D, R, Ix>>>::Name>,
+    ShapeOf<D, Key<D, R, Ix>>: KernelChildren<Key<D, R, Ix>, Payload>,
+    WalkedChildren<D, Key<D, R, Ix>, Payload>: KernelOnly,
+{
+    type Verdict = <RoleVerdict<D, R, Ix> as KernelVerdict>::And<
+        <WalkedChildren<D, Key<D, R, Ix>, Payload> as KernelOnly>::Verdict,
+    >;
+}
+
+impl KernelOnly for () {
+    type Verdict = Passed;
+}
+
+macro_rules! impl_tuple_kernel_only {
+    () => {};
+    ( $A0:ident $(, $A:ident )* ) => {
+        impl<$A0: KernelOnly, $( $A, )*> KernelOnly for ($A0, $( $A, )*)
+        where
+            ($( $A, )*): KernelOnly,
+        {
+            type Verdict = <$A0::Verdict as KernelVerdict>::And<<($( $A, )*) as KernelOnly>::Verdict>;
+        }
+
+        impl_tuple_kernel_only!($( $A ),*);
+    };
+}
+
+impl_tuple_kernel_only!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+
+/// A lowered program after the kernel-only walk: `Prog` itself, or `Rejected`.
+pub type Verified<Prog> = <<Prog as KernelOnly>::Verdict as KernelVerdict>::Keep<Prog>;
+
+/// Pass a lowered program through the kernel-only walk, failing to compile unless it is
+/// kernel-only.
+#[inline]
+pub fn verify_kernel<Prog: KernelOnly>(prog: Prog) -> Verified<Prog> {
+    <Prog::Verdict as KernelVerdict>::keep(prog)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_type_eq, fold_children_l_prog, hlist_map_prog, holds, id, if_prog, lower,
+        map_children_prog, op_lift, then, true_prog, HlistNode, LowerTable, MapChildrenProg,
+        OpLift, OpOnce, OpTy,
+    };
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    impl<T> OpTy<T> for AddTen {
+        type OutTy = T;
+    }
+
+    impl<T: core::ops::Add<Output = T> + From<u8>> OpOnce<T> for AddTen {
+        type OutVal = T;
+        fn run(self, x: T) -> T {
+            x + T::from(10)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Sum;
+
+    impl<X: Into<u64>> OpOnce<(u64, X)> for Sum {
+        type OutVal = u64;
+        fn run(self, (acc, x): (u64, X)) -> u64 {
+            acc + x.into()
+        }
+    }
+
+    type H = HlistNode<(u8, u16)>;
+    type LowerOut<P, Prog> = <LowerTable<P> as OpOnce<Prog>>::OutVal;
+
+    fn is_kernel_only<P: KernelOnly<Verdict = Passed>>(_: &P) {}
+
+    #[test]
+    fn lowered_programs_are_kernel_only() {
+        is_kernel_only(&lower::<H, _>(map_children_prog(op_lift(AddTen))));
+
+        let folded = then(
+            map_children_prog(op_lift(AddTen)),
+            fold_children_l_prog(op_lift(Sum), 0_u64),
+        );
+        is_kernel_only(&verify_kernel(lower::<H, _>(folded)));
+
+        type Lowered = LowerOut<H, MapChildrenProg<OpLift<AddTen>>>;
+        assert_type_eq::<Verified<Lowered>, Lowered>();
+    }
+
+    #[test]
+    fn runtime_bridge_steps_are_not_walked() {
+        // `hlist_map`'s step is compiled per element later; it may still be a frontend program.
+        let step = if_prog(holds(&true_prog()), op_lift(AddTen), id());
+        is_kernel_only(&hlist_map_prog(step));
+    }
+}
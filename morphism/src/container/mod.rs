@@ -4,12 +4,13 @@ pub use from_container::*;
 mod into_container;
 pub use into_container::*;
 
+mod std_sum_bridge;
 mod std_tuple_bridge;
 
 use crate::Compile;
 use crate::{
-    fold_children_l_prog, id, map_children_prog, op_lift, FoldChildrenLProg, MapChildrenProg,
-    OpLift,
+    fold_children_l_prog, fold_everything_l_prog, id, map_children_prog, op_lift,
+    FoldChildrenLProg, FoldEverythingLProg, MapChildrenProg, OpLift,
 };
 use crate::{then, Id, OpOnce, Tagged, Then};
 use core::marker::PhantomData;
@@ -49,6 +50,12 @@ where
             _t: PhantomData,
         }
     }
+
+    /// Give back the container representation (the identity program carries nothing).
+    #[inline]
+    pub(crate) fn into_tagged(self) -> Tagged<T::Tag, T::Payload> {
+        self.input
+    }
 }
 
 impl<T, Prog> Container<T, Prog>
@@ -141,4 +148,16 @@ where
     ) -> Container<T, Then<Prog, FoldChildrenLProg<OpLift<Op>, Acc>>> {
         self.then(fold_children_l_prog(op_lift(op), acc))
     }
+
+    /// Deep fold over every leaf below the generic node (depth-first, left-to-right).
+    ///
+    /// Nested products are traversed; `NewTypeNode` leaves are folded as `op(acc, payload)`.
+    #[inline]
+    pub fn fold_everything_l<Op, Acc>(
+        self,
+        op: Op,
+        acc: Acc,
+    ) -> Container<T, Then<Prog, FoldEverythingLProg<OpLift<Op>, Acc>>> {
+        self.then(fold_everything_l_prog(op_lift(op), acc))
+    }
 }
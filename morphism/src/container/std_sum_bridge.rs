@@ -0,0 +1,142 @@
+use crate::{Container, FromContainer, IntoContainer};
+use crate::{Either, Id, Tagged, IX_SUMNODE};
+
+// Tag `Result` / `Option` as binary sums by viewing them as `SumNode<Either<_, _>>`.
+//
+// The success side is the left one (`Ok` / `Some`), so `either(on_ok, on_err)` reads in
+// source order. `Option<T>` is `Either<T, ()>`.
+impl<T, E> IntoContainer for Result<T, E> {
+    type Tag = IX_SUMNODE;
+    type Payload = Either<T, E>;
+
+    #[inline]
+    fn into_container(self) -> Container<Self, Id> {
+        Container::<Self, Id>::from_tagged(Tagged::new(self.into()))
+    }
+}
+
+// Like tuples, `collect` returns the new payload as-is (an `Either`, or whatever `either`
+// eliminated it into). Convert back with `Result::from` / `Option::from`.
+impl<T, E> FromContainer for Result<T, E> {
+    type Rewrap<NewPayload> = NewPayload;
+
+    #[inline]
+    fn from_container<NewPayload>(payload: NewPayload) -> Self::Rewrap<NewPayload> {
+        payload
+    }
+}
+
+impl<T> IntoContainer for Option<T> {
+    type Tag = IX_SUMNODE;
+    type Payload = Either<T, ()>;
+
+    #[inline]
+    fn into_container(self) -> Container<Self, Id> {
+        Container::<Self, Id>::from_tagged(Tagged::new(self.into()))
+    }
+}
+
+impl<T> FromContainer for Option<T> {
+    type Rewrap<NewPayload> = NewPayload;
+
+    #[inline]
+    fn from_container<NewPayload>(payload: NewPayload) -> Self::Rewrap<NewPayload> {
+        payload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{choose, either, op_lift, then, unwrap_tagged, wrap_tagged};
+    use crate::{Container, Either, OpOnce, OpTy, IX_SUMNODE};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Inc;
+
+    impl OpTy<i32> for Inc {
+        type OutTy = i32;
+    }
+
+    impl OpOnce<i32> for Inc {
+        type OutVal = i32;
+        fn run(self, x: i32) -> Self::OutVal {
+            x + 1
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Len;
+
+    impl OpTy<&'static str> for Len {
+        type OutTy = i32;
+    }
+
+    impl OpOnce<&'static str> for Len {
+        type OutVal = i32;
+        fn run(self, s: &'static str) -> Self::OutVal {
+            s.len() as i32
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Zero;
+
+    impl OpTy<()> for Zero {
+        type OutTy = i32;
+    }
+
+    impl OpOnce<()> for Zero {
+        type OutVal = i32;
+        fn run(self, _: ()) -> Self::OutVal {
+            0
+        }
+    }
+
+    #[test]
+    fn result_is_eliminated_by_either() {
+        let on_sum = || {
+            then(
+                unwrap_tagged::<IX_SUMNODE>(),
+                either(op_lift(Inc), op_lift(Len)),
+            )
+        };
+
+        let ok: i32 = Container::new(Ok::<i32, &'static str>(41))
+            .then(on_sum())
+            .run();
+        let err: i32 = Container::new(Err::<i32, &'static str>("oops"))
+            .then(on_sum())
+            .run();
+        assert_eq!((ok, err), (42, 4));
+    }
+
+    #[test]
+    fn result_is_mapped_by_choose_and_collected_back() {
+        let prog = then(
+            unwrap_tagged::<IX_SUMNODE>(),
+            then(
+                choose(op_lift(Inc), op_lift(Len)),
+                wrap_tagged::<IX_SUMNODE>(),
+            ),
+        );
+        let out = Container::new(Err::<i32, &'static str>("oops"))
+            .then(prog)
+            .collect();
+        assert_eq!(out, Either::Right(4));
+        assert_eq!(Result::from(out), Err::<i32, i32>(4));
+    }
+
+    #[test]
+    fn option_is_a_sum_with_unit_on_the_right() {
+        let on_sum = || {
+            then(
+                unwrap_tagged::<IX_SUMNODE>(),
+                either(op_lift(Inc), op_lift(Zero)),
+            )
+        };
+
+        let some: i32 = Container::new(Some(41)).then(on_sum()).run();
+        let none: i32 = Container::new(None::<i32>).then(on_sum()).run();
+        assert_eq!((some, none), (42, 0));
+    }
+}
@@ -0,0 +1,130 @@
+//! Boolean programs: type-level booleans computed from the input type at compile time.
+//!
+//! A boolean program lowers to a constant, `const_move(True)` or `const_move(False)`: leaves
+//! are the literals `True` / `False` (the `IX_TRUE` / `IX_FALSE` syntax nodes) and lifted
+//! predicates (`pred_prog`), combined with `not_prog`, `and_prog`, `or_prog`, `xor_prog`.
+//! Compiled, it returns that (zero-sized) value; `Holds<Prog>` turns it into a predicate for
+//! `if_prog`:
+//!
+//! ```rust,ignore
+//! let prog = if_prog(
+//!     holds(&and_prog(pred_prog(KeyIsOp::<IX_HLISTNODE>::default()), not_prog(pred_prog(HlistIsEmpty)))),
+//!     map_children_prog(op_lift(f)),
+//!     id(),
+//! );
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::{ConstMove, False, True};
+use crate::{LowerTable, NullaryToken, OpOnce, OpTy, Tagged};
+use crate::{IX_AND, IX_NOT, IX_OR, IX_PRED, IX_XOR};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Program nodes
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Literal `true` as a boolean program (the `IX_TRUE` syntax node).
+#[inline]
+pub const fn true_prog() -> True {
+    Tagged::new(NullaryToken)
+}
+
+/// Literal `false` as a boolean program (the `IX_FALSE` syntax node).
+#[inline]
+pub const fn false_prog() -> False {
+    Tagged::new(NullaryToken)
+}
+
+/// Boolean program: the type-level predicate `Pred` (`OpTy<Input>` ↦ `True` / `False`) applied
+/// to the lowering input. `Pred` is never run.
+pub type PredProg<Pred> = Tagged<IX_PRED, Pred>;
+
+/// Lift a type-level predicate into a boolean program (pure).
+#[inline]
+pub const fn pred_prog<Pred>(pred: Pred) -> PredProg<Pred> {
+    Tagged::new(pred)
+}
+
+/// Boolean program: negation of `P`.
+pub type NotProg<P> = Tagged<IX_NOT, P>;
+
+/// Construct `!p` (pure).
+#[inline]
+pub const fn not_prog<P>(p: P) -> NotProg<P> {
+    Tagged::new(p)
+}
+
+/// Boolean program: `P & Q`.
+pub type AndProg<P, Q> = Tagged<IX_AND, (P, Q)>;
+
+/// Construct `p & q` (pure).
+#[inline]
+pub const fn and_prog<P, Q>(p: P, q: Q) -> AndProg<P, Q> {
+    Tagged::new((p, q))
+}
+
+/// Boolean program: `P | Q`.
+pub type OrProg<P, Q> = Tagged<IX_OR, (P, Q)>;
+
+/// Construct `p | q` (pure).
+#[inline]
+pub const fn or_prog<P, Q>(p: P, q: Q) -> OrProg<P, Q> {
+    Tagged::new((p, q))
+}
+
+/// Boolean program: `P ^ Q`.
+pub type XorProg<P, Q> = Tagged<IX_XOR, (P, Q)>;
+
+/// Construct `p ^ q` (pure).
+#[inline]
+pub const fn xor_prog<P, Q>(p: P, q: Q) -> XorProg<P, Q> {
+    Tagged::new((p, q))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Reading lowered booleans
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A lowered boolean program: `const_move(True)` or `const_move(False)`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a lowered boolean program",
+    label = "expected `const_move(True)` or `const_move(False)` after lowering",
+    note = "boolean programs are built from `true_prog`, `false_prog`, `pred_prog` (with a predicate returning `True` / `False`) and the connectives"
+)]
+pub trait LoweredBool {
+    /// The constant: `True` / `False`.
+    type Value;
+}
+
+impl LoweredBool for ConstMove<True> {
+    type Value = True;
+}
+
+impl LoweredBool for ConstMove<False> {
+    type Value = False;
+}
+
+/// Value of the boolean program `Prog` on `Input` (type-level only).
+pub type BoolOf<Input, Prog> = <<LowerTable<Input> as OpOnce<Prog>>::OutVal as LoweredBool>::Value;
+
+/// Type-level predicate from a boolean program: `Input ↦ BoolOf<Input, Prog>`.
+///
+/// This is how a boolean program drives `if_prog`: `if_prog(holds(&prog), then_prog, else_prog)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Holds<Prog>(PhantomData<fn() -> Prog>);
+
+impl<Input, Prog> OpTy<Input> for Holds<Prog>
+where
+    LowerTable<Input>: OpOnce<Prog>,
+    <LowerTable<Input> as OpOnce<Prog>>::OutVal: LoweredBool,
+{
+    type OutTy = BoolOf<Input, Prog>;
+}
+
+/// Predicate for `if_prog` from a boolean program (the program value is only a type witness, so
+/// it is borrowed, not consumed).
+#[inline]
+pub const fn holds<Prog>(_prog: &Prog) -> Holds<Prog> {
+    Holds(PhantomData)
+}
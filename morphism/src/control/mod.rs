@@ -0,0 +1,49 @@
+//! Program-level control flow: branches chosen by type-level predicates.
+//!
+//! `if_prog(pred, then_prog, else_prog)` is one program with two arms. `pred` is an `OpTy`
+//! over the lowering input whose output is `True` or `False`; lowering replaces the node with
+//! the lowered arm it selects, so nothing of the `if` survives to reify:
+//!
+//! ```rust,ignore
+//! let prog = if_prog(KeyIsOp::<IX_HLISTNODE>::default(), map_children_prog(op_lift(f)), id());
+//! compile::<HlistNode<(u8, u16)>, _>(prog); // maps the children
+//! compile::<NewTypeNode<u8>, _>(prog);      // leaves the node as it is
+//! ```
+//!
+//! ## Contents
+//!
+//! - `IfProg<Pred, Then, Else>` / `if_prog`: the program node (`IX_IF`)
+//! - `bool_prog.rs`: boolean programs (`true_prog`, `pred_prog`, `not_prog`, `and_prog`, ..) and
+//!   `Holds<Prog>`, the predicate that branches on one
+//! - `repeat.rs`: `RepeatN<N, Prog>` / `repeat_n` (`IX_REPEAT`), unrolled into `N` sequential copies
+//!
+//! Lowering lives in `compiler/lower/desugar/control.rs` and `compiler/lower/desugar/bool.rs`.
+
+mod bool_prog;
+pub use bool_prog::*;
+
+mod repeat;
+pub use repeat::*;
+
+use crate::Tagged;
+use crate::IX_IF;
+
+/// High-level program: `Then` if `Pred` holds for the input type, else `Else`.
+///
+/// Payload is `(pred, then_prog, else_prog)`; `Pred` is a type-level predicate (`OpTy<Input>`
+/// with a `True` / `False` output) and is never run.
+///
+/// **Resolved during closed lowering**: `LowerTable<Input>` evaluates `Pred` on `Input` (the
+/// compile input; inside `EvalProg` steps, the element) and lowers only the selected arm. The
+/// other arm is dropped and need not compile for `Input`.
+pub type IfProg<Pred, Then, Else> = Tagged<IX_IF, (Pred, Then, Else)>;
+
+/// Construct a statically resolved branch (pure).
+#[inline]
+pub const fn if_prog<Pred, Then, Else>(
+    pred: Pred,
+    then_prog: Then,
+    else_prog: Else,
+) -> IfProg<Pred, Then, Else> {
+    Tagged::new((pred, then_prog, else_prog))
+}
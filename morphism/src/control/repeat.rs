@@ -0,0 +1,84 @@
+//! Bounded iteration: a program repeated a type-level number of times.
+
+use crate::{id, then_balanced, Id, Tagged, ThenBalanced};
+use crate::{UInt, UTerm, B0, B1, IX_REPEAT};
+
+/// High-level program: `Prog` run `N` times in sequence (`N` a `base::num` natural).
+///
+/// Payload is `(prog, n)`; `n` is a zero-sized natural and is never run.
+///
+/// **Unrolled during closed lowering**: `LowerTable<Input>` lowers `Prog` once (against `Input`,
+/// like every `then` step) and replaces the node with a balanced `ThenBalanced` tree of `N`
+/// copies (see `UnrollRepeat`). `N = 0` lowers to `id`. The optimizer keeps each
+/// `then_balanced` node whole, so the tree stays balanced through `compile`.
+pub type RepeatN<N, Prog> = Tagged<IX_REPEAT, (Prog, N)>;
+
+/// Construct a bounded repetition: `repeat_n::<U3, _>(p)` is `p >>> p >>> p` after lowering.
+#[inline]
+pub fn repeat_n<N: Default, Prog>(prog: Prog) -> RepeatN<N, Prog> {
+    Tagged::new((prog, N::default()))
+}
+
+/// Unroll `Self` (a canonical natural) copies of `P` into a `ThenBalanced` tree.
+///
+/// The tree follows the binary digits of `N`: `2k` is `h >>> h` and `2k + 1` is `p >>> h >>> h`,
+/// where `h` unrolls `k`. Type depth is `O(log N)`; `1` is `p` itself, with no `id` leftovers.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a canonical natural to repeat `{P}` by",
+    note = "use `U0..U255`, `nat!(n)` or a normalized `UInt` chain as the repeat count"
+)]
+pub trait UnrollRepeat<P> {
+    type Out;
+    fn unroll(prog: P) -> Self::Out;
+}
+
+impl<P> UnrollRepeat<P> for UTerm {
+    type Out = Id;
+
+    #[inline]
+    fn unroll(_: P) -> Self::Out {
+        id()
+    }
+}
+
+impl<P> UnrollRepeat<P> for UInt<B1, UTerm> {
+    type Out = P;
+
+    #[inline]
+    fn unroll(prog: P) -> Self::Out {
+        prog
+    }
+}
+
+impl<P, B, T> UnrollRepeat<P> for UInt<B0, UInt<B, T>>
+where
+    UInt<B, T>: UnrollRepeat<P>,
+    <UInt<B, T> as UnrollRepeat<P>>::Out: Clone,
+{
+    type Out =
+        ThenBalanced<<UInt<B, T> as UnrollRepeat<P>>::Out, <UInt<B, T> as UnrollRepeat<P>>::Out>;
+
+    #[inline]
+    fn unroll(prog: P) -> Self::Out {
+        let half = <UInt<B, T>>::unroll(prog);
+        then_balanced(half.clone(), half)
+    }
+}
+
+impl<P, B, T> UnrollRepeat<P> for UInt<B1, UInt<B, T>>
+where
+    P: Clone,
+    UInt<B, T>: UnrollRepeat<P>,
+    <UInt<B, T> as UnrollRepeat<P>>::Out: Clone,
+{
+    type Out = ThenBalanced<
+        P,
+        ThenBalanced<<UInt<B, T> as UnrollRepeat<P>>::Out, <UInt<B, T> as UnrollRepeat<P>>::Out>,
+    >;
+
+    #[inline]
+    fn unroll(prog: P) -> Self::Out {
+        let half = <UInt<B, T>>::unroll(prog.clone());
+        then_balanced(prog, then_balanced(half.clone(), half))
+    }
+}
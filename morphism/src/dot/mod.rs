@@ -0,0 +1,179 @@
+//! Graphviz export: render a reflected program as a DOT graph.
+//!
+//! Each program node becomes a box labeled with its key's short name, its registry identifiers
+//! (domain / role / key, as declared) and, on a secondary line, the raw codes; child programs hang off it by solid edges numbered by
+//! payload position, host values (lifted ops, constants, accumulators) by dashed edges to
+//! plain-text leaves:
+//!
+//! ```text
+//! digraph prog {
+//!   node [shape=box, fontname="monospace"];
+//!   n0 [label="then\nD_COMBINATORS / R_SEMANTICS / IX_THEN\nDomain<U1> r=U1 ix=U1"];
+//!   ..
+//! }
+//! ```
+//!
+//! Like `pretty`, this is a plain interpretation of the program value, so it works before and
+//! after lowering: `dot(&prog)`, `dot(&lower::<P, _>(prog))`.
+//!
+//! ## Contents
+//!
+//! - `DotNode<K, Payload>`: how a payload becomes edges (implemented on the payload shapes of
+//!   `kit/shape.rs`; a node's shape comes from its domain's `NodeShape` impl, its name and value
+//!   label from `PrettyKey`, its identifiers from the `KeyIdent` impl `declare_domain!` emits)
+//! - `ProgDot`: programs that can be exported (`Tagged` nodes with a name, identifiers and a shape)
+//! - `DotGraph`: the graph being built (nodes, program edges, value leaves)
+//! - `dot(&prog)`: the DOT source of a program
+
+mod shapes;
+
+use core::fmt::Write as _;
+
+use crate::{short_type_name, Key, KeyIdent, NodeShape, PrettyKey, ShapeOf, Tagged};
+
+/// Edges of a node `Tagged<K, Payload>` (implemented on payload shapes, once per shape).
+pub trait DotNode<K, Payload> {
+    /// Add `payload`'s child programs and host values below `node`; `value` labels the edge to
+    /// a host value.
+    fn dot_payload(value: &str, payload: &Payload, node: usize, graph: &mut DotGraph);
+}
+
+/// A reflected program that can be exported as a DOT graph.
+pub trait ProgDot {
+    /// Add this program's nodes to `graph`; returns the id of its root node.
+    fn dot_into(&self, graph: &mut DotGraph) -> usize;
+}
+
+impl<D, R, N, Payload> ProgDot for Tagged<Key<D, R, N>, Payload>
+where
+    D: PrettyKey<Key<D, R, N>> + KeyIdent<Key<D, R, N>> + NodeShape<Key<D, R, N>>,
+    ShapeOf<D, Key<D, R, N>>: DotNode<Key<D, R, N>, Payload>,
+{
+    fn dot_into(&self, graph: &mut DotGraph) -> usize {
+        let label = format!(
+            "{}\n{} / {} / {}\n{} r={} ix={}",
+            <D as PrettyKey<Key<D, R, N>>>::NAME,
+            <D as KeyIdent<Key<D, R, N>>>::DOMAIN,
+            <D as KeyIdent<Key<D, R, N>>>::ROLE,
+            <D as KeyIdent<Key<D, R, N>>>::KEY,
+            short_type_name::<D>(),
+            short_type_name::<R>(),
+            short_type_name::<N>(),
+        );
+        let node = graph.node(&label);
+        let value = <D as PrettyKey<Key<D, R, N>>>::VALUE;
+        <ShapeOf<D, Key<D, R, N>> as DotNode<Key<D, R, N>, Payload>>::dot_payload(
+            value, &self.0, node, graph,
+        );
+        node
+    }
+}
+
+/// A DOT graph under construction. Node ids are assigned in creation order (`n0`, `n1`, ..).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DotGraph {
+    body: String,
+    next: usize,
+}
+
+impl DotGraph {
+    /// Add a program node (a box); returns its id.
+    pub fn node(&mut self, label: &str) -> usize {
+        let id = self.next;
+        self.next += 1;
+        let _ = writeln!(self.body, "  n{id} [label=\"{}\"];", escape(label));
+        id
+    }
+
+    /// Add a child program below `parent`, on an edge labeled `edge`.
+    pub fn child<P: ProgDot>(&mut self, parent: usize, edge: &str, prog: &P) {
+        let child = prog.dot_into(self);
+        let _ = writeln!(
+            self.body,
+            "  n{parent} -> n{child} [label=\"{}\"];",
+            escape(edge)
+        );
+    }
+
+    /// Add a host value of type `V` below `parent` (a plain-text leaf on a dashed edge).
+    pub fn value<V: ?Sized>(&mut self, parent: usize, edge: &str) {
+        self.text(parent, edge, &short_type_name::<V>());
+    }
+
+    /// Add a plain-text leaf below `parent` (a dashed edge).
+    pub fn text(&mut self, parent: usize, edge: &str, text: &str) {
+        let id = self.next;
+        self.next += 1;
+        let _ = writeln!(
+            self.body,
+            "  n{id} [label=\"{}\", shape=plaintext];\n  n{parent} -> n{id} [label=\"{}\", style=dashed];",
+            escape(text),
+            escape(edge),
+        );
+    }
+
+    /// The DOT source: `digraph <name> { .. }`.
+    pub fn finish(self, name: &str) -> String {
+        format!(
+            "digraph {name} {{\n  node [shape=box, fontname=\"monospace\"];\n{}}}\n",
+            self.body
+        )
+    }
+}
+
+/// Export a program as a DOT graph named `prog` (see module docs).
+pub fn dot<P: ProgDot>(prog: &P) -> String {
+    let mut graph = DotGraph::default();
+    prog.dot_into(&mut graph);
+    graph.finish("prog")
+}
+
+/// Escape a label for a quoted DOT string (`\n` stays a line break).
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fanout, fst, id, lower, map_children_prog, op_lift, then, HlistNode};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    #[test]
+    fn exports_nodes_with_registry_names() {
+        let prog = then(id(), fanout(fst(), op_lift(AddTen)));
+        assert_eq!(
+            dot(&prog),
+            "digraph prog {
+  node [shape=box, fontname=\"monospace\"];
+  n0 [label=\"then\\nD_COMBINATORS / R_SEMANTICS / IX_THEN\\nDomain<U1> r=U1 ix=U1\"];
+  n1 [label=\"id\\nD_COMBINATORS / R_SEMANTICS / IX_ID\\nDomain<U1> r=U1 ix=U0\"];
+  n0 -> n1 [label=\"0\"];
+  n2 [label=\"fanout\\nD_COMBINATORS / R_SEMANTICS / IX_FANOUT\\nDomain<U1> r=U1 ix=U6\"];
+  n3 [label=\"fst\\nD_COMBINATORS / R_SEMANTICS / IX_FST\\nDomain<U1> r=U1 ix=U4\"];
+  n2 -> n3 [label=\"0\"];
+  n4 [label=\"lift\\nD_OP / R_SEMANTICS / IX_OP_LIFT\\nDomain<U5> r=U1 ix=U0\"];
+  n5 [label=\"AddTen\", shape=plaintext];
+  n4 -> n5 [label=\"op\", style=dashed];
+  n2 -> n4 [label=\"1\"];
+  n0 -> n2 [label=\"1\"];
+}
+"
+        );
+    }
+
+    #[test]
+    fn exports_lowered_programs() {
+        let prog = map_children_prog(op_lift(AddTen));
+        let lowered = lower::<HlistNode<(u8, u16)>, _>(prog);
+        let graph = dot(&lowered);
+        assert!(graph.contains("[label=\"hlist_map\\nD_HLIST_FOLD / R_SEMANTICS / IX_HLIST_MAP\\nDomain<U7> r=U1 ix=U2\"]"));
+        assert!(graph.contains("[label=\"hlist\", shape=plaintext]"));
+        assert_eq!(graph.matches("shape=plaintext").count(), 3);
+    }
+}
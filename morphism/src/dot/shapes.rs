@@ -0,0 +1,85 @@
+//! DOT edges of each payload shape (see `kit/shape.rs`).
+//!
+//! - nullary: no edges
+//! - programs (and steps): `p`, `(p, q)` become edges `0`, `1`
+//! - host values (constants, accumulators, node contents, lifted ops): dashed value leaves,
+//!   labeled with the key's `PrettyKey::VALUE` (`acc`, `env`, `op`, ..)
+//! - key / index parameters: a dashed leaf naming the key they wrap/unwrap, or the index
+
+use core::marker::PhantomData;
+
+use super::{DotGraph, DotNode, ProgDot};
+use crate::shape::{
+    Branches, Custom, IndexParam, KeyParam, Nullary, Prog, ProgPair, ProgValue, Step, Value,
+    ValueProg,
+};
+use crate::{KeyName, NullaryToken};
+
+impl<K> DotNode<K, NullaryToken> for Nullary {
+    fn dot_payload(_: &str, _: &NullaryToken, _: usize, _: &mut DotGraph) {}
+}
+
+impl<K, P: ProgDot> DotNode<K, P> for Prog {
+    fn dot_payload(_: &str, p: &P, node: usize, graph: &mut DotGraph) {
+        graph.child(node, "0", p);
+    }
+}
+
+impl<K, P: ProgDot> DotNode<K, P> for Step {
+    fn dot_payload(_: &str, p: &P, node: usize, graph: &mut DotGraph) {
+        graph.child(node, "0", p);
+    }
+}
+
+impl<K, P: ProgDot, Q: ProgDot> DotNode<K, (P, Q)> for ProgPair {
+    fn dot_payload(_: &str, (p, q): &(P, Q), node: usize, graph: &mut DotGraph) {
+        graph.child(node, "0", p);
+        graph.child(node, "1", q);
+    }
+}
+
+impl<K, P: ProgDot, V> DotNode<K, (P, V)> for ProgValue {
+    fn dot_payload(value: &str, (p, _): &(P, V), node: usize, graph: &mut DotGraph) {
+        graph.child(node, "0", p);
+        graph.value::<V>(node, value);
+    }
+}
+
+impl<K, V, P: ProgDot> DotNode<K, (V, P)> for ValueProg {
+    fn dot_payload(value: &str, (_, p): &(V, P), node: usize, graph: &mut DotGraph) {
+        graph.value::<V>(node, value);
+        graph.child(node, "0", p);
+    }
+}
+
+impl<K, Pred, P: ProgDot, Q: ProgDot> DotNode<K, (Pred, P, Q)> for Branches {
+    fn dot_payload(_: &str, (_, p, q): &(Pred, P, Q), node: usize, graph: &mut DotGraph) {
+        graph.value::<Pred>(node, "pred");
+        graph.child(node, "0", p);
+        graph.child(node, "1", q);
+    }
+}
+
+impl<K, V> DotNode<K, V> for Value {
+    fn dot_payload(value: &str, _: &V, node: usize, graph: &mut DotGraph) {
+        graph.value::<V>(node, value);
+    }
+}
+
+impl<K, T: KeyName> DotNode<K, PhantomData<fn() -> T>> for KeyParam {
+    fn dot_payload(_: &str, _: &PhantomData<fn() -> T>, node: usize, graph: &mut DotGraph) {
+        graph.text(node, "key", T::NAME);
+    }
+}
+
+impl<K, Ix> DotNode<K, PhantomData<fn() -> Ix>> for IndexParam {
+    fn dot_payload(_: &str, _: &PhantomData<fn() -> Ix>, node: usize, graph: &mut DotGraph) {
+        graph.value::<Ix>(node, "ix");
+    }
+}
+
+impl<K, Payload, S: DotNode<K, Payload>> DotNode<K, Payload> for Custom<S> {
+    fn dot_payload(value: &str, payload: &Payload, node: usize, graph: &mut DotGraph) {
+        S::dot_payload(value, payload, node, graph);
+    }
+}
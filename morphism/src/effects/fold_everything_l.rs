@@ -0,0 +1,165 @@
+use crate::{Compile, Either, IntoContainer, OpOnce, OpTy, OpTyOut, Tagged};
+use crate::{IX_FOLD_EVERYTHING_L, IX_HLIST_FOLD_EVERYTHING_STEP_L, IX_NEWTYPENODE};
+
+/// High-level prog: deep fold-left over every leaf of a nested product — dispatcher.
+///
+/// Traversal is depth-first, left-to-right:
+/// - `HlistNode<Children>`: each child is viewed through `DeepNode` and folded recursively
+/// - `NewTypeNode<S>`: atomic leaf, folded as `f(acc, s)`
+/// - `SumNode<Either<L, R>>` (`Option`, `Result`): the side that is present is folded recursively
+///
+/// **Dispatch happens during closed lowering** (`LowerFoldEverythingL`), like `FoldChildrenLProg`.
+pub type FoldEverythingLProg<FProg, Acc> = Tagged<IX_FOLD_EVERYTHING_L, (FProg, Acc)>;
+
+/// Construct a high-level deep fold prog (pure).
+#[inline]
+pub const fn fold_everything_l_prog<FProg, Acc>(
+    f_prog: FProg,
+    acc: Acc,
+) -> FoldEverythingLProg<FProg, Acc> {
+    Tagged::new((f_prog, acc))
+}
+
+/// Kernel program node: per-child step of the deep fold (runtime bridge key).
+///
+/// Payload is the leaf step program `FProg`; it reifies to `FoldEverythingStepL<FProg>`.
+/// Lowering emits this node as the step of the HList fold pipeline.
+pub type HlistFoldEverythingStepLProg<FProg> = Tagged<IX_HLIST_FOLD_EVERYTHING_STEP_L, FProg>;
+
+/// Construct a deep fold step program node.
+#[inline]
+pub const fn hlist_fold_everything_step_l_prog<FProg>(
+    f_prog: FProg,
+) -> HlistFoldEverythingStepLProg<FProg> {
+    Tagged::new(f_prog)
+}
+
+/// Runtime step of the deep fold: `(acc, child) ↦ fold_everything_l(f, acc)(child)`.
+///
+/// The child is converted into its node (`DeepNode`) and the deep fold program is compiled
+/// under that node on demand (like `EvalProg`). Children must therefore be host types with a
+/// container view (tuples, `Tagged`, user wrappers) or std scalars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FoldEverythingStepL<FProg>(pub FProg);
+
+impl<FProg> FoldEverythingStepL<FProg> {
+    #[inline]
+    pub const fn new(f_prog: FProg) -> Self {
+        Self(f_prog)
+    }
+}
+
+impl<FProg, Acc, X> OpTy<(Acc, X)> for FoldEverythingStepL<FProg>
+where
+    X: DeepNode,
+    Compile<Tagged<X::Tag, X::Payload>>: OpOnce<FoldEverythingLProg<FProg, Acc>>,
+    <Compile<Tagged<X::Tag, X::Payload>> as OpOnce<FoldEverythingLProg<FProg, Acc>>>::OutVal:
+        OpOnce<Tagged<X::Tag, X::Payload>>,
+{
+    type OutTy = <<Compile<Tagged<X::Tag, X::Payload>> as OpOnce<
+        FoldEverythingLProg<FProg, Acc>,
+    >>::OutVal as OpOnce<Tagged<X::Tag, X::Payload>>>::OutVal;
+}
+
+impl<FProg, Acc, X> OpOnce<(Acc, X)> for FoldEverythingStepL<FProg>
+where
+    X: DeepNode,
+    Compile<Tagged<X::Tag, X::Payload>>: OpOnce<FoldEverythingLProg<FProg, Acc>>,
+    <Compile<Tagged<X::Tag, X::Payload>> as OpOnce<FoldEverythingLProg<FProg, Acc>>>::OutVal:
+        OpOnce<Tagged<X::Tag, X::Payload>>,
+{
+    type OutVal = <<Compile<Tagged<X::Tag, X::Payload>> as OpOnce<
+        FoldEverythingLProg<FProg, Acc>,
+    >>::OutVal as OpOnce<Tagged<X::Tag, X::Payload>>>::OutVal;
+
+    #[inline]
+    fn run(self, (acc, child): (Acc, X)) -> Self::OutVal {
+        let node = child.into_deep_node();
+        Compile::<Tagged<X::Tag, X::Payload>>::new()
+            .run(fold_everything_l_prog(self.0, acc))
+            .run(node)
+    }
+}
+
+// The present side of a sum (`SumNode` lowers to this step over its `Either` payload): both
+// sides must fold to the same accumulator type.
+impl<FProg, Acc, L, R> OpTy<(Acc, Either<L, R>)> for FoldEverythingStepL<FProg>
+where
+    Self: OpTy<(Acc, L)> + OpTy<(Acc, R), OutTy = OpTyOut<Self, (Acc, L)>>,
+{
+    type OutTy = OpTyOut<Self, (Acc, L)>;
+}
+
+impl<FProg, Acc, L, R> OpOnce<(Acc, Either<L, R>)> for FoldEverythingStepL<FProg>
+where
+    Self: OpOnce<(Acc, L)>,
+    Self: OpOnce<(Acc, R), OutVal = <Self as OpOnce<(Acc, L)>>::OutVal>,
+{
+    type OutVal = <Self as OpOnce<(Acc, L)>>::OutVal;
+
+    #[inline]
+    fn run(self, (acc, side): (Acc, Either<L, R>)) -> Self::OutVal {
+        match side {
+            Either::Left(l) => <Self as OpOnce<(Acc, L)>>::run(self, (acc, l)),
+            Either::Right(r) => <Self as OpOnce<(Acc, R)>>::run(self, (acc, r)),
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Children as nodes
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// How the deep fold views a child: as its container node, or as an opaque leaf.
+///
+/// Every `IntoContainer` type is viewed through its container; std scalars (`bool`, `char`,
+/// integers, floats, `String`, `&str`) are `NewTypeNode<Self>` leaves. This is a view for the
+/// deep fold only: scalars get no `IntoContainer` impl (`5u8.map(op)` does not compile).
+pub trait DeepNode {
+    type Tag;
+    type Payload;
+
+    fn into_deep_node(self) -> Tagged<Self::Tag, Self::Payload>;
+}
+
+impl<T: IntoContainer> DeepNode for T {
+    type Tag = T::Tag;
+    type Payload = T::Payload;
+
+    #[inline]
+    fn into_deep_node(self) -> Tagged<T::Tag, T::Payload> {
+        self.into_container().into_tagged()
+    }
+}
+
+macro_rules! impl_scalar_deep_leaf {
+    ( $( $S:ty ),+ $(,)? ) => {
+        $(
+            impl DeepNode for $S {
+                type Tag = IX_NEWTYPENODE;
+                type Payload = $S;
+
+                #[inline]
+                fn into_deep_node(self) -> Tagged<IX_NEWTYPENODE, $S> {
+                    Tagged::new(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_deep_leaf!(bool, char);
+impl_scalar_deep_leaf!(u8, u16, u32, u64, u128, usize);
+impl_scalar_deep_leaf!(i8, i16, i32, i64, i128, isize);
+impl_scalar_deep_leaf!(f32, f64);
+impl_scalar_deep_leaf!(String);
+
+impl<'a> DeepNode for &'a str {
+    type Tag = IX_NEWTYPENODE;
+    type Payload = &'a str;
+
+    #[inline]
+    fn into_deep_node(self) -> Tagged<IX_NEWTYPENODE, &'a str> {
+        Tagged::new(self)
+    }
+}
@@ -6,3 +6,6 @@ pub use fold_children_l::*;
 
 mod hlist_node;
 pub use hlist_node::*;
+
+mod fold_everything_l;
+pub use fold_everything_l::*;
@@ -13,6 +13,20 @@ macro_rules! pair_val {
 
 define_compose_balanced!(pair_ty, pair_val);
 
+// No segments compose to the identity.
+impl crate::OpTy<()> for HlistComposeBalanced {
+    type OutTy = crate::IdOp;
+}
+
+impl crate::OpOnce<()> for HlistComposeBalanced {
+    type OutVal = crate::IdOp;
+
+    #[inline]
+    fn run(self, _: ()) -> crate::IdOp {
+        crate::IdOp
+    }
+}
+
 #[cfg(test)]
 mod tuple_compose_balanced_tests {
     use super::*;
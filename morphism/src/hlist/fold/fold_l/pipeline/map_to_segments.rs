@@ -2,6 +2,20 @@
 
 define_map_to_segments!(SegmentFoldL);
 
+// The empty HList has no segments (see `HlistComposeBalanced` on `()`): folding it returns the
+// accumulator, which is what a deep fold needs for `()` children (e.g. the `None` side of an
+// `Option`).
+impl<F> OpTy<()> for HlistToSegments<F> {
+    type OutTy = ();
+}
+
+impl<F> OpOnce<()> for HlistToSegments<F> {
+    type OutVal = ();
+
+    #[inline]
+    fn run(self, _: ()) {}
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,6 +73,13 @@ mod tests {
         assert_eq!(total.run(0usize), 23usize);
     }
 
+    #[test]
+    fn empty_hlist_folds_to_the_accumulator() {
+        let segments: () = HlistToSegments::new(Count).run(());
+        let total = HlistComposeBalanced.run(segments);
+        assert_eq!(total.run(5usize), 5usize);
+    }
+
     #[test]
     fn type_checks() {
         type Out = <HlistToSegments<Count> as OpTy<(u8, (u8,))>>::OutTy;
@@ -1,5 +1,5 @@
 use crate::IX_HLIST_PUSH_BACK;
-use crate::{NullaryToken, OpOnce, Tagged};
+use crate::{NullaryToken, OpOnce, OpTy, Tagged};
 
 /// Push a single element onto the end of an **HList-encoded tuple**
 /// (chunked at 11 with a recursive tail).
@@ -101,6 +101,14 @@ where
     }
 }
 
+// Type-level: the output shape only depends on the types, so reuse the value-level impls.
+impl<Acc, E> OpTy<(Acc, E)> for HlistPushBack
+where
+    PushBackAcc<Acc>: OpOnce<E>,
+{
+    type OutTy = <PushBackAcc<Acc> as OpOnce<E>>::OutVal;
+}
+
 // Public op: (Acc, E) -> AccOut, implemented via PushBackAcc(acc).run(e)
 impl<Acc, E> OpOnce<(Acc, E)> for HlistPushBack
 where
@@ -12,10 +12,10 @@
 
 use crate::ApplyOp;
 use crate::HlistPushBack;
-use crate::OpOnce;
 use crate::PartialROp;
 use crate::{BimapOp, IdOp, ThenOp};
 use crate::{HlistComposeBalanced, HlistToSegments};
+use crate::{OpOnce, OpTy, OpTyOut};
 
 use crate::{Tagged, IX_HLIST_MAP};
 
@@ -69,6 +69,19 @@ where
 // Pipeline: HlistToSegments(HlistMapStep(op)) |> HlistComposeBalanced |> PartialROp(ApplyOp, ())
 // ─────────────────────────────────────────────────────────────────────────────
 
+impl<Op, Args> OpTy<Args> for HlistMap<Op>
+where
+    HlistToSegments<HlistMapStep<Op>>: OpTy<Args>,
+    HlistComposeBalanced: OpTy<OpTyOut<HlistToSegments<HlistMapStep<Op>>, Args>>,
+    PartialROp<ApplyOp, ()>:
+        OpTy<OpTyOut<HlistComposeBalanced, OpTyOut<HlistToSegments<HlistMapStep<Op>>, Args>>>,
+{
+    type OutTy = OpTyOut<
+        PartialROp<ApplyOp, ()>,
+        OpTyOut<HlistComposeBalanced, OpTyOut<HlistToSegments<HlistMapStep<Op>>, Args>>,
+    >;
+}
+
 impl<Op, Args> OpOnce<Args> for HlistMap<Op>
 where
     Op: Clone,
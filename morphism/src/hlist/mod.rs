@@ -1,4 +1,4 @@
-use crate::{NullaryToken, OpOnce, OpTy};
+use crate::{False, NullaryToken, OpOnce, OpTy, True};
 
 // Runtime HList ops live here (`map.rs`).
 // Public AST program nodes are `HlistMapProg` in that module.
@@ -77,3 +77,45 @@ impl<A, B, C, D, E, F, G, H, I, J, K, Tail> OpOnce<(A, B, C, D, E, F, G, H, I, J
         ((a, b, c, d, e, f, g, h, i, j, k), tail)
     }
 }
+
+/// Type-level predicate: is this HList (or `HlistNode`'s child list) empty?
+///
+/// `()` ↦ `True`, any tuple of arity 1..=12 ↦ `False`; `HlistNode<S>` asks about `S`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HlistIsEmpty;
+
+impl OpTy<()> for HlistIsEmpty {
+    type OutTy = True;
+}
+
+impl<S> OpTy<HlistNode<S>> for HlistIsEmpty
+where
+    HlistIsEmpty: OpTy<S>,
+{
+    type OutTy = <HlistIsEmpty as OpTy<S>>::OutTy;
+}
+
+macro_rules! impl_hlist_is_empty_false {
+    ( $( ( $( $A:ident ),+ ) )+ ) => {
+        $(
+            impl<$( $A, )+> OpTy<( $( $A, )+ )> for HlistIsEmpty {
+                type OutTy = False;
+            }
+        )+
+    };
+}
+
+impl_hlist_is_empty_false! {
+    (A0)
+    (A0, A1)
+    (A0, A1, A2)
+    (A0, A1, A2, A3)
+    (A0, A1, A2, A3, A4)
+    (A0, A1, A2, A3, A4, A5)
+    (A0, A1, A2, A3, A4, A5, A6)
+    (A0, A1, A2, A3, A4, A5, A6, A7)
+    (A0, A1, A2, A3, A4, A5, A6, A7, A8)
+    (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9)
+    (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10)
+    (A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, Tail)
+}
@@ -0,0 +1,172 @@
+//! Choice combinators: the sum counterparts of `bimap` / `fanout`.
+//!
+//! - `choose(f, g)` (`+++`): `Either<A, B> → Either<f(A), g(B)>`
+//! - `either(f, g)` (`|||`): `Either<A, B> → O`, where `f: A → O` and `g: B → O`
+//! - `left(f)`: `Either<A, B> → Either<f(A), B>`; `right(g)`: `Either<A, B> → Either<A, g(B)>`
+
+use crate::kit::op::{OpOnce, OpTy};
+use crate::registry::combinators::{IX_CHOOSE, IX_EITHER, IX_LEFT, IX_RIGHT};
+use crate::tag::Tagged;
+use crate::Either;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROGRAM - Reflected syntax nodes (AST)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Choose program node: reflected syntax node for (F, G) (`f +++ g`).
+pub type Choose<F, G> = Tagged<IX_CHOOSE, (F, G)>;
+
+/// Either program node: reflected syntax node for (F, G) (`f ||| g`).
+pub type EitherProg<F, G> = Tagged<IX_EITHER, (F, G)>;
+
+/// Left program node: reflected syntax node for `F` (applied to the left side).
+pub type LeftProg<F> = Tagged<IX_LEFT, F>;
+
+/// Right program node: reflected syntax node for `G` (applied to the right side).
+pub type RightProg<G> = Tagged<IX_RIGHT, G>;
+
+/// Construct a choose program node: map whichever side of a binary sum is present.
+#[inline]
+pub const fn choose<F, G>(f: F, g: G) -> Choose<F, G> {
+    Tagged::new((f, g))
+}
+
+/// Construct an either program node: eliminate a binary sum into a common output type.
+#[inline]
+pub const fn either<F, G>(f: F, g: G) -> EitherProg<F, G> {
+    Tagged::new((f, g))
+}
+
+/// Construct a left program node: run `f` on the left side, pass the right side through.
+#[inline]
+pub const fn left<F>(f: F) -> LeftProg<F> {
+    Tagged::new(f)
+}
+
+/// Construct a right program node: run `g` on the right side, pass the left side through.
+#[inline]
+pub const fn right<G>(g: G) -> RightProg<G> {
+    Tagged::new(g)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SEMANTICS - Runnable op implementations
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Choose: Either<A, B> → Either<F(A), G(B)>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChooseOp<F, G> {
+    pub f: F,
+    pub g: G,
+}
+
+impl<A, B, F, G> OpTy<Either<A, B>> for ChooseOp<F, G>
+where
+    F: OpTy<A>,
+    G: OpTy<B>,
+{
+    type OutTy = Either<F::OutTy, G::OutTy>;
+}
+
+impl<A, B, F, G> OpOnce<Either<A, B>> for ChooseOp<F, G>
+where
+    F: OpOnce<A>,
+    G: OpOnce<B>,
+{
+    type OutVal = Either<F::OutVal, G::OutVal>;
+
+    #[inline]
+    fn run(self, input: Either<A, B>) -> Self::OutVal {
+        match input {
+            Either::Left(a) => Either::Left(self.f.run(a)),
+            Either::Right(b) => Either::Right(self.g.run(b)),
+        }
+    }
+}
+
+/// Either: Either<A, B> → O, where both arms produce `O`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EitherOp<F, G> {
+    pub f: F,
+    pub g: G,
+}
+
+impl<A, B, F, G> OpTy<Either<A, B>> for EitherOp<F, G>
+where
+    F: OpTy<A>,
+    G: OpTy<B, OutTy = F::OutTy>,
+{
+    type OutTy = F::OutTy;
+}
+
+impl<A, B, F, G> OpOnce<Either<A, B>> for EitherOp<F, G>
+where
+    F: OpOnce<A>,
+    G: OpOnce<B, OutVal = F::OutVal>,
+{
+    type OutVal = F::OutVal;
+
+    #[inline]
+    fn run(self, input: Either<A, B>) -> Self::OutVal {
+        match input {
+            Either::Left(a) => self.f.run(a),
+            Either::Right(b) => self.g.run(b),
+        }
+    }
+}
+
+/// Left: Either<A, B> → Either<F(A), B>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LeftOp<F> {
+    pub f: F,
+}
+
+impl<A, B, F> OpTy<Either<A, B>> for LeftOp<F>
+where
+    F: OpTy<A>,
+{
+    type OutTy = Either<F::OutTy, B>;
+}
+
+impl<A, B, F> OpOnce<Either<A, B>> for LeftOp<F>
+where
+    F: OpOnce<A>,
+{
+    type OutVal = Either<F::OutVal, B>;
+
+    #[inline]
+    fn run(self, input: Either<A, B>) -> Self::OutVal {
+        match input {
+            Either::Left(a) => Either::Left(self.f.run(a)),
+            Either::Right(b) => Either::Right(b),
+        }
+    }
+}
+
+/// Right: Either<A, B> → Either<A, G(B)>
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RightOp<G> {
+    pub g: G,
+}
+
+impl<A, B, G> OpTy<Either<A, B>> for RightOp<G>
+where
+    G: OpTy<B>,
+{
+    type OutTy = Either<A, G::OutTy>;
+}
+
+impl<A, B, G> OpOnce<Either<A, B>> for RightOp<G>
+where
+    G: OpOnce<B>,
+{
+    type OutVal = Either<A, G::OutVal>;
+
+    #[inline]
+    fn run(self, input: Either<A, B>) -> Self::OutVal {
+        match input {
+            Either::Left(a) => Either::Left(a),
+            Either::Right(b) => Either::Right(self.g.run(b)),
+        }
+    }
+}
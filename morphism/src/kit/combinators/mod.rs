@@ -24,5 +24,14 @@ pub use apply::*;
 pub mod projections;
 pub use projections::*;
 
+mod product;
+pub use product::*;
+
+mod choice;
+pub use choice::*;
+
 mod tagged;
 pub use tagged::*;
+
+mod only_for;
+pub use only_for::*;
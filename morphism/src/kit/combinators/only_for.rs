@@ -0,0 +1,142 @@
+use core::marker::PhantomData;
+
+use crate::{OpOnce, OpTy, OpTyOut, SameTypeOp, Select};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SEMANTICS - Runnable op implementations
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Type-case op: apply `Op` to values of type `T`, identity on every other type.
+///
+/// The case split is decided by `SameTypeOp<T>` (structural `TypeCode` comparison), so `Op`
+/// only needs an `OpOnce` impl for `T`. Every argument type must implement `TypeCode`: the
+/// crate covers std scalars, `String`/`&str`, `Option`, `Vec`, `Box`, tuples and `Tagged`, and
+/// any other child (e.g. a user struct) is a compile error until it gets a `TypeCode` impl.
+///
+/// Typical use: `x.map(OnlyFor::<String, _>::new(Trim))` on a tuple with mixed children.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OnlyFor<T, Op> {
+    pub op: Op,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<T, Op> OnlyFor<T, Op> {
+    #[inline]
+    pub const fn new(op: Op) -> Self {
+        Self {
+            op,
+            _t: PhantomData,
+        }
+    }
+}
+
+/// Construct a type-case op (`OnlyFor<T, Op>`).
+#[inline]
+pub const fn only_for<T, Op>(op: Op) -> OnlyFor<T, Op> {
+    OnlyFor::new(op)
+}
+
+/// Branch taken when the argument type matches: runs the op.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[doc(hidden)]
+pub struct OnlyForHit;
+
+/// Branch taken when the argument type differs: returns the argument unchanged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[doc(hidden)]
+pub struct OnlyForMiss;
+
+impl<Op, X> OpTy<(Op, X)> for OnlyForHit
+where
+    Op: OpTy<X>,
+{
+    type OutTy = OpTyOut<Op, X>;
+}
+
+impl<Op, X> OpOnce<(Op, X)> for OnlyForHit
+where
+    Op: OpOnce<X>,
+{
+    type OutVal = Op::OutVal;
+
+    #[inline]
+    fn run(self, (op, x): (Op, X)) -> Self::OutVal {
+        op.run(x)
+    }
+}
+
+impl<Op, X> OpTy<(Op, X)> for OnlyForMiss {
+    type OutTy = X;
+}
+
+impl<Op, X> OpOnce<(Op, X)> for OnlyForMiss {
+    type OutVal = X;
+
+    #[inline]
+    fn run(self, (_op, x): (Op, X)) -> Self::OutVal {
+        x
+    }
+}
+
+impl<T, Op, X> OpTy<X> for OnlyFor<T, Op>
+where
+    SameTypeOp<T>: OpTy<X>,
+    Select<OnlyForHit, OnlyForMiss>: OpTy<OpTyOut<SameTypeOp<T>, X>>,
+    OpTyOut<Select<OnlyForHit, OnlyForMiss>, OpTyOut<SameTypeOp<T>, X>>: OpTy<(Op, X)>,
+{
+    type OutTy =
+        OpTyOut<OpTyOut<Select<OnlyForHit, OnlyForMiss>, OpTyOut<SameTypeOp<T>, X>>, (Op, X)>;
+}
+
+impl<T, Op, X> OpOnce<X> for OnlyFor<T, Op>
+where
+    SameTypeOp<T>: OpTy<X>,
+    Select<OnlyForHit, OnlyForMiss>: OpTy<OpTyOut<SameTypeOp<T>, X>>,
+    OpTyOut<Select<OnlyForHit, OnlyForMiss>, OpTyOut<SameTypeOp<T>, X>>: OpOnce<(Op, X)> + Default,
+{
+    type OutVal = <OpTyOut<Select<OnlyForHit, OnlyForMiss>, OpTyOut<SameTypeOp<T>, X>> as OpOnce<
+        (Op, X),
+    >>::OutVal;
+
+    #[inline]
+    fn run(self, x: X) -> Self::OutVal {
+        <OpTyOut<Select<OnlyForHit, OnlyForMiss>, OpTyOut<SameTypeOp<T>, X>> as Default>::default()
+            .run((self.op, x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mappable;
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Shout;
+
+    impl OpOnce<String> for Shout {
+        type OutVal = String;
+        fn run(self, s: String) -> Self::OutVal {
+            s.to_uppercase()
+        }
+    }
+
+    #[test]
+    fn only_for_applies_to_matching_children_only() {
+        let t = (1u8, String::from("a"), 2i32, String::from("b"));
+        let out = t.map(only_for::<String, _>(Shout));
+        assert_eq!(out, (1u8, String::from("A"), 2i32, String::from("B")));
+    }
+
+    #[test]
+    fn only_for_is_identity_on_other_types() {
+        let out = OnlyFor::<String, _>::new(Shout).run((1u8, 2u16));
+        assert_eq!(out, (1u8, 2u16));
+    }
+
+    #[test]
+    fn only_for_skips_std_containers() {
+        let t = (vec![1u8], Some(String::from("a")), String::from("b"));
+        let out = t.map(only_for::<String, _>(Shout));
+        assert_eq!(out, (vec![1u8], Some(String::from("a")), String::from("B")));
+    }
+}
@@ -0,0 +1,191 @@
+//! Product plumbing combinators: rearrange pairs without bespoke ops.
+//!
+//! - `swap`: `(A, B) → (B, A)`
+//! - `assoc`: `((A, B), C) → (A, (B, C))`; `unassoc` is its inverse
+//! - `first(f)`: `(A, B) → (f(A), B)`; `second(g)`: `(A, B) → (A, g(B))`
+//! - `dup`: `A → (A, A)` (requires `A: Clone`)
+
+use crate::kit::op::{OpOnce, OpTy};
+use crate::kit::tokens::NullaryToken;
+use crate::registry::combinators::{IX_ASSOC, IX_DUP, IX_FIRST, IX_SECOND, IX_SWAP, IX_UNASSOC};
+use crate::tag::Tagged;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PROGRAM - Reflected syntax nodes (AST)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Swap program node: reflected syntax node.
+pub type Swap = Tagged<IX_SWAP, NullaryToken>;
+
+/// Assoc program node (`((a, b), c) → (a, (b, c))`): reflected syntax node.
+pub type Assoc = Tagged<IX_ASSOC, NullaryToken>;
+
+/// Unassoc program node (`(a, (b, c)) → ((a, b), c)`): reflected syntax node.
+pub type Unassoc = Tagged<IX_UNASSOC, NullaryToken>;
+
+/// First program node: reflected syntax node for `F` (applied to the first component).
+pub type First<F> = Tagged<IX_FIRST, F>;
+
+/// Second program node: reflected syntax node for `G` (applied to the second component).
+pub type Second<G> = Tagged<IX_SECOND, G>;
+
+/// Dup program node: reflected syntax node.
+pub type Dup = Tagged<IX_DUP, NullaryToken>;
+
+/// Construct a swap program node.
+#[inline]
+pub const fn swap() -> Swap {
+    Tagged::new(NullaryToken)
+}
+
+/// Construct an assoc program node.
+#[inline]
+pub const fn assoc() -> Assoc {
+    Tagged::new(NullaryToken)
+}
+
+/// Construct an unassoc program node.
+#[inline]
+pub const fn unassoc() -> Unassoc {
+    Tagged::new(NullaryToken)
+}
+
+/// Construct a first program node: run `f` on the first component of a pair.
+#[inline]
+pub const fn first<F>(f: F) -> First<F> {
+    Tagged::new(f)
+}
+
+/// Construct a second program node: run `g` on the second component of a pair.
+#[inline]
+pub const fn second<G>(g: G) -> Second<G> {
+    Tagged::new(g)
+}
+
+/// Construct a dup program node.
+#[inline]
+pub const fn dup() -> Dup {
+    Tagged::new(NullaryToken)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SEMANTICS - Runnable op implementations
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Swap: (A, B) → (B, A)
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapOp;
+
+impl<A, B> OpTy<(A, B)> for SwapOp {
+    type OutTy = (B, A);
+}
+
+impl<A, B> OpOnce<(A, B)> for SwapOp {
+    type OutVal = (B, A);
+
+    #[inline]
+    fn run(self, (a, b): (A, B)) -> Self::OutVal {
+        (b, a)
+    }
+}
+
+/// Assoc: ((A, B), C) → (A, (B, C))
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AssocOp;
+
+impl<A, B, C> OpTy<((A, B), C)> for AssocOp {
+    type OutTy = (A, (B, C));
+}
+
+impl<A, B, C> OpOnce<((A, B), C)> for AssocOp {
+    type OutVal = (A, (B, C));
+
+    #[inline]
+    fn run(self, ((a, b), c): ((A, B), C)) -> Self::OutVal {
+        (a, (b, c))
+    }
+}
+
+/// Unassoc: (A, (B, C)) → ((A, B), C)
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnassocOp;
+
+impl<A, B, C> OpTy<(A, (B, C))> for UnassocOp {
+    type OutTy = ((A, B), C);
+}
+
+impl<A, B, C> OpOnce<(A, (B, C))> for UnassocOp {
+    type OutVal = ((A, B), C);
+
+    #[inline]
+    fn run(self, (a, (b, c)): (A, (B, C))) -> Self::OutVal {
+        ((a, b), c)
+    }
+}
+
+/// First: (A, B) → (F(A), B)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FirstOp<F> {
+    pub f: F,
+}
+
+impl<A, B, F> OpTy<(A, B)> for FirstOp<F>
+where
+    F: OpTy<A>,
+{
+    type OutTy = (F::OutTy, B);
+}
+
+impl<A, B, F> OpOnce<(A, B)> for FirstOp<F>
+where
+    F: OpOnce<A>,
+{
+    type OutVal = (F::OutVal, B);
+
+    #[inline]
+    fn run(self, (a, b): (A, B)) -> Self::OutVal {
+        (self.f.run(a), b)
+    }
+}
+
+/// Second: (A, B) → (A, G(B))
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SecondOp<G> {
+    pub g: G,
+}
+
+impl<A, B, G> OpTy<(A, B)> for SecondOp<G>
+where
+    G: OpTy<B>,
+{
+    type OutTy = (A, G::OutTy);
+}
+
+impl<A, B, G> OpOnce<(A, B)> for SecondOp<G>
+where
+    G: OpOnce<B>,
+{
+    type OutVal = (A, G::OutVal);
+
+    #[inline]
+    fn run(self, (a, b): (A, B)) -> Self::OutVal {
+        (a, self.g.run(b))
+    }
+}
+
+/// Dup: A → (A, A)
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DupOp;
+
+impl<A: Clone> OpTy<A> for DupOp {
+    type OutTy = (A, A);
+}
+
+impl<A: Clone> OpOnce<A> for DupOp {
+    type OutVal = (A, A);
+
+    #[inline]
+    fn run(self, a: A) -> Self::OutVal {
+        (a.clone(), a)
+    }
+}
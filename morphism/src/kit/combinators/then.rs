@@ -1,5 +1,5 @@
 use crate::kit::op::{OpOnce, OpTy, OpTyOut};
-use crate::registry::combinators::IX_THEN;
+use crate::registry::combinators::{IX_THEN, IX_THEN_BALANCED};
 use crate::tag::Tagged;
 use core::fmt::Debug;
 
@@ -21,6 +21,19 @@ pub const fn then<F, G>(f: F, g: G) -> Then<F, G> {
     Tagged::new((f, g))
 }
 
+/// Composition program node that the optimizer keeps whole: runs like `then(f, g)`, but is one
+/// element of the `then` spine instead of being flattened into it.
+///
+/// `repeat_n` unrolls into a tree of these, so the lowered (and optimized) type stays
+/// `O(log N)` deep.
+pub type ThenBalanced<F, G> = Tagged<IX_THEN_BALANCED, (F, G)>;
+
+/// Construct a balanced composition node (see `ThenBalanced`).
+#[inline]
+pub const fn then_balanced<F, G>(f: F, g: G) -> ThenBalanced<F, G> {
+    Tagged::new((f, g))
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // SEMANTICS - Runnable op implementation
 // ═══════════════════════════════════════════════════════════════════════════
@@ -0,0 +1,273 @@
+//! `declare_domain!`: declare a domain, its roles and its keys in one place.
+//!
+//! Indices are assigned by position (`U0`, `U1`, ..) inside each role block, so keys are
+//! contiguous by construction; a duplicated key name is a duplicate type alias (hard error).
+//! A role block can also emit its closed-table skeleton: a type alias with one handler slot
+//! per key, in index order, chunked the way closed tables are (`E0..E10` + tail).
+//!
+//! ```rust,ignore
+//! declare_domain! {
+//!     domain TaggedDomain = D_TAGGED;          // or: domain struct MyDomain: Ext<CRATE_ID, U0>;
+//!
+//!     semantics(table = TaggedSemanticsTable) {
+//!         IX_WRAP_TAGGED,                      // U0
+//!         IX_UNWRAP_TAGGED,                    // U1
+//!     }
+//! }
+//!
+//! // compiler side: the table type cannot drift from the key list
+//! pub type ReifyTableTagged<I> = TaggedSemanticsTable<CompileWrapTagged<I>, CompileUnwrapTagged<I>>;
+//! ```
+//!
+//! Forms:
+//! - `domain Name = CODE;`: this crate's domains (`pub type Name = Domain<CODE>`). The code is
+//!   claimed with a `ClaimedDomainCode` impl, so two domains sharing a code fail to compile.
+//! - `domain struct Name: CODE;`: other crates' domains (local marker + `HasDomainCode`).
+//! - role blocks: `syntax { .. }` (`R_SYNTAX`) and `semantics { .. }` (`R_SEMANTICS`), each at
+//!   most 256 keys (indices `U0..U255`, the closed-table selector range), with an optional
+//!   `(table = Alias)`.
+//!
+//! Every key also gets a `KeyIdent<Key>` impl on the domain type: its registry identifiers as
+//! written in the declaration (`D_COMBINATORS` / `R_SEMANTICS` / `IX_THEN`), for tooling that
+//! should show names rather than numbers (`dot`), and a name type (`IX_THEN<1>`) that compiler
+//! diagnostics print in place of the key's binary-natural encoding.
+
+/// A key's registry identifiers, as declared (emitted by `declare_domain!`, once per key).
+#[diagnostic::on_unimplemented(
+    message = "`{K}` is not a key declared for `{Self}`",
+    note = "keys are declared with `declare_domain!`, which also emits their `KeyIdent` impls"
+)]
+pub trait KeyIdent<K> {
+    /// The domain code, e.g. `D_COMBINATORS` (or `Ext<CrateId, Local>` for other crates).
+    const DOMAIN: &'static str;
+    /// The role block, `R_SYNTAX` or `R_SEMANTICS`.
+    const ROLE: &'static str;
+    /// The key alias, e.g. `IX_THEN`.
+    const KEY: &'static str;
+    /// A type named after the key, with its index in decimal: `IX_THEN<1>`. Compile errors
+    /// about a key are phrased on this type, so they show the key as declared.
+    type Name;
+}
+
+// Index pools: `U0..U255` (the closed-table selector range) and the matching handler slots.
+// Keys are consumed eight at a time so a full 256-key block expands ~40 macro levels deep,
+// well inside the default recursion limit.
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __declare_domain_keys {
+    ($D:ident, $Code:ty, $R:ty, $role:literal; [$($ixs:ident)*];) => {};
+    ($D:ident, $Code:ty, $R:ty, $role:literal; [$i0:ident $i1:ident $i2:ident $i3:ident $i4:ident $i5:ident $i6:ident $i7:ident $($ixs:ident)*]; $(#[$m0:meta])* $K0:ident, $(#[$m1:meta])* $K1:ident, $(#[$m2:meta])* $K2:ident, $(#[$m3:meta])* $K3:ident, $(#[$m4:meta])* $K4:ident, $(#[$m5:meta])* $K5:ident, $(#[$m6:meta])* $K6:ident, $(#[$m7:meta])* $K7:ident $(, $($rest:tt)*)?) => {
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $i0; $(#[$m0])* $K0);
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $i1; $(#[$m1])* $K1);
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $i2; $(#[$m2])* $K2);
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $i3; $(#[$m3])* $K3);
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $i4; $(#[$m4])* $K4);
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $i5; $(#[$m5])* $K5);
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $i6; $(#[$m6])* $K6);
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $i7; $(#[$m7])* $K7);
+        $crate::__declare_domain_keys!($D, $Code, $R, $role; [$($ixs)*]; $($($rest)*)?);
+    };
+    ($D:ident, $Code:ty, $R:ty, $role:literal; [$ix:ident $($ixs:ident)*]; $(#[$m:meta])* $K:ident $(, $($rest:tt)*)?) => {
+        $crate::__declare_domain_key!($D, $Code, $R, $role, $ix; $(#[$m])* $K);
+        $crate::__declare_domain_keys!($D, $Code, $R, $role; [$($ixs)*]; $($($rest)*)?);
+    };
+    ($D:ident, $Code:ty, $R:ty, $role:literal; []; $($keys:tt)+) => {
+        compile_error!("declare_domain!: a role block holds at most 256 keys (`U0..U255`)");
+    };
+    // Entry (keys not yet paired with the pool).
+    ($D:ident, $Code:ty, $R:ty, $role:literal; $($keys:tt)*) => {
+        $crate::__declare_domain_keys!($D, $Code, $R, $role; [U0 U1 U2 U3 U4 U5 U6 U7 U8 U9 U10 U11 U12 U13 U14 U15 U16 U17 U18 U19 U20 U21 U22 U23 U24 U25 U26 U27 U28 U29 U30 U31 U32 U33 U34 U35 U36 U37 U38 U39 U40 U41 U42 U43 U44 U45 U46 U47 U48 U49 U50 U51 U52 U53 U54 U55 U56 U57 U58 U59 U60 U61 U62 U63 U64 U65 U66 U67 U68 U69 U70 U71 U72 U73 U74 U75 U76 U77 U78 U79 U80 U81 U82 U83 U84 U85 U86 U87 U88 U89 U90 U91 U92 U93 U94 U95 U96 U97 U98 U99 U100 U101 U102 U103 U104 U105 U106 U107 U108 U109 U110 U111 U112 U113 U114 U115 U116 U117 U118 U119 U120 U121 U122 U123 U124 U125 U126 U127 U128 U129 U130 U131 U132 U133 U134 U135 U136 U137 U138 U139 U140 U141 U142 U143 U144 U145 U146 U147 U148 U149 U150 U151 U152 U153 U154 U155 U156 U157 U158 U159 U160 U161 U162 U163 U164 U165 U166 U167 U168 U169 U170 U171 U172 U173 U174 U175 U176 U177 U178 U179 U180 U181 U182 U183 U184 U185 U186 U187 U188 U189 U190 U191 U192 U193 U194 U195 U196 U197 U198 U199 U200 U201 U202 U203 U204 U205 U206 U207 U208 U209 U210 U211 U212 U213 U214 U215 U216 U217 U218 U219 U220 U221 U222 U223 U224 U225 U226 U227 U228 U229 U230 U231 U232 U233 U234 U235 U236 U237 U238 U239 U240 U241 U242 U243 U244 U245 U246 U247 U248 U249 U250 U251 U252 U253 U254 U255]; $($keys)*);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __declare_domain_key {
+    ($D:ident, $Code:ty, $R:ty, $role:literal, $ix:ident; $(#[$m:meta])* $K:ident) => {
+        $(#[$m])*
+        #[allow(non_camel_case_types)]
+        pub type $K = $crate::Key<$D, $R, $crate::$ix>;
+
+        // The key's `Name` lives in its own scope: inside it, `$K` is the name type.
+        const _: () = {
+            #[allow(non_camel_case_types, dead_code)]
+            pub struct $K<const INDEX: usize>;
+
+            impl $crate::KeyIdent<$crate::Key<$D, $R, $crate::$ix>> for $D {
+                const DOMAIN: &'static str = stringify!($Code);
+                const ROLE: &'static str = $role;
+                const KEY: &'static str = stringify!($K);
+                type Name = $K<{ <$crate::$ix as $crate::NatToUsize>::VALUE }>;
+            }
+        };
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __declare_domain_table {
+    ($D:ident, []; $($keys:tt)*) => {};
+    ($D:ident, [$T:ident]; $($keys:tt)*) => {
+        $crate::__declare_domain_table!($D, $T; []; [H0 H1 H2 H3 H4 H5 H6 H7 H8 H9 H10 H11 H12 H13 H14 H15 H16 H17 H18 H19 H20 H21 H22 H23 H24 H25 H26 H27 H28 H29 H30 H31 H32 H33 H34 H35 H36 H37 H38 H39 H40 H41 H42 H43 H44 H45 H46 H47 H48 H49 H50 H51 H52 H53 H54 H55 H56 H57 H58 H59 H60 H61 H62 H63 H64 H65 H66 H67 H68 H69 H70 H71 H72 H73 H74 H75 H76 H77 H78 H79 H80 H81 H82 H83 H84 H85 H86 H87 H88 H89 H90 H91 H92 H93 H94 H95 H96 H97 H98 H99 H100 H101 H102 H103 H104 H105 H106 H107 H108 H109 H110 H111 H112 H113 H114 H115 H116 H117 H118 H119 H120 H121 H122 H123 H124 H125 H126 H127 H128 H129 H130 H131 H132 H133 H134 H135 H136 H137 H138 H139 H140 H141 H142 H143 H144 H145 H146 H147 H148 H149 H150 H151 H152 H153 H154 H155 H156 H157 H158 H159 H160 H161 H162 H163 H164 H165 H166 H167 H168 H169 H170 H171 H172 H173 H174 H175 H176 H177 H178 H179 H180 H181 H182 H183 H184 H185 H186 H187 H188 H189 H190 H191 H192 H193 H194 H195 H196 H197 H198 H199 H200 H201 H202 H203 H204 H205 H206 H207 H208 H209 H210 H211 H212 H213 H214 H215 H216 H217 H218 H219 H220 H221 H222 H223 H224 H225 H226 H227 H228 H229 H230 H231 H232 H233 H234 H235 H236 H237 H238 H239 H240 H241 H242 H243 H244 H245 H246 H247 H248 H249 H250 H251 H252 H253 H254 H255]; $($keys)*);
+    };
+    // Pool exhausted: `__declare_domain_keys!` reports the limit.
+    ($D:ident, $T:ident; [$($h:ident)*]; []; $($keys:tt)+) => {};
+    ($D:ident, $T:ident; [$($h:ident)*]; [$($pool:ident)*];) => {
+        #[doc = concat!(
+            "Closed-table skeleton for `", stringify!($D), "`: one handler slot per key, in index order."
+        )]
+        pub type $T<$($h),*> = $crate::__declare_domain_chunk!($($h),*);
+    };
+    ($D:ident, $T:ident; [$($h:ident)*]; [$n0:ident $n1:ident $n2:ident $n3:ident $n4:ident $n5:ident $n6:ident $n7:ident $($pool:ident)*]; $(#[$m0:meta])* $K0:ident, $(#[$m1:meta])* $K1:ident, $(#[$m2:meta])* $K2:ident, $(#[$m3:meta])* $K3:ident, $(#[$m4:meta])* $K4:ident, $(#[$m5:meta])* $K5:ident, $(#[$m6:meta])* $K6:ident, $(#[$m7:meta])* $K7:ident $(, $($rest:tt)*)?) => {
+        $crate::__declare_domain_table!($D, $T; [$($h)* $n0 $n1 $n2 $n3 $n4 $n5 $n6 $n7]; [$($pool)*]; $($($rest)*)?);
+    };
+    ($D:ident, $T:ident; [$($h:ident)*]; [$next:ident $($pool:ident)*]; $(#[$m:meta])* $K:ident $(, $($rest:tt)*)?) => {
+        $crate::__declare_domain_table!($D, $T; [$($h)* $next]; [$($pool)*]; $($($rest)*)?);
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __declare_domain_chunk {
+    ($h0:ident, $h1:ident, $h2:ident, $h3:ident, $h4:ident, $h5:ident, $h6:ident, $h7:ident, $h8:ident, $h9:ident, $h10:ident, $($rest:ident),+) => {
+        ($h0, $h1, $h2, $h3, $h4, $h5, $h6, $h7, $h8, $h9, $h10, $crate::__declare_domain_chunk!($($rest),+))
+    };
+    ($($h:ident),*) => {
+        ($($h,)*)
+    };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __declare_domain_roles {
+    ($D:ident, $Code:ty;) => {};
+    ($D:ident, $Code:ty; syntax $(( table = $T:ident ))? { $($keys:tt)* } $($rest:tt)*) => {
+        $crate::__declare_domain_keys!($D, $Code, $crate::R_SYNTAX, "R_SYNTAX"; $($keys)*);
+        $crate::__declare_domain_table!($D, [$($T)?]; $($keys)*);
+        $crate::__declare_domain_roles!($D, $Code; $($rest)*);
+    };
+    ($D:ident, $Code:ty; semantics $(( table = $T:ident ))? { $($keys:tt)* } $($rest:tt)*) => {
+        $crate::__declare_domain_keys!($D, $Code, $crate::R_SEMANTICS, "R_SEMANTICS"; $($keys)*);
+        $crate::__declare_domain_table!($D, [$($T)?]; $($keys)*);
+        $crate::__declare_domain_roles!($D, $Code; $($rest)*);
+    };
+}
+
+/// Declare a domain and its keys; see the module docs.
+#[macro_export]
+macro_rules! declare_domain {
+    ($(#[$dm:meta])* domain struct $D:ident : $Code:ty; $($roles:tt)*) => {
+        $(#[$dm])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $D;
+
+        impl $crate::HasDomainCode for $D {
+            type Code = $Code;
+        }
+
+        $crate::__declare_domain_roles!($D, $Code; $($roles)*);
+    };
+    ($(#[$dm:meta])* domain $D:ident = $Code:ty; $($roles:tt)*) => {
+        $(#[$dm])*
+        pub type $D = $crate::Domain<$Code>;
+
+        // A second domain with the same code is a conflicting impl.
+        impl $crate::ClaimedDomainCode for $crate::DomainCodeClaim<$Code> {}
+
+        $crate::__declare_domain_roles!($D, $Code; $($roles)*);
+    };
+}
+
+#[cfg(test)]
+#[allow(dead_code)] // most test keys only exist to be numbered
+mod tests {
+    use crate::{assert_type_eq, Domain, Ext, Key, R_SEMANTICS, R_SYNTAX};
+    use crate::{U0, U1, U10, U11, U2, U255, U3, U4, U5, U6, U64, U7, U8, U9};
+
+    crate::declare_domain! {
+        domain TestDomain = Ext<U9, U0>;
+
+        syntax {
+            IX_T_NODE,
+        }
+
+        semantics(table = TestSemanticsTable) {
+            IX_T_0, IX_T_1, IX_T_2, IX_T_3, IX_T_4, IX_T_5,
+            IX_T_6, IX_T_7, IX_T_8, IX_T_9, IX_T_10, IX_T_11,
+        }
+    }
+
+    crate::declare_domain! {
+        domain struct ForeignDomain: Ext<U3, U1>;
+
+        semantics(table = ForeignSemanticsTable) {
+            IX_F_ONLY,
+        }
+    }
+
+    // A full pool: 256 keys in one role block.
+    crate::declare_domain! {
+        domain WideDomain = Ext<U9, U1>;
+
+        semantics(table = WideSemanticsTable) {
+            IX_W_0, IX_W_1, IX_W_2, IX_W_3, IX_W_4, IX_W_5, IX_W_6, IX_W_7, IX_W_8, IX_W_9, IX_W_10, IX_W_11, IX_W_12, IX_W_13, IX_W_14, IX_W_15,
+            IX_W_16, IX_W_17, IX_W_18, IX_W_19, IX_W_20, IX_W_21, IX_W_22, IX_W_23, IX_W_24, IX_W_25, IX_W_26, IX_W_27, IX_W_28, IX_W_29, IX_W_30, IX_W_31,
+            IX_W_32, IX_W_33, IX_W_34, IX_W_35, IX_W_36, IX_W_37, IX_W_38, IX_W_39, IX_W_40, IX_W_41, IX_W_42, IX_W_43, IX_W_44, IX_W_45, IX_W_46, IX_W_47,
+            IX_W_48, IX_W_49, IX_W_50, IX_W_51, IX_W_52, IX_W_53, IX_W_54, IX_W_55, IX_W_56, IX_W_57, IX_W_58, IX_W_59, IX_W_60, IX_W_61, IX_W_62, IX_W_63,
+            IX_W_64, IX_W_65, IX_W_66, IX_W_67, IX_W_68, IX_W_69, IX_W_70, IX_W_71, IX_W_72, IX_W_73, IX_W_74, IX_W_75, IX_W_76, IX_W_77, IX_W_78, IX_W_79,
+            IX_W_80, IX_W_81, IX_W_82, IX_W_83, IX_W_84, IX_W_85, IX_W_86, IX_W_87, IX_W_88, IX_W_89, IX_W_90, IX_W_91, IX_W_92, IX_W_93, IX_W_94, IX_W_95,
+            IX_W_96, IX_W_97, IX_W_98, IX_W_99, IX_W_100, IX_W_101, IX_W_102, IX_W_103, IX_W_104, IX_W_105, IX_W_106, IX_W_107, IX_W_108, IX_W_109, IX_W_110, IX_W_111,
+            IX_W_112, IX_W_113, IX_W_114, IX_W_115, IX_W_116, IX_W_117, IX_W_118, IX_W_119, IX_W_120, IX_W_121, IX_W_122, IX_W_123, IX_W_124, IX_W_125, IX_W_126, IX_W_127,
+            IX_W_128, IX_W_129, IX_W_130, IX_W_131, IX_W_132, IX_W_133, IX_W_134, IX_W_135, IX_W_136, IX_W_137, IX_W_138, IX_W_139, IX_W_140, IX_W_141, IX_W_142, IX_W_143,
+            IX_W_144, IX_W_145, IX_W_146, IX_W_147, IX_W_148, IX_W_149, IX_W_150, IX_W_151, IX_W_152, IX_W_153, IX_W_154, IX_W_155, IX_W_156, IX_W_157, IX_W_158, IX_W_159,
+            IX_W_160, IX_W_161, IX_W_162, IX_W_163, IX_W_164, IX_W_165, IX_W_166, IX_W_167, IX_W_168, IX_W_169, IX_W_170, IX_W_171, IX_W_172, IX_W_173, IX_W_174, IX_W_175,
+            IX_W_176, IX_W_177, IX_W_178, IX_W_179, IX_W_180, IX_W_181, IX_W_182, IX_W_183, IX_W_184, IX_W_185, IX_W_186, IX_W_187, IX_W_188, IX_W_189, IX_W_190, IX_W_191,
+            IX_W_192, IX_W_193, IX_W_194, IX_W_195, IX_W_196, IX_W_197, IX_W_198, IX_W_199, IX_W_200, IX_W_201, IX_W_202, IX_W_203, IX_W_204, IX_W_205, IX_W_206, IX_W_207,
+            IX_W_208, IX_W_209, IX_W_210, IX_W_211, IX_W_212, IX_W_213, IX_W_214, IX_W_215, IX_W_216, IX_W_217, IX_W_218, IX_W_219, IX_W_220, IX_W_221, IX_W_222, IX_W_223,
+            IX_W_224, IX_W_225, IX_W_226, IX_W_227, IX_W_228, IX_W_229, IX_W_230, IX_W_231, IX_W_232, IX_W_233, IX_W_234, IX_W_235, IX_W_236, IX_W_237, IX_W_238, IX_W_239,
+            IX_W_240, IX_W_241, IX_W_242, IX_W_243, IX_W_244, IX_W_245, IX_W_246, IX_W_247, IX_W_248, IX_W_249, IX_W_250, IX_W_251, IX_W_252, IX_W_253, IX_W_254, IX_W_255,
+        }
+    }
+
+    #[test]
+    fn indices_follow_position_per_role() {
+        assert_type_eq::<TestDomain, Domain<Ext<U9, U0>>>();
+        assert_type_eq::<IX_T_NODE, Key<TestDomain, R_SYNTAX, U0>>();
+        assert_type_eq::<IX_T_0, Key<TestDomain, R_SEMANTICS, U0>>();
+        assert_type_eq::<IX_T_11, Key<TestDomain, R_SEMANTICS, U11>>();
+        assert_type_eq::<IX_F_ONLY, Key<ForeignDomain, R_SEMANTICS, U0>>();
+    }
+
+    #[test]
+    fn a_role_block_holds_the_whole_selector_range() {
+        assert_type_eq::<IX_W_7, Key<WideDomain, R_SEMANTICS, U7>>();
+        assert_type_eq::<IX_W_8, Key<WideDomain, R_SEMANTICS, U8>>();
+        assert_type_eq::<IX_W_64, Key<WideDomain, R_SEMANTICS, U64>>();
+        assert_type_eq::<IX_W_255, Key<WideDomain, R_SEMANTICS, U255>>();
+    }
+
+    #[test]
+    fn keys_know_their_registry_identifiers() {
+        use crate::KeyIdent;
+
+        assert_eq!(<TestDomain as KeyIdent<IX_T_11>>::DOMAIN, "Ext<U9, U0>");
+        assert_eq!(<TestDomain as KeyIdent<IX_T_11>>::ROLE, "R_SEMANTICS");
+        assert_eq!(<TestDomain as KeyIdent<IX_T_11>>::KEY, "IX_T_11");
+        assert_eq!(<ForeignDomain as KeyIdent<IX_F_ONLY>>::KEY, "IX_F_ONLY");
+        assert_type_eq::<<ForeignDomain as crate::HasDomainCode>::Code, Ext<U3, U1>>();
+
+        type Name<D, K> = <D as KeyIdent<K>>::Name;
+        assert!(core::any::type_name::<Name<TestDomain, IX_T_11>>().ends_with("::IX_T_11<11>"));
+        assert!(core::any::type_name::<Name<WideDomain, IX_W_255>>().ends_with("::IX_W_255<255>"));
+    }
+
+    #[test]
+    fn table_skeleton_chunks_like_closed_tables() {
+        assert_type_eq::<ForeignSemanticsTable<u8>, (u8,)>();
+        // Slot types are arbitrary; naturals keep the lists short.
+        type Twelve = TestSemanticsTable<U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, U11>;
+        type Chunked = (U0, U1, U2, U3, U4, U5, U6, U7, U8, U9, U10, (U11,));
+        assert_type_eq::<Twelve, Chunked>();
+    }
+}
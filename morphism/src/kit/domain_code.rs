@@ -0,0 +1,154 @@
+//! Domain codes: canonical comparison and compile-time distinctness.
+//!
+//! A domain code is either a bare natural (this crate's domains, namespace `0`) or an
+//! `Ext<CrateId, Local>` (another crate's domain, namespace `CrateId ≥ 1`). `DomainCode`
+//! projects both forms onto a canonical `(Ns, Local)` pair so they can be compared with
+//! `NumEqOp` and never collide across forms.
+//!
+//! Key types name a *domain type*, not a code: `Domain<Code>` here, or a local marker type in
+//! other crates (it must be local so the crate can implement `LowerDomain` / `ReifyDomain`
+//! for it). `HasDomainCode` recovers the code from either.
+//!
+//! ## Contents
+//!
+//! - `HasDomainCode`: domain type → code
+//! - `DomainCode`: code → `(Ns, Local)`
+//! - `DomainCodeEqOp<Rhs>`: code equality predicate (`OpTy` only)
+//! - `AllDistinctOp`: cons-list of codes → `True` iff pairwise distinct
+//! - `assert_distinct_domain_codes::<List>()`: compile-time collision check
+//! - `ClaimedDomainCode` / `DomainCodeClaim<Code>`: this crate's codes, claimed by `declare_domain!`
+
+use core::marker::PhantomData;
+
+use crate::{And, AndNot, Domain, Ext, NumEqOp, OpTy, OpTyOut, True, UInt, UTerm, U0};
+
+/// The domain code of a domain type.
+///
+/// Other crates implement this for their local domain marker with an `Ext<CrateId, Local>` code.
+pub trait HasDomainCode {
+    type Code;
+}
+
+impl<Code> HasDomainCode for Domain<Code> {
+    type Code = Code;
+}
+
+/// Canonical two-level view of a domain code.
+///
+/// `Ext<UTerm, _>` has no impl: namespace `0` is reserved for this crate's bare codes.
+pub trait DomainCode {
+    type Ns;
+    type Local;
+}
+
+impl DomainCode for UTerm {
+    type Ns = U0;
+    type Local = UTerm;
+}
+
+impl<B, T> DomainCode for UInt<B, T> {
+    type Ns = U0;
+    type Local = UInt<B, T>;
+}
+
+impl<B, T, L> DomainCode for Ext<UInt<B, T>, L> {
+    type Ns = UInt<B, T>;
+    type Local = L;
+}
+
+/// Domain code equality predicate: `Lhs ↦ (Lhs == Rhs)` on canonical `(Ns, Local)` pairs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DomainCodeEqOp<Rhs>(PhantomData<fn() -> Rhs>);
+
+impl<Lhs, Rhs> OpTy<Lhs> for DomainCodeEqOp<Rhs>
+where
+    Lhs: DomainCode,
+    Rhs: DomainCode,
+    NumEqOp<Rhs::Ns>: OpTy<Lhs::Ns>,
+    NumEqOp<Rhs::Local>: OpTy<Lhs::Local>,
+    And<OpTyOut<NumEqOp<Rhs::Local>, Lhs::Local>>: OpTy<OpTyOut<NumEqOp<Rhs::Ns>, Lhs::Ns>>,
+{
+    type OutTy =
+        OpTyOut<And<OpTyOut<NumEqOp<Rhs::Local>, Lhs::Local>>, OpTyOut<NumEqOp<Rhs::Ns>, Lhs::Ns>>;
+}
+
+/// `H` does not occur in the cons-list (`()` / `(X, Rest)`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[doc(hidden)]
+pub struct NotInOp<H>(PhantomData<fn() -> H>);
+
+impl<H> OpTy<()> for NotInOp<H> {
+    type OutTy = True;
+}
+
+impl<H, X, Rest> OpTy<(X, Rest)> for NotInOp<H>
+where
+    NotInOp<H>: OpTy<Rest>,
+    DomainCodeEqOp<H>: OpTy<X>,
+    AndNot<OpTyOut<DomainCodeEqOp<H>, X>>: OpTy<OpTyOut<NotInOp<H>, Rest>>,
+{
+    type OutTy = OpTyOut<AndNot<OpTyOut<DomainCodeEqOp<H>, X>>, OpTyOut<NotInOp<H>, Rest>>;
+}
+
+/// Pairwise distinctness of a cons-list of domain codes: `(C0, (C1, (.., ())))` → `Bool`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllDistinctOp;
+
+impl OpTy<()> for AllDistinctOp {
+    type OutTy = True;
+}
+
+impl<H, Rest> OpTy<(H, Rest)> for AllDistinctOp
+where
+    AllDistinctOp: OpTy<Rest>,
+    NotInOp<H>: OpTy<Rest>,
+    And<OpTyOut<AllDistinctOp, Rest>>: OpTy<OpTyOut<NotInOp<H>, Rest>>,
+{
+    type OutTy = OpTyOut<And<OpTyOut<AllDistinctOp, Rest>>, OpTyOut<NotInOp<H>, Rest>>;
+}
+
+/// Compile-time collision check: only type-checks if the codes in `List` are pairwise distinct.
+///
+/// Force it from a constant so the check runs on `cargo build`:
+/// `const _: fn() = assert_distinct_domain_codes::<MyDomainCodes>;`
+#[inline]
+pub fn assert_distinct_domain_codes<List>()
+where
+    AllDistinctOp: OpTy<List, OutTy = True>,
+{
+}
+
+/// A domain code claimed by a `declare_domain! { domain Name = CODE; }` in this crate.
+///
+/// The macro emits `impl ClaimedDomainCode for DomainCodeClaim<CODE> {}` once per domain, so
+/// two of this crate's domains with the same code are conflicting impls (E0119) and no list
+/// has to be kept in sync. Other crates cannot add claims (orphan rule); they use the
+/// `domain struct` form and `assert_distinct_domain_codes` over their own list.
+#[doc(hidden)]
+pub trait ClaimedDomainCode {}
+
+/// Claim slot for a domain code (see `ClaimedDomainCode`).
+#[doc(hidden)]
+pub struct DomainCodeClaim<Code>(PhantomData<fn() -> Code>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type_eq, False, U1, U2, U3, U7};
+    use crate::{D_BOOL, D_HLIST_FOLD};
+
+    #[test]
+    fn external_codes_never_equal_builtin_codes() {
+        assert_type_eq::<OpTyOut<DomainCodeEqOp<D_BOOL>, Ext<U1, U0>>, False>();
+        assert_type_eq::<OpTyOut<DomainCodeEqOp<D_HLIST_FOLD>, Ext<U1, U7>>, False>();
+        assert_type_eq::<OpTyOut<DomainCodeEqOp<Ext<U1, U3>>, Ext<U1, U3>>, True>();
+        assert_type_eq::<OpTyOut<DomainCodeEqOp<Ext<U1, U3>>, Ext<U2, U3>>, False>();
+    }
+
+    #[test]
+    fn all_distinct_detects_duplicates() {
+        assert_type_eq::<OpTyOut<AllDistinctOp, (U0, (U1, (Ext<U1, U0>, ())))>, True>();
+        assert_type_eq::<OpTyOut<AllDistinctOp, (U0, (U1, (U0, ())))>, False>();
+        assert_type_eq::<OpTyOut<AllDistinctOp, (Ext<U2, U1>, (U1, (Ext<U2, U1>, ())))>, False>();
+    }
+}
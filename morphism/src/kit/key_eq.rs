@@ -0,0 +1,74 @@
+//! Key equality: decide at the type level whether two registry keys are the same key.
+//!
+//! Keys are compared component-wise: domain code with `DomainCodeEqOp` (so bare and external
+//! `Ext<..>` codes compare correctly), role code and index with `NumEqOp`,
+//! so the answer is a reflected boolean (`True` / `False`) rather than a trait-solving
+//! success/failure. This is what lets callers *branch* on key identity without specialization.
+//!
+//! ## Contents
+//!
+//! - `KeyEqOp<Rhs>`: `Key<..> -> Bool` (is the input key equal to `Rhs`?)
+
+use core::marker::PhantomData;
+
+use crate::{And, DomainCodeEqOp, HasDomainCode, Key, NumEqOp, OpTy, OpTyOut};
+
+/// Key equality predicate: `Lhs ↦ (Lhs == Rhs)` for `Key<D, Role, Index>` keys.
+///
+/// Domains are compared by code (`HasDomainCode`), so built-in and external domains mix freely.
+/// Type-level only (`OpTy`), like the other predicates over naturals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyEqOp<Rhs>(PhantomData<fn() -> Rhs>);
+
+impl<D1, R1, N1, D2, R2, N2> OpTy<Key<D1, R1, N1>> for KeyEqOp<Key<D2, R2, N2>>
+where
+    D1: HasDomainCode,
+    D2: HasDomainCode,
+    DomainCodeEqOp<D2::Code>: OpTy<D1::Code>,
+    NumEqOp<R2>: OpTy<R1>,
+    NumEqOp<N2>: OpTy<N1>,
+    And<OpTyOut<NumEqOp<R2>, R1>>: OpTy<OpTyOut<DomainCodeEqOp<D2::Code>, D1::Code>>,
+    And<OpTyOut<NumEqOp<N2>, N1>>:
+        OpTy<OpTyOut<And<OpTyOut<NumEqOp<R2>, R1>>, OpTyOut<DomainCodeEqOp<D2::Code>, D1::Code>>>,
+{
+    type OutTy = OpTyOut<
+        And<OpTyOut<NumEqOp<N2>, N1>>,
+        OpTyOut<And<OpTyOut<NumEqOp<R2>, R1>>, OpTyOut<DomainCodeEqOp<D2::Code>, D1::Code>>,
+    >;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type_eq, Ext, False, True, D_HLIST, R_SYNTAX, U0, U1};
+    use crate::{IX_FALSE, IX_HLISTNODE, IX_HLIST_PUSH_BACK, IX_NEWTYPENODE, IX_THEN, IX_TRUE};
+
+    #[test]
+    fn key_eq_is_reflexive() {
+        assert_type_eq::<OpTyOut<KeyEqOp<IX_THEN>, IX_THEN>, True>();
+        assert_type_eq::<OpTyOut<KeyEqOp<IX_HLISTNODE>, IX_HLISTNODE>, True>();
+    }
+
+    #[test]
+    fn key_eq_distinguishes_index_domain_and_role() {
+        // same domain + role, different index
+        assert_type_eq::<OpTyOut<KeyEqOp<IX_TRUE>, IX_FALSE>, False>();
+        // same index (U0), different domain
+        assert_type_eq::<OpTyOut<KeyEqOp<IX_TRUE>, IX_HLISTNODE>, False>();
+        assert_type_eq::<OpTyOut<KeyEqOp<IX_NEWTYPENODE>, IX_HLISTNODE>, False>();
+        // same domain + index (U0), different role
+        assert_type_eq::<OpTyOut<KeyEqOp<IX_HLIST_PUSH_BACK>, IX_HLISTNODE>, False>();
+    }
+
+    #[test]
+    fn key_eq_separates_external_domains() {
+        struct ExtDomain;
+        impl HasDomainCode for ExtDomain {
+            type Code = Ext<U1, D_HLIST>;
+        }
+        type ExtKey = Key<ExtDomain, R_SYNTAX, U0>;
+        assert_type_eq::<OpTyOut<KeyEqOp<ExtKey>, ExtKey>, True>();
+        // same local code, role and index as `IX_HLISTNODE`, but a different namespace
+        assert_type_eq::<OpTyOut<KeyEqOp<ExtKey>, IX_HLISTNODE>, False>();
+    }
+}
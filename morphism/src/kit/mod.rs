@@ -10,6 +10,18 @@ pub use op_lift::*;
 mod introspect;
 pub use introspect::*;
 
+mod domain_code;
+pub use domain_code::*;
+
+mod key_eq;
+pub use key_eq::*;
+
+mod type_code;
+pub use type_code::*;
+
+mod declare_domain;
+pub use declare_domain::KeyIdent;
+
 pub mod registry;
 pub use registry::*;
 
@@ -18,3 +30,6 @@ pub use combinators::*;
 
 pub mod tokens;
 pub use tokens::*;
+
+pub mod shape;
+pub use shape::{NodeShape, ShapeOf};
@@ -0,0 +1,19 @@
+use crate::D_BINDING;
+
+crate::declare_domain! {
+    domain BindingDomain = D_BINDING;
+
+    // ****************************************************************************
+    // Semantics keys (R_SEMANTICS): variable binding (de Bruijn, environment machine)
+    // ****************************************************************************
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Binders and variables (desugared into product plumbing during lowering)
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    semantics(table = BindingSemanticsTable) {
+        IX_LAM,
+        IX_VAR,
+        IX_LET,
+    }
+}
@@ -1,16 +1,39 @@
-use crate::{Domain, Key, D_BOOL, R_SYNTAX, U0, U1};
+use crate::D_BOOL;
 
-pub type BoolDomain = Domain<D_BOOL>;
+crate::declare_domain! {
+    domain BoolDomain = D_BOOL;
 
-// ****************************************************************************
-// Syntax keys (R_SYNTAX): boolean term constructors
-// ****************************************************************************
+    // ****************************************************************************
+    // Syntax keys (R_SYNTAX): boolean term constructors
+    // ****************************************************************************
 
-// ─────────────────────────────────────────────────────────────────────────────
-// Boolean value constructors
-// ─────────────────────────────────────────────────────────────────────────────
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Boolean value constructors
+    // ─────────────────────────────────────────────────────────────────────────────
 
-#[allow(non_camel_case_types)]
-pub type IX_TRUE = Key<BoolDomain, R_SYNTAX, U0>;
-#[allow(non_camel_case_types)]
-pub type IX_FALSE = Key<BoolDomain, R_SYNTAX, U1>;
+    syntax {
+        IX_TRUE,
+        IX_FALSE,
+    }
+
+    // ****************************************************************************
+    // Semantics keys (R_SEMANTICS): boolean programs (evaluated during lowering)
+    // ****************************************************************************
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Type-level predicates
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    semantics(table = BoolSemanticsTable) {
+        IX_PRED,
+
+        // ─────────────────────────────────────────────────────────────────────────────
+        // Connectives
+        // ─────────────────────────────────────────────────────────────────────────────
+
+        IX_NOT,
+        IX_AND,
+        IX_OR,
+        IX_XOR,
+    }
+}
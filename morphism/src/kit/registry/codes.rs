@@ -1,7 +1,9 @@
-use crate::{U0, U1, U2, U3, U4, U5, U6, U7};
+use crate::{U0, U1, U10, U11, U2, U3, U4, U5, U6, U7, U8, U9};
 
 // Domain codes (globally unique). Registry owns these numbers.
-// Domain codes are contiguous.
+// Uniqueness is checked by `declare_domain!`: each `domain X = D_*;` claims its code, and a
+// second claim of the same code fails the build. Domain codes are contiguous.
+// They live in namespace 0; other crates use `Ext<CrateId, Local>`.
 #[allow(non_camel_case_types)]
 pub type D_BOOL = U0;
 #[allow(non_camel_case_types)]
@@ -18,5 +20,13 @@ pub type D_OP = U5;
 pub type D_TAGGED = U6;
 #[allow(non_camel_case_types)]
 pub type D_HLIST_FOLD = U7;
+#[allow(non_camel_case_types)]
+pub type D_HOST_TYPE = U8;
+#[allow(non_camel_case_types)]
+pub type D_CONTROL = U9;
+#[allow(non_camel_case_types)]
+pub type D_SUM = U10;
+#[allow(non_camel_case_types)]
+pub type D_BINDING = U11;
 
-// next free: U8 (you will update this manually as you add domains)
+// next free: U12 (you will update this manually as you add domains)
@@ -0,0 +1,23 @@
+use crate::D_CONTROL;
+
+crate::declare_domain! {
+    domain ControlDomain = D_CONTROL;
+
+    // ****************************************************************************
+    // Semantics keys (R_SEMANTICS): program-level control flow
+    // ****************************************************************************
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Static branching (resolved during lowering)
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    semantics(table = ControlSemanticsTable) {
+        IX_IF,
+
+        // ─────────────────────────────────────────────────────────────────────────────
+        // Bounded iteration (unrolled during lowering)
+        // ─────────────────────────────────────────────────────────────────────────────
+
+        IX_REPEAT,
+    }
+}
@@ -0,0 +1,65 @@
+use crate::D_HOST_TYPE;
+
+crate::declare_domain! {
+    domain HostTypeDomain = D_HOST_TYPE;
+
+    // ****************************************************************************
+    // Syntax keys (R_SYNTAX): host type identities
+    // ****************************************************************************
+    //
+    // Constructors of structural type codes (`TypeCode`) for std types. Tuples use the
+    // non-key `TupleCode` head and `Tagged` uses its own key (see `kit/type_code.rs`).
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Primitive scalars
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    syntax {
+        IX_TY_BOOL,
+        IX_TY_CHAR,
+
+        // ─────────────────────────────────────────────────────────────────────────────
+        // Unsigned integers
+        // ─────────────────────────────────────────────────────────────────────────────
+
+        IX_TY_U8,
+        IX_TY_U16,
+        IX_TY_U32,
+        IX_TY_U64,
+        IX_TY_U128,
+        IX_TY_USIZE,
+
+        // ─────────────────────────────────────────────────────────────────────────────
+        // Signed integers
+        // ─────────────────────────────────────────────────────────────────────────────
+
+        IX_TY_I8,
+        IX_TY_I16,
+        IX_TY_I32,
+        IX_TY_I64,
+        IX_TY_I128,
+        IX_TY_ISIZE,
+
+        // ─────────────────────────────────────────────────────────────────────────────
+        // Floating point
+        // ─────────────────────────────────────────────────────────────────────────────
+
+        IX_TY_F32,
+        IX_TY_F64,
+
+        // ─────────────────────────────────────────────────────────────────────────────
+        // Text
+        // ─────────────────────────────────────────────────────────────────────────────
+
+        IX_TY_STRING,
+        IX_TY_STR,
+
+        // ─────────────────────────────────────────────────────────────────────────────
+        // Std containers (one argument code each)
+        // ─────────────────────────────────────────────────────────────────────────────
+
+        IX_TY_OPTION,
+        IX_TY_VEC,
+        IX_TY_BOX,
+    }
+}
@@ -28,30 +28,24 @@ pub use tagged::*;
 mod generic;
 pub use generic::*;
 
+mod host_type;
+pub use host_type::*;
+
+mod control;
+pub use control::*;
+
+mod sum;
+pub use sum::*;
+
+mod binding;
+pub use binding::*;
+
 #[cfg(test)]
 mod tests {
-    use std::any::TypeId;
     use std::collections::BTreeSet;
     use std::fs;
     use std::path::{Path, PathBuf};
 
-    use crate::{IX_HLISTNODE, IX_TRUE};
-
-    /// Collision guard: ensures critical keys from different domains are distinct.
-    ///
-    /// This test catches domain code collisions (like the OpDomain bug we fixed).
-    /// If two keys from different domains have the same TypeId, they're type-equal
-    /// and dispatch/pattern matching will be ambiguous.
-    #[test]
-    fn key_collision_guard() {
-        // Different domains, same index (U0) - should still be distinct
-        assert_ne!(
-            TypeId::of::<IX_TRUE>(),
-            TypeId::of::<IX_HLISTNODE>(),
-            "IX_TRUE (BoolDomain) and IX_HLISTNODE (HlistDomain) must be distinct"
-        );
-    }
-
     // =============================================================================
     // Registry guard tests (Phase 4.D)
     // =============================================================================
@@ -73,7 +67,9 @@ mod tests {
     //
     // 1) Collect declared keys:
     //    - Walk `src/kit/registry/**/*.rs`
-    //    - Parse lines that look like `pub type IX_FOO = ...`
+    //    - Parse lines that look like `pub type IX_FOO = ...`, and `IX_FOO,` entries inside
+    //      the `syntax { .. }` / `semantics { .. }` blocks of a `declare_domain!` (other
+    //      `IX_FOO,` lines, e.g. in `use` lists, are not declarations)
     //    - Collect `IX_FOO` into a set.
     //
     // 2) Collect reference candidates:
@@ -139,26 +135,63 @@ mod tests {
         false
     }
 
-    /// Parse all `IX_*` keys declared in `src/registry/**/*.rs` by scanning for `pub type IX_*`.
+    /// Parse all `IX_*` keys declared in `src/registry/**/*.rs` (`pub type IX_*` or `IX_*`
+    /// entries of a `declare_domain!` role block).
     fn parse_registry_ix_keys(registry_dir: &Path) -> BTreeSet<String> {
         let mut keys = BTreeSet::new();
 
         let mut files = Vec::new();
         walk_rs_files(registry_dir, &mut files);
 
-        for file in files {
-            let Ok(src) = fs::read_to_string(&file) else {
+        // `mod.rs` declares no keys; its tests hold sample `declare_domain!` blocks.
+        for file in files.iter().filter(|f| !f.ends_with("registry/mod.rs")) {
+            let Ok(src) = fs::read_to_string(file) else {
                 continue;
             };
             for line in src.lines() {
-                let line = line.trim_start();
-                let Some(rest) = line.strip_prefix("pub type ") else {
-                    continue;
-                };
-                let Some(tok) = rest.split_whitespace().next() else {
-                    continue;
-                };
-                if tok.starts_with("IX_") {
+                if let Some(rest) = line.trim().strip_prefix("pub type ") {
+                    let tok = rest.split_whitespace().next().unwrap_or("");
+                    if tok.starts_with("IX_") {
+                        keys.insert(tok.to_string());
+                    }
+                }
+            }
+            keys.extend(parse_role_block_keys(&src));
+        }
+
+        keys
+    }
+
+    /// Keys listed in the role blocks of `declare_domain!` invocations:
+    ///
+    /// ```text
+    /// declare_domain! {
+    ///     domain Name = CODE;
+    ///     syntax { IX_A, .. }                    // or: semantics(table = Alias) { .. }
+    /// }
+    /// ```
+    ///
+    /// Only lines inside a `syntax` / `semantics` block of a `declare_domain!` count; one entry
+    /// per line, with optional trailing comma, comment and `#[..]` attribute lines.
+    fn parse_role_block_keys(src: &str) -> BTreeSet<String> {
+        let mut keys = BTreeSet::new();
+        let (mut in_macro, mut in_block) = (false, false);
+
+        for line in src.lines() {
+            let line = line.split("//").next().unwrap_or("").trim();
+            if !in_macro {
+                in_macro = line.ends_with("declare_domain! {");
+            } else if !in_block {
+                in_block = (line.starts_with("syntax") || line.starts_with("semantics"))
+                    && line.ends_with('{');
+                in_macro = line != "}";
+            } else if line == "}" {
+                in_block = false;
+            } else {
+                let tok = line.strip_suffix(',').unwrap_or(line);
+                if tok.starts_with("IX_")
+                    && tok.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                {
                     keys.insert(tok.to_string());
                 }
             }
@@ -268,6 +301,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn role_block_parser_only_reads_declare_domain_blocks() {
+        let src = r#"
+            use crate::{
+                IX_IMPORTED,
+            };
+
+            crate::declare_domain! {
+                domain TestDomain = D_TEST;
+
+                // A comment mentioning IX_COMMENTED,
+                syntax {
+                    IX_A,
+                    #[doc(hidden)]
+                    IX_B, // U1
+                }
+
+                semantics(table = TestSemanticsTable) {
+                    IX_C
+                }
+            }
+
+            const KEYS: &[&str] = &[
+                IX_LISTED,
+            ];
+        "#;
+        let keys: Vec<String> = parse_role_block_keys(src).into_iter().collect();
+        assert_eq!(keys, ["IX_A", "IX_B", "IX_C"]);
+    }
+
     /// Guard: every `Tagged<IX_*, ...>` owner must have `IX_*` declared in the registry.
     #[test]
     fn all_tagged_ix_owners_have_registry_keys() {
@@ -1,14 +1,17 @@
-use crate::{Domain, Key, D_NEW_TYPE, R_SYNTAX, U0};
+use crate::D_NEW_TYPE;
 
-pub type NewTypeDomain = Domain<D_NEW_TYPE>;
+crate::declare_domain! {
+    domain NewTypeDomain = D_NEW_TYPE;
 
-// ****************************************************************************
-// Syntax keys (R_SYNTAX): newtype node constructors
-// ****************************************************************************
+    // ****************************************************************************
+    // Syntax keys (R_SYNTAX): newtype node constructors
+    // ****************************************************************************
 
-// ─────────────────────────────────────────────────────────────────────────────
-// Node constructors
-// ─────────────────────────────────────────────────────────────────────────────
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Node constructors
+    // ─────────────────────────────────────────────────────────────────────────────
 
-#[allow(non_camel_case_types)]
-pub type IX_NEWTYPENODE = Key<NewTypeDomain, R_SYNTAX, U0>;
+    syntax {
+        IX_NEWTYPENODE,
+    }
+}
@@ -1,14 +1,17 @@
-use crate::{Domain, Key, D_OP, R_SEMANTICS, U0};
+use crate::D_OP;
 
-pub type OpDomain = Domain<D_OP>;
+crate::declare_domain! {
+    domain OpDomain = D_OP;
 
-// ****************************************************************************
-// Semantics keys (R_SEMANTICS): operation lift bridge
-// ****************************************************************************
+    // ****************************************************************************
+    // Semantics keys (R_SEMANTICS): operation lift bridge
+    // ****************************************************************************
 
-// ─────────────────────────────────────────────────────────────────────────────
-// Operation lift constructors
-// ─────────────────────────────────────────────────────────────────────────────
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Operation lift constructors
+    // ─────────────────────────────────────────────────────────────────────────────
 
-#[allow(non_camel_case_types)]
-pub type IX_OP_LIFT = Key<OpDomain, R_SEMANTICS, U0>;
+    semantics(table = OpSemanticsTable) {
+        IX_OP_LIFT,
+    }
+}
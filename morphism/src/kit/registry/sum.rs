@@ -0,0 +1,17 @@
+use crate::D_SUM;
+
+crate::declare_domain! {
+    domain SumDomain = D_SUM;
+
+    // ****************************************************************************
+    // Syntax keys (R_SYNTAX): binary sum node constructors
+    // ****************************************************************************
+
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Node constructors
+    // ─────────────────────────────────────────────────────────────────────────────
+
+    syntax {
+        IX_SUMNODE,
+    }
+}
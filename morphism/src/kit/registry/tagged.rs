@@ -1,16 +1,18 @@
-use crate::{Domain, Key, D_TAGGED, R_SEMANTICS, U0, U1};
+use crate::D_TAGGED;
 
-pub type TaggedDomain = Domain<D_TAGGED>;
+crate::declare_domain! {
+    domain TaggedDomain = D_TAGGED;
 
-// ****************************************************************************
-// Semantics keys (R_SEMANTICS): tagged type operations
-// ****************************************************************************
+    // ****************************************************************************
+    // Semantics keys (R_SEMANTICS): tagged type operations
+    // ****************************************************************************
 
-// ─────────────────────────────────────────────────────────────────────────────
-// Wrap and unwrap operations
-// ─────────────────────────────────────────────────────────────────────────────
+    // ─────────────────────────────────────────────────────────────────────────────
+    // Wrap and unwrap operations
+    // ─────────────────────────────────────────────────────────────────────────────
 
-#[allow(non_camel_case_types)]
-pub type IX_WRAP_TAGGED = Key<TaggedDomain, R_SEMANTICS, U0>;
-#[allow(non_camel_case_types)]
-pub type IX_UNWRAP_TAGGED = Key<TaggedDomain, R_SEMANTICS, U1>;
+    semantics(table = TaggedSemanticsTable) {
+        IX_WRAP_TAGGED,
+        IX_UNWRAP_TAGGED,
+    }
+}
@@ -0,0 +1,246 @@
+//! Payload shapes of program nodes.
+//!
+//! Every key that appears as a program node has one `NodeShape` impl on its domain type, naming
+//! which parts of the node's payload are child programs. The compiler's kernel-only walk,
+//! `metrics`, `pretty`, `normal` and `dot` interpret a node through its shape, so a key (this
+//! crate's or another crate's) gets compiled, counted, rendered, normalized and drawn from that
+//! one impl:
+//!
+//! ```rust,ignore
+//! impl NodeShape<KX_TRIM> for TextDomain {
+//!     type Shape = shape::Nullary;
+//! }
+//! ```
+//!
+//! ## Shapes
+//!
+//! | marker       | payload                  | child programs |
+//! |--------------|--------------------------|----------------|
+//! | `Nullary`    | `NullaryToken`           | none           |
+//! | `Prog`       | `p`                      | `p`            |
+//! | `Step`       | `p`                      | `p`            |
+//! | `ProgPair`   | `(p, q)`                 | `p`, `q`       |
+//! | `ProgValue`  | `(p, value)`             | `p`            |
+//! | `ValueProg`  | `(value, p)`             | `p`            |
+//! | `Branches`   | `(pred, p, q)`           | `p`, `q`       |
+//! | `Value`      | host value               | none           |
+//! | `KeyParam`   | `PhantomData<fn() -> K>` | none           |
+//! | `IndexParam` | `PhantomData<fn() -> N>` | none           |
+//! | `Custom<S>`  | as `S`                   | as `S`         |
+//!
+//! `Step` is a step program of a runtime-bridge key (`hlist_map`): it is not lowered with its
+//! parent but compiled per element when the node runs, so the kernel-only walk skips it.
+//!
+//! `Custom<S>` marks a key that some tool treats specially (`then` prints infix and flattens in
+//! normal forms, `then_balanced` prints infix but keeps its nesting, `bimap(id, id)` normalizes to `id`); tools without a special rule read it as `S`.
+
+use crate::{
+    BindingDomain, BoolDomain, CombinatorsDomain, ControlDomain, GenericDomain, HlistDomain,
+    HlistFoldDomain, NewTypeDomain, OpDomain, SumDomain, TaggedDomain,
+};
+use crate::{
+    IX_AND, IX_APPLY, IX_ASSOC, IX_BIMAP, IX_CHOOSE, IX_CONST_MOVE, IX_DUP, IX_EITHER, IX_FALSE,
+    IX_FANOUT, IX_FIRST, IX_FOLD_CHILDREN_L, IX_FOLD_EVERYTHING_L, IX_FOLD_HLISTNODE_L,
+    IX_FOLD_NEWTYPENODE_L, IX_FST, IX_HLISTNODE, IX_HLIST_COMPOSE_BALANCED_L,
+    IX_HLIST_FOLD_EVERYTHING_STEP_L, IX_HLIST_MAP, IX_HLIST_PUSH_BACK, IX_HLIST_TO_SEGMENTS_L,
+    IX_ID, IX_IF, IX_LAM, IX_LEFT, IX_LET, IX_MAP_CHILDREN, IX_MAP_HLISTNODE, IX_MAP_NEWTYPENODE,
+    IX_NEWTYPENODE, IX_NOT, IX_OP_LIFT, IX_OR, IX_PARTIAL_L, IX_PARTIAL_R, IX_PRED, IX_REPEAT,
+    IX_RIGHT, IX_SECOND, IX_SND, IX_SUMNODE, IX_SWAP, IX_THEN, IX_THEN_BALANCED, IX_TRUE,
+    IX_UNASSOC, IX_UNWRAP_TAGGED, IX_VAR, IX_WRAP_TAGGED, IX_XOR,
+};
+
+/// Payload shape of key `K`'s nodes (implemented on the key's domain type, once per key).
+#[diagnostic::on_unimplemented(
+    message = "domain `{Self}` declares no payload shape for key `{K}`",
+    label = "no `NodeShape` impl for this key",
+    note = "every key that reaches `compile` needs one: `impl NodeShape<KX_..> for {Self} {{ type Shape = shape::Nullary; }}` (see `kit/shape.rs`)"
+)]
+pub trait NodeShape<K> {
+    type Shape;
+}
+
+/// `ShapeOf<D, K>` = `<D as NodeShape<K>>::Shape`
+pub type ShapeOf<D, K> = <D as NodeShape<K>>::Shape;
+
+/// `NullaryToken` payload: no children.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Nullary;
+
+/// A single child program.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Prog;
+
+/// A step program compiled per element at runtime (not lowered with its parent).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Step;
+
+/// Two child programs `(p, q)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProgPair;
+
+/// A child program and a host value `(p, value)`: folds (`(f, acc)`), `partial_r` (`(op, env)`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProgValue;
+
+/// A host value and a child program `(value, p)`: `partial_l` (`(env, op)`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValueProg;
+
+/// A predicate type and two child programs `(pred, p, q)`: `if`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Branches;
+
+/// A host value: constants, structural node contents, lifted ops.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Value;
+
+/// A key parameter `PhantomData<fn() -> K>`: the tag ops `wrap[K]` / `unwrap[K]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyParam;
+
+/// A natural parameter `PhantomData<fn() -> N>`: `var[N]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IndexParam;
+
+/// Shape `S` with key-specific rules in some tools (see module docs).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Custom<S>(pub S);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// This crate's keys
+// ─────────────────────────────────────────────────────────────────────────────
+
+macro_rules! node_shapes {
+    ( $( $D:ty { $( $K:ty => $S:ty ),+ $(,)? } )+ ) => {
+        $( $(
+            impl NodeShape<$K> for $D {
+                type Shape = $S;
+            }
+        )+ )+
+    };
+}
+
+node_shapes! {
+    BindingDomain {
+        IX_LAM => Prog,
+        IX_VAR => IndexParam,
+        IX_LET => ProgPair,
+    }
+    BoolDomain {
+        IX_TRUE => Nullary,
+        IX_FALSE => Nullary,
+        IX_PRED => ProgValue,
+        IX_NOT => Prog,
+        IX_AND => ProgPair,
+        IX_OR => ProgPair,
+        IX_XOR => ProgPair,
+    }
+    CombinatorsDomain {
+        IX_ID => Nullary,
+        IX_THEN => Custom<ProgPair>,
+        IX_PARTIAL_L => ValueProg,
+        IX_PARTIAL_R => ProgValue,
+        IX_FST => Nullary,
+        IX_SND => Nullary,
+        IX_FANOUT => ProgPair,
+        IX_CONST_MOVE => Value,
+        IX_BIMAP => Custom<ProgPair>,
+        IX_APPLY => Nullary,
+        IX_SWAP => Nullary,
+        IX_ASSOC => Nullary,
+        IX_UNASSOC => Nullary,
+        IX_FIRST => Prog,
+        IX_SECOND => Prog,
+        IX_DUP => Nullary,
+        IX_CHOOSE => ProgPair,
+        IX_EITHER => ProgPair,
+        IX_LEFT => Prog,
+        IX_RIGHT => Prog,
+        IX_THEN_BALANCED => Custom<ProgPair>,
+    }
+    ControlDomain {
+        IX_IF => Branches,
+        IX_REPEAT => ProgValue,
+    }
+    GenericDomain {
+        IX_MAP_NEWTYPENODE => Prog,
+        IX_MAP_HLISTNODE => Prog,
+        IX_MAP_CHILDREN => Prog,
+        IX_FOLD_NEWTYPENODE_L => ProgValue,
+        IX_FOLD_CHILDREN_L => ProgValue,
+        IX_FOLD_HLISTNODE_L => ProgValue,
+        IX_FOLD_EVERYTHING_L => ProgValue,
+    }
+    HlistDomain {
+        IX_HLISTNODE => Value,
+        IX_HLIST_PUSH_BACK => Nullary,
+    }
+    HlistFoldDomain {
+        IX_HLIST_TO_SEGMENTS_L => Prog,
+        IX_HLIST_COMPOSE_BALANCED_L => Nullary,
+        IX_HLIST_MAP => Step,
+        IX_HLIST_FOLD_EVERYTHING_STEP_L => Step,
+    }
+    NewTypeDomain {
+        IX_NEWTYPENODE => Value,
+    }
+    OpDomain {
+        IX_OP_LIFT => Value,
+    }
+    SumDomain {
+        IX_SUMNODE => Value,
+    }
+    TaggedDomain {
+        IX_WRAP_TAGGED => KeyParam,
+        IX_UNWRAP_TAGGED => KeyParam,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_type_eq, dot, fst, normalize, pretty, then, Ext, NodeCount, NullaryToken, PrettyKey,
+        Tagged, U1, U2, U3,
+    };
+
+    crate::declare_domain! {
+        domain struct ShapedDomain: Ext<U3, U2>;
+
+        semantics {
+            KX_NOP,
+            KX_TWICE,
+        }
+    }
+
+    impl PrettyKey<KX_NOP> for ShapedDomain {
+        const NAME: &'static str = "nop";
+    }
+
+    impl PrettyKey<KX_TWICE> for ShapedDomain {
+        const NAME: &'static str = "twice";
+    }
+
+    impl NodeShape<KX_NOP> for ShapedDomain {
+        type Shape = Nullary;
+    }
+
+    impl NodeShape<KX_TWICE> for ShapedDomain {
+        type Shape = Prog;
+    }
+
+    #[test]
+    fn one_shape_impl_drives_every_tool() {
+        let nop = Tagged::<KX_NOP, _>::new(NullaryToken);
+        let prog = Tagged::<KX_TWICE, _>::new(then(then(nop, fst()), nop));
+
+        assert_eq!(format!("{}", pretty(&prog)), "twice(nop >>> fst >>> nop)");
+        assert_type_eq::<NodeCount<Tagged<KX_TWICE, Tagged<KX_NOP, NullaryToken>>>, U2>();
+        assert_type_eq::<NodeCount<Tagged<KX_NOP, NullaryToken>>, U1>();
+        assert_eq!(
+            normalize(prog),
+            Tagged::<KX_TWICE, _>::new(then(nop, then(fst(), nop)))
+        );
+        assert!(dot(&prog).contains("n0 [label=\"twice\\nExt<U3, U2> / R_SEMANTICS / KX_TWICE"));
+    }
+}
@@ -8,7 +8,10 @@
 //!
 //! ## Definitions
 //!
-//! - `Domain<Code>`: a namespace marker. `Code` is a globally unique type-level natural.
+//! - `Domain<Code>`: a namespace marker. `Code` is a globally unique type-level natural
+//!   (this crate's domains, e.g. `D_BOOL`).
+//! - `Ext<CrateId, Local>`: the domain code of a domain owned by another crate. Such a crate
+//!   declares its own local domain marker type (see `HasDomainCode`) carrying this code.
 //! - `Key<D, R, N>`: an identifier within a domain `D`, with role `R`, indexed by `N`.
 //!   - `D`: the domain (e.g., `Domain<D_BOOL>`)
 //!   - `R`: the role code (e.g., `R_SYNTAX`, `R_SEMANTICS`)
@@ -18,7 +21,8 @@
 //!
 //! ## Invariants
 //!
-//! - Domain codes are globally unique. They are allocated in `registry::codes`.
+//! - Domain codes are globally unique. This crate's codes are allocated in `registry::codes`;
+//!   external codes are unique per `(CrateId, Local)` pair, with `CrateId` non-zero.
 //! - Role codes are defined in `registry::roles`.
 //! - Within a `(Domain, Role)` pair, indices are contiguous and unique.
 //! - `Key<..>` values are zero-sized and must remain so.
@@ -36,10 +40,22 @@ use std::marker::PhantomData;
 /// Namespace marker for a family of keys.
 ///
 /// `Code` is a globally unique type-level natural number. Domain codes are defined in
-/// `registry::codes`.
+/// `registry::codes`. (Other crates use local marker types with `Ext<..>` codes instead.)
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Domain<Code>(PhantomData<fn() -> Code>);
 
+/// Two-level domain code for domains owned by other crates: `(CrateId, Local)`.
+///
+/// - `CrateId`: a non-zero type-level natural identifying the owning crate (namespace `0` is
+///   this crate's bare-natural codes).
+/// - `Local`: the domain's code within that crate, allocated by the crate itself.
+///
+/// Two crates can only collide if they pick the same `CrateId`. Ids are allocated in the
+/// registry README (`CrateId` table); a single assertion over the combined code list catches
+/// a collision between unregistered crates at compile time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ext<CrateId, Local>(PhantomData<fn() -> (CrateId, Local)>);
+
 /// Identifier within a domain: `Key<Domain, RoleCode, Index>`.
 ///
 /// The domain and role parameters prevent cross-domain/role collisions even when the same index type
@@ -0,0 +1,241 @@
+//! Structural type codes: a reflected identity for host types.
+//!
+//! Rust cannot answer "is `X` the same type as `T`?" with a boolean without specialization.
+//! `TypeCode` sidesteps this by giving each participating host type a *code* built only from
+//! registry keys, and codes can be compared with `KeyEqOp` into `True` / `False`.
+//!
+//! ## Code shape
+//!
+//! A code is a node `(Key, Args)` where `Args` is a cons-list of argument codes:
+//! - scalars: `(IX_TY_*, ())`
+//! - `Option<T>`, `Vec<T>`, `Box<T>`: `(IX_TY_*, (Code<T>, ()))`
+//! - tuples: `(TupleCode, (Code<A0>, (Code<A1>, ..())))`
+//! - `Tagged<K, P>`: `(K, (Code<P>, ()))`
+//!
+//! Tuples are headed by `TupleCode`, which is not a `Key`, so no `Tagged<K, _>` can share a
+//! tuple's code (with a key head, `(A,)` and `Tagged<IX_HLISTNODE, A>` would coincide).
+//!
+//! ## Coverage
+//!
+//! Without specialization there is no fallback code: a type without a `TypeCode` impl makes
+//! every comparison that mentions it fail to compile, rather than compare unequal. This is the
+//! limit of `SameTypeOp` and of `OnlyFor`, whose every argument must have a code. User types
+//! opt in by implementing `TypeCode` with a key from their own domain, e.g.
+//! `type Code = (IX_MY_TYPE, ());` (add argument codes for generic parameters).
+//!
+//! ## Contents
+//!
+//! - `TypeCode`: host type → code
+//! - `TupleCode`: head of tuple codes
+//! - `CodeEqOp<Rhs>`: code equality predicate (`OpTy` only)
+//! - `SameTypeOp<T>`: `X ↦ (X == T)` for types with a `TypeCode`
+
+use core::marker::PhantomData;
+
+use crate::{And, False, Key, KeyEqOp, OpTy, OpTyOut, Tagged, True};
+use crate::{
+    IX_TY_BOOL, IX_TY_BOX, IX_TY_CHAR, IX_TY_F32, IX_TY_F64, IX_TY_I128, IX_TY_I16, IX_TY_I32,
+    IX_TY_I64, IX_TY_I8, IX_TY_ISIZE, IX_TY_OPTION, IX_TY_STR, IX_TY_STRING, IX_TY_U128, IX_TY_U16,
+    IX_TY_U32, IX_TY_U64, IX_TY_U8, IX_TY_USIZE, IX_TY_VEC,
+};
+
+/// Reflected identity of a host type (see module docs for the code shape).
+pub trait TypeCode {
+    type Code;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Scalars
+// ─────────────────────────────────────────────────────────────────────────────
+
+macro_rules! impl_scalar_type_code {
+    ( $( $S:ty => $K:ty ),+ $(,)? ) => {
+        $(
+            impl TypeCode for $S {
+                type Code = ($K, ());
+            }
+        )+
+    };
+}
+
+impl_scalar_type_code!(
+    bool => IX_TY_BOOL,
+    char => IX_TY_CHAR,
+    u8 => IX_TY_U8,
+    u16 => IX_TY_U16,
+    u32 => IX_TY_U32,
+    u64 => IX_TY_U64,
+    u128 => IX_TY_U128,
+    usize => IX_TY_USIZE,
+    i8 => IX_TY_I8,
+    i16 => IX_TY_I16,
+    i32 => IX_TY_I32,
+    i64 => IX_TY_I64,
+    i128 => IX_TY_I128,
+    isize => IX_TY_ISIZE,
+    f32 => IX_TY_F32,
+    f64 => IX_TY_F64,
+    String => IX_TY_STRING,
+    &str => IX_TY_STR,
+);
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Std containers
+// ─────────────────────────────────────────────────────────────────────────────
+
+impl<T: TypeCode> TypeCode for Option<T> {
+    type Code = (IX_TY_OPTION, (T::Code, ()));
+}
+
+impl<T: TypeCode> TypeCode for Vec<T> {
+    type Code = (IX_TY_VEC, (T::Code, ()));
+}
+
+impl<T: TypeCode> TypeCode for Box<T> {
+    type Code = (IX_TY_BOX, (T::Code, ()));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Tuples (HList nodes) and tagged values
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Head of tuple codes. Not a `Key`, so it never equals the key of a `Tagged` code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TupleCode;
+
+macro_rules! code_list {
+    () => { () };
+    ( $A:ident $(, $Rest:ident )* ) => { (<$A as TypeCode>::Code, code_list!($( $Rest ),*)) };
+}
+
+macro_rules! impl_tuple_type_code {
+    ( $( $A:ident ),* ) => {
+        impl<$( $A: TypeCode, )*> TypeCode for ( $( $A, )* ) {
+            type Code = (TupleCode, code_list!($( $A ),*));
+        }
+    };
+}
+
+impl_tuple_type_code!();
+impl_tuple_type_code!(A0);
+impl_tuple_type_code!(A0, A1);
+impl_tuple_type_code!(A0, A1, A2);
+impl_tuple_type_code!(A0, A1, A2, A3);
+impl_tuple_type_code!(A0, A1, A2, A3, A4);
+impl_tuple_type_code!(A0, A1, A2, A3, A4, A5);
+impl_tuple_type_code!(A0, A1, A2, A3, A4, A5, A6);
+impl_tuple_type_code!(A0, A1, A2, A3, A4, A5, A6, A7);
+impl_tuple_type_code!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
+impl_tuple_type_code!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_tuple_type_code!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_tuple_type_code!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, Tail);
+
+impl<K, P: TypeCode> TypeCode for Tagged<K, P> {
+    type Code = (K, (P::Code, ()));
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// CodeEqOp<Rhs>: structural equality of codes
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Code equality predicate: `Lhs ↦ (Lhs == Rhs)`, comparing keys with `KeyEqOp`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CodeEqOp<Rhs>(PhantomData<fn() -> Rhs>);
+
+impl<D1, R1, N1, D2, R2, N2> OpTy<Key<D1, R1, N1>> for CodeEqOp<Key<D2, R2, N2>>
+where
+    KeyEqOp<Key<D2, R2, N2>>: OpTy<Key<D1, R1, N1>>,
+{
+    type OutTy = OpTyOut<KeyEqOp<Key<D2, R2, N2>>, Key<D1, R1, N1>>;
+}
+
+impl OpTy<TupleCode> for CodeEqOp<TupleCode> {
+    type OutTy = True;
+}
+
+impl<D, R, N> OpTy<TupleCode> for CodeEqOp<Key<D, R, N>> {
+    type OutTy = False;
+}
+
+impl<D, R, N> OpTy<Key<D, R, N>> for CodeEqOp<TupleCode> {
+    type OutTy = False;
+}
+
+impl OpTy<()> for CodeEqOp<()> {
+    type OutTy = True;
+}
+
+impl<H, T> OpTy<()> for CodeEqOp<(H, T)> {
+    type OutTy = False;
+}
+
+impl<H, T> OpTy<(H, T)> for CodeEqOp<()> {
+    type OutTy = False;
+}
+
+impl<H1, T1, H2, T2> OpTy<(H1, T1)> for CodeEqOp<(H2, T2)>
+where
+    CodeEqOp<H2>: OpTy<H1>,
+    CodeEqOp<T2>: OpTy<T1>,
+    And<OpTyOut<CodeEqOp<T2>, T1>>: OpTy<OpTyOut<CodeEqOp<H2>, H1>>,
+{
+    type OutTy = OpTyOut<And<OpTyOut<CodeEqOp<T2>, T1>>, OpTyOut<CodeEqOp<H2>, H1>>;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// SameTypeOp<T>: type equality as a reflected boolean
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Type equality predicate: `X ↦ (X == T)`, decided on `TypeCode`s.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SameTypeOp<T>(PhantomData<fn() -> T>);
+
+impl<T, X> OpTy<X> for SameTypeOp<T>
+where
+    T: TypeCode,
+    X: TypeCode,
+    CodeEqOp<T::Code>: OpTy<X::Code>,
+{
+    type OutTy = OpTyOut<CodeEqOp<T::Code>, X::Code>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_type_eq, NewTypeNode, IX_HLISTNODE};
+
+    #[test]
+    fn same_type_decides_scalars_and_tuples() {
+        assert_type_eq::<OpTyOut<SameTypeOp<u8>, u8>, True>();
+        assert_type_eq::<OpTyOut<SameTypeOp<u8>, u16>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<String>, &str>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<(u8, String)>, (u8, String)>, True>();
+        assert_type_eq::<OpTyOut<SameTypeOp<(u8, String)>, (u8, u8)>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<(u8,)>, (u8, u8)>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<()>, (u8,)>, False>();
+    }
+
+    #[test]
+    fn same_type_sees_through_tags() {
+        assert_type_eq::<OpTyOut<SameTypeOp<NewTypeNode<u8>>, NewTypeNode<u8>>, True>();
+        assert_type_eq::<OpTyOut<SameTypeOp<NewTypeNode<u8>>, NewTypeNode<i8>>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<NewTypeNode<(u8,)>>, (u8,)>, False>();
+    }
+
+    #[test]
+    fn tuples_never_share_a_code_with_tagged_values() {
+        type Tuple = ((u8,),);
+        type Tag = Tagged<IX_HLISTNODE, (u8,)>;
+        assert_type_eq::<OpTyOut<SameTypeOp<Tuple>, Tag>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<Tag>, Tuple>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<Tag>, Tag>, True>();
+    }
+
+    #[test]
+    fn same_type_decides_std_containers() {
+        assert_type_eq::<OpTyOut<SameTypeOp<Vec<u8>>, Vec<u8>>, True>();
+        assert_type_eq::<OpTyOut<SameTypeOp<Vec<u8>>, Vec<i8>>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<Option<u8>>, Vec<u8>>, False>();
+        assert_type_eq::<OpTyOut<SameTypeOp<Box<(u8,)>>, Box<(u8,)>>, True>();
+    }
+}
@@ -12,15 +12,26 @@
 //! - `cargo run --example mappable_simple`
 //! - `cargo run --example mappable_multi`
 //! - `cargo run --example foldable`
+//! - `cargo run --example recursion_schemes`
+//! - `cargo run --example custom_domain`
+//! - `cargo run --example dot_export`
 //!
 //! ## Where to look
 //! - `kit/`: kernel traits, combinators, and the `registry` (keys / identity).
 //! - `base/`: primitive domains (bool/num/order/arithmetic).
 //! - `container/`: Rust ↔ DSL bridge (`Container`, tuple bridge).
 //! - `effects/`: higher-level AST constructors (frontend syntax terms).
-//! - `compiler/`: closed lowering + reify pipeline.
+//! - `control/`: program-level branching (`if_prog`), boolean programs (`and_prog`, `pred_prog`, ..) and `repeat_n`, resolved during lowering.
+//! - `binding/`: variable binding in programs (`lam`, `var::<Ix>`, `let_`), lowered to product plumbing.
+//! - `compiler/`: closed lowering + reify pipeline (per-domain tables; extend via `LowerDomain` / `ReifyDomain`).
 //! - `hlist/`: tuple-based "HList" product plumbing (map/fold/get-at).
 //! - `new_type/`: `NewTypeNode` (opaque/atomic boundary).
+//! - `sum/`: `Either<L, R>` binary sums and `SumNode` (`Result` / `Option` bridge).
+//! - `recursion/`: recursion schemes (`cata`, `ana`, `para`) over user-defined recursive types.
+//! - `pretty/`: program pretty-printer (`pretty(&prog)`), before or after lowering.
+//! - `metrics/`: compile-time program size (`NodeCount`, `Depth`, lowered variants, `assert_metric`).
+//! - `normal/`: program normal forms (`normalize`, `NormalForm`) and equality (`ProgEq`).
+//! - `dot/`: Graphviz export of programs (`dot(&prog)`), before or after lowering.
 //!
 //! Status: pre-release; APIs will change quickly.
 
@@ -48,9 +59,33 @@ pub use container::*;
 mod effects;
 pub use effects::*;
 
+mod control;
+pub use control::*;
+
+mod binding;
+pub use binding::*;
+
 mod new_type;
 pub use new_type::*;
 
+mod sum;
+pub use sum::*;
+
+mod recursion;
+pub use recursion::*;
+
+mod pretty;
+pub use pretty::*;
+
+mod metrics;
+pub use metrics::*;
+
+mod normal;
+pub use normal::*;
+
+mod dot;
+pub use dot::*;
+
 pub trait Generic {
     type Shape; // constructor tag (Key<Domain, Nat>)
     type Children; // immediate children representation
@@ -0,0 +1,232 @@
+//! Compile-time program metrics: node count and depth as type-level naturals.
+//!
+//! A program's cost to the compiler grows with its size: every node is one table dispatch
+//! while lowering and one handler instantiation while reifying. The ops here compute that size
+//! from the program **type**, before and after lowering, so a crate can see (and cap) it:
+//!
+//! ```rust,ignore
+//! type N = NodeCount<Prog>;                    // e.g. U5
+//! type D = LoweredDepth<HlistNode<(u8, u16)>, Prog>;
+//! let prog = assert_metric::<DepthOp, LeOp<U16>, _>(prog); // fails to compile if deeper
+//! ```
+//!
+//! ## Contents
+//!
+//! - `ProgChildren<K, Payload>`: which part of a payload is child programs (implemented on the
+//!   payload shapes of `kit/shape.rs`; a node's shape comes from its domain's `NodeShape` impl)
+//! - `ProgMetrics`: node count and depth of a program type (`Tagged` nodes, tuples of programs)
+//! - `NodeCountOp` / `DepthOp`: the same metrics as ops, for `OfLowered` and `AssertMetric`
+//! - `OfLowered<Input, Metric>`: apply a metric to the lowered form of a program
+//! - `AssertMetric<Metric, Pred>` / `assert_metric`: reject programs whose metric fails `Pred`
+//!
+//! Host values inside payloads (lifted ops, constants, accumulators) are leaves and count zero.
+
+mod shapes;
+
+use core::marker::PhantomData;
+
+use crate::{Add, AssertTrue, Key, LowerTable, MaxOp, NodeShape, OpOnce, OpTy, OpTyOut, ShapeOf};
+use crate::{Tagged, U0, U1};
+
+/// Child programs of a node `Tagged<K, Payload>` (implemented on payload shapes, once per shape).
+///
+/// `Children` is a program, a tuple of programs, or `()` for leaves.
+pub trait ProgChildren<K, Payload> {
+    type Children;
+}
+
+/// `NodeChildren<D, K, Payload>`: child programs of a node whose key `K` lives in domain `D`.
+pub type NodeChildren<D, K, Payload> = <ShapeOf<D, K> as ProgChildren<K, Payload>>::Children;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Node count and depth
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Size of a program type: node count and depth as naturals.
+///
+/// Implemented for `Tagged` nodes whose domain has a `NodeShape` impl, and for tuples of
+/// programs (count is the sum of the elements, depth the deepest element; `()` is `U0`, `U0`).
+pub trait ProgMetrics {
+    type NodeCount;
+    type Depth;
+}
+
+impl<D, R, N, Payload, Children> ProgMetrics for Tagged<Key<D, R, N>, Payload>
+where
+    D: NodeShape<Key<D, R, N>>,
+    ShapeOf<D, Key<D, R, N>>: ProgChildren<Key<D, R, N>, Payload, Children = Children>,
+    Children: ProgMetrics,
+    Add<U1>: OpTy<Children::NodeCount> + OpTy<Children::Depth>,
+{
+    type NodeCount = OpTyOut<Add<U1>, Children::NodeCount>;
+    type Depth = OpTyOut<Add<U1>, Children::Depth>;
+}
+
+impl ProgMetrics for () {
+    type NodeCount = U0;
+    type Depth = U0;
+}
+
+// `(A0, A1, ..)` is `A0` combined with the tail tuple `(A1, ..)`. The tail's metrics are bound
+// to impl parameters (`TailCount`, `TailDepth`) so the impl's own bounds stay opaque instead of
+// being normalised through the tail's impl.
+macro_rules! impl_tuple_metrics {
+    () => {};
+    ( $A0:ident $(, $A:ident )* ) => {
+        impl<$A0, $( $A, )* TailCount, TailDepth> ProgMetrics for ($A0, $( $A, )*)
+        where
+            $A0: ProgMetrics,
+            ($( $A, )*): ProgMetrics<NodeCount = TailCount, Depth = TailDepth>,
+            Add<TailCount>: OpTy<$A0::NodeCount>,
+            TailDepth: MaxAfter<$A0::Depth>,
+        {
+            type NodeCount = OpTyOut<Add<TailCount>, $A0::NodeCount>;
+            type Depth = <TailDepth as MaxAfter<$A0::Depth>>::Max;
+        }
+
+        impl_tuple_metrics!($( $A ),*);
+    };
+}
+
+impl_tuple_metrics!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+
+/// `OpTyOut<MaxOp<Self>, Lhs>`, as a bound on the tail depth itself.
+///
+/// A goal whose `Self` is still uninferred waits, so the comparison only runs once the tail's
+/// depth is known; `MaxOp<Rhs>: OpTy<Lhs>` alone would start comparing a half-inferred natural
+/// and make `CmpNat` recurse without bound.
+pub trait MaxAfter<Lhs> {
+    type Max;
+}
+
+impl<Lhs, Rhs> MaxAfter<Lhs> for Rhs
+where
+    MaxOp<Rhs>: OpTy<Lhs>,
+{
+    type Max = OpTyOut<MaxOp<Rhs>, Lhs>;
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Metric ops
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Number of program nodes, as an op: `Prog -> ProgMetrics::NodeCount`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NodeCountOp;
+
+impl<Prog: ProgMetrics> OpTy<Prog> for NodeCountOp {
+    type OutTy = Prog::NodeCount;
+}
+
+/// Nesting depth (a leaf node is `U1`), as an op: `Prog -> ProgMetrics::Depth`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DepthOp;
+
+impl<Prog: ProgMetrics> OpTy<Prog> for DepthOp {
+    type OutTy = Prog::Depth;
+}
+
+/// `NodeCount<Prog>` = `<Prog as ProgMetrics>::NodeCount`
+pub type NodeCount<Prog> = <Prog as ProgMetrics>::NodeCount;
+
+/// `Depth<Prog>` = `<Prog as ProgMetrics>::Depth`
+pub type Depth<Prog> = <Prog as ProgMetrics>::Depth;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Lowered form
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Apply `Metric` to the program that `lower::<Input, _>` produces.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OfLowered<Input, Metric>(PhantomData<fn() -> (Input, Metric)>);
+
+impl<Input, Metric, Prog> OpTy<Prog> for OfLowered<Input, Metric>
+where
+    LowerTable<Input>: OpOnce<Prog>,
+    Metric: OpTy<<LowerTable<Input> as OpOnce<Prog>>::OutVal>,
+{
+    type OutTy = OpTyOut<Metric, <LowerTable<Input> as OpOnce<Prog>>::OutVal>;
+}
+
+/// Node count after lowering for `Input`.
+///
+/// This is also the reify instantiation estimate: reification instantiates one handler per
+/// kernel node (inner compilations through `EvalProg` are not included).
+pub type LoweredNodeCount<Input, Prog> = OpTyOut<OfLowered<Input, NodeCountOp>, Prog>;
+
+/// Depth after lowering for `Input`.
+pub type LoweredDepth<Input, Prog> = OpTyOut<OfLowered<Input, DepthOp>, Prog>;
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Assertion
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Identity on programs whose `Metric` satisfies `Pred`; no impl otherwise.
+///
+/// `AssertMetric<DepthOp, LeOp<U64>>` caps depth at 64;
+/// `AssertMetric<OfLowered<Input, NodeCountOp>, LeOp<U64>>` caps the lowered size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AssertMetric<Metric, Pred>(PhantomData<fn() -> (Metric, Pred)>);
+
+impl<Metric, Pred, Prog> OpTy<Prog> for AssertMetric<Metric, Pred>
+where
+    Metric: OpTy<Prog>,
+    AssertTrue<Pred>: OpTy<OpTyOut<Metric, Prog>>,
+{
+    type OutTy = Prog;
+}
+
+/// Pass `prog` through unchanged, failing to compile unless `Pred(Metric(Prog))` is `True`.
+#[inline]
+pub fn assert_metric<Metric, Pred, Prog>(prog: Prog) -> Prog
+where
+    AssertMetric<Metric, Pred>: OpTy<Prog>,
+{
+    prog
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_type_eq, id, lower, map_children_prog, op_lift, then, Fanout, Fst, HlistNode, Id,
+        LeOp, MapChildrenProg, OpLift, Snd, Then, U2, U3, U4, U5, U6, U64,
+    };
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    type H = HlistNode<(u8, u16)>;
+    type MapAddTen = MapChildrenProg<OpLift<AddTen>>;
+
+    #[test]
+    fn counts_nodes_and_depth() {
+        type Prog = Then<Id, Fanout<Fst, Snd>>;
+        assert_type_eq::<NodeCount<Prog>, U5>();
+        assert_type_eq::<Depth<Prog>, U3>();
+        assert_type_eq::<NodeCount<(Prog, Id)>, OpTyOut<Add<U1>, U5>>();
+        assert_type_eq::<Depth<(Id, Prog, Id)>, U3>();
+    }
+
+    #[test]
+    fn measures_lowered_form() {
+        assert_type_eq::<NodeCount<MapAddTen>, U2>();
+        assert_type_eq::<LoweredNodeCount<H, MapAddTen>, U6>();
+        assert_type_eq::<LoweredDepth<H, MapAddTen>, U4>();
+
+        // `unwrap[hlist] >>> hlist_map(lift(AddTen)) >>> wrap[hlist]`: what `lower` returns.
+        fn node_count_of<P: ProgMetrics>(_: &P) -> core::marker::PhantomData<NodeCount<P>> {
+            core::marker::PhantomData
+        }
+        let lowered = lower::<H, _>(map_children_prog(op_lift(AddTen)));
+        let _: core::marker::PhantomData<U6> = node_count_of(&lowered);
+    }
+
+    #[test]
+    fn assert_metric_passes_programs_within_bounds() {
+        let prog = then(id(), id());
+        let prog = assert_metric::<DepthOp, LeOp<U2>, _>(prog);
+        let prog = assert_metric::<NodeCountOp, LeOp<U64>, _>(prog);
+        assert_eq!(prog, then(id(), id()));
+    }
+}
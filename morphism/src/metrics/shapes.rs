@@ -0,0 +1,58 @@
+//! Child programs of each payload shape (see `kit/shape.rs`).
+//!
+//! - nullary, host values, key / index parameters: no children
+//! - programs: `p` (also steps), `(p, q)`; `(p, value)` / `(value, p)` keep only the program; `if` keeps both
+//!   arms (the predicate is a type, not a program)
+
+use core::marker::PhantomData;
+
+use super::ProgChildren;
+use crate::shape::{
+    Branches, Custom, IndexParam, KeyParam, Nullary, Prog, ProgPair, ProgValue, Step, Value,
+    ValueProg,
+};
+use crate::NullaryToken;
+
+impl<K> ProgChildren<K, NullaryToken> for Nullary {
+    type Children = ();
+}
+
+impl<K, P> ProgChildren<K, P> for Prog {
+    type Children = P;
+}
+
+impl<K, P> ProgChildren<K, P> for Step {
+    type Children = P;
+}
+
+impl<K, P, Q> ProgChildren<K, (P, Q)> for ProgPair {
+    type Children = (P, Q);
+}
+
+impl<K, P, V> ProgChildren<K, (P, V)> for ProgValue {
+    type Children = P;
+}
+
+impl<K, V, P> ProgChildren<K, (V, P)> for ValueProg {
+    type Children = P;
+}
+
+impl<K, Pred, P, Q> ProgChildren<K, (Pred, P, Q)> for Branches {
+    type Children = (P, Q);
+}
+
+impl<K, V> ProgChildren<K, V> for Value {
+    type Children = ();
+}
+
+impl<K, T> ProgChildren<K, PhantomData<fn() -> T>> for KeyParam {
+    type Children = ();
+}
+
+impl<K, Ix> ProgChildren<K, PhantomData<fn() -> Ix>> for IndexParam {
+    type Children = ();
+}
+
+impl<K, Payload, S: ProgChildren<K, Payload>> ProgChildren<K, Payload> for Custom<S> {
+    type Children = S::Children;
+}
@@ -0,0 +1,218 @@
+//! Program normal forms and program equality.
+//!
+//! `normalize(prog)` rewrites a program into a canonical form, recursively through child
+//! programs:
+//!
+//! - `then` chains are right-associated: `(p >>> q) >>> r` → `p >>> (q >>> r)`
+//! - `id`s inside a chain are removed; a chain of only `id`s is `id`
+//! - `bimap(id, id)` is `id`
+//!
+//! Two programs mean the same thing (up to these laws) when their normal form **types** are
+//! equal, so the check is static:
+//!
+//! ```rust,ignore
+//! assert_type_eq::<NormalForm<Then<Id, MapChildrenProg<F>>>, MapChildrenProg<F>>();
+//! assert_prog_eq::<Then<Then<A, B>, C>, Then<A, Then<Id, Then<B, C>>>>();
+//! ```
+//!
+//! ## Contents
+//!
+//! - `NormalizeNode<K, Payload>`: normal form of a node (implemented on the payload shapes of
+//!   `kit/shape.rs`; a node's shape comes from its domain's `NodeShape` impl)
+//! - `Normalize`: programs that have a normal form; `NormalForm<P>` alias, `normalize(prog)`
+//! - `ProgEq<Rhs>` / `assert_prog_eq`: equal normal forms
+//!
+//! Host values (lifted ops, constants, accumulators) are opaque: `lift(f)` and `lift(g)` are
+//! equal only if `f` and `g` have the same type.
+
+mod shapes;
+
+use crate::compiler::optimize::{KeyIsOp, ThenOnto, Unchain};
+use crate::{bimap, id, And, Bimap, False, Id, Key, NodeShape, OpTy, OpTyOut, ShapeOf, Tagged};
+use crate::{Then, True, IX_ID};
+
+/// Normal form of a node `Tagged<K, Payload>` (implemented on payload shapes, once per shape).
+pub trait NormalizeNode<K, Payload> {
+    type Out;
+    fn normalize_node(payload: Payload) -> Self::Out;
+}
+
+/// A program with a normal form (see module docs).
+pub trait Normalize {
+    type Out;
+    fn normalize(self) -> Self::Out;
+}
+
+impl<D, R, N, Payload> Normalize for Tagged<Key<D, R, N>, Payload>
+where
+    D: NodeShape<Key<D, R, N>>,
+    ShapeOf<D, Key<D, R, N>>: NormalizeNode<Key<D, R, N>, Payload>,
+{
+    type Out = <ShapeOf<D, Key<D, R, N>> as NormalizeNode<Key<D, R, N>, Payload>>::Out;
+
+    #[inline]
+    fn normalize(self) -> Self::Out {
+        <ShapeOf<D, Key<D, R, N>> as NormalizeNode<Key<D, R, N>, Payload>>::normalize_node(self.0)
+    }
+}
+
+/// `NormalForm<Prog>` = `<Prog as Normalize>::Out`
+pub type NormalForm<Prog> = <Prog as Normalize>::Out;
+
+/// Rewrite a program into its normal form (see module docs).
+#[inline]
+pub fn normalize<Prog: Normalize>(prog: Prog) -> Prog::Out {
+    prog.normalize()
+}
+
+/// Programs whose normal forms are the same type.
+///
+/// Implemented for every such pair, so `A: ProgEq<B>` is a static equality check; for two
+/// program types, `assert_type_eq::<NormalForm<A>, NormalForm<B>>()` says the same.
+pub trait ProgEq<Rhs> {}
+
+impl<Lhs, Rhs> ProgEq<Rhs> for Lhs
+where
+    Lhs: Normalize,
+    Rhs: Normalize<Out = Lhs::Out>,
+{
+}
+
+/// Compile-time check that `A` and `B` have the same normal form.
+#[inline]
+pub fn assert_prog_eq<A, B>()
+where
+    A: ProgEq<B>,
+{
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Rewrites
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Normal form of `then(f, g)` from the normal forms of `f` and `g`: flatten both chains
+/// (dropping `id`s) and rebuild one right-associated chain.
+pub type NormalThen<F, G> = <<Then<F, G> as ThenOnto<()>>::Out as Unchain>::Out;
+
+#[inline]
+fn normal_then<F, G>(f: F, g: G) -> NormalThen<F, G>
+where
+    Then<F, G>: ThenOnto<()>,
+    <Then<F, G> as ThenOnto<()>>::Out: Unchain,
+{
+    crate::then(f, g).then_onto(()).unchain()
+}
+
+/// Is a (normalized) program `id`: `True` / `False`.
+pub type IsId<P> = OpTyOut<KeyIsOp<IX_ID>, P>;
+
+/// `bimap(f, g)` for normalized arms, collapsed to `id` when both arms are (`Self = both id`).
+pub trait CollapseBimap<F, G> {
+    type Out;
+    fn collapse(f: F, g: G) -> Self::Out;
+}
+
+impl<F, G> CollapseBimap<F, G> for True {
+    type Out = Id;
+
+    #[inline]
+    fn collapse(_: F, _: G) -> Self::Out {
+        id()
+    }
+}
+
+impl<F, G> CollapseBimap<F, G> for False {
+    type Out = Bimap<F, G>;
+
+    #[inline]
+    fn collapse(f: F, g: G) -> Self::Out {
+        bimap(f, g)
+    }
+}
+
+/// Both arms of a normalized `bimap` are `id`.
+pub type BothId<F, G> = OpTyOut<And<IsId<G>>, IsId<F>>;
+
+#[inline]
+fn normal_bimap<F, G>(f: F, g: G) -> <BothId<F, G> as CollapseBimap<F, G>>::Out
+where
+    KeyIsOp<IX_ID>: OpTy<F> + OpTy<G>,
+    And<IsId<G>>: OpTy<IsId<F>>,
+    BothId<F, G>: CollapseBimap<F, G>,
+{
+    <BothId<F, G> as CollapseBimap<F, G>>::collapse(f, g)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assert_type_eq, fanout, fst, lower, map_children_prog, op_lift, pretty, snd, then,
+        Container, Fst, HlistNode, IntoContainer, MapChildrenProg, MappablePlan, OpLift, Snd,
+    };
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Double;
+
+    /// The program a `Container` carries.
+    trait ProgOf {
+        type Prog;
+    }
+
+    impl<T: IntoContainer, Prog> ProgOf for Container<T, Prog> {
+        type Prog = Prog;
+    }
+
+    #[test]
+    fn right_associates_and_drops_ids() {
+        let prog = then(then(id(), fst()), then(snd(), then(id(), id())));
+        assert_eq!(normalize(prog), then(fst(), snd()));
+        assert_eq!(normalize(then(id(), id())), id());
+
+        assert_type_eq::<NormalForm<Then<Then<Fst, Snd>, Fst>>, Then<Fst, Then<Snd, Fst>>>();
+        assert_prog_eq::<Then<Then<Fst, Snd>, Fst>, Then<Fst, Then<Id, Then<Snd, Fst>>>>();
+
+        type Nf = NormalForm<Then<Then<Id, Fst>, Bimap<Id, Id>>>;
+        assert_type_eq::<Nf, Fst>();
+        assert_type_eq::<NormalForm<Nf>, Nf>();
+    }
+
+    #[test]
+    fn collapses_bimap_of_ids_in_children() {
+        let prog = fanout(bimap(id(), then(id(), id())), bimap(id(), fst()));
+        assert_eq!(
+            pretty(&normalize(prog)).to_string(),
+            "fanout(id, bimap(id, fst))"
+        );
+        assert_prog_eq::<Then<Bimap<Id, Id>, Fst>, Fst>();
+    }
+
+    #[test]
+    fn container_pipeline_equals_hand_written_program() {
+        type Built = <<(u8, u16) as MappablePlan>::Plan<AddTen> as ProgOf>::Prog;
+        assert_prog_eq::<Built, MapChildrenProg<OpLift<AddTen>>>();
+
+        let built = Container::new((1_u8, 2_u16)).map(AddTen).map(Double);
+        let hand = then(
+            map_children_prog(op_lift(AddTen)),
+            map_children_prog(op_lift(Double)),
+        );
+        fn same_prog<C: ProgOf, P>(_: &C, _: &P)
+        where
+            C::Prog: ProgEq<P>,
+        {
+        }
+        same_prog(&built, &hand);
+
+        // Normal forms of lowered programs are right-associated chains as well.
+        let lowered = lower::<HlistNode<(u8, u16)>, _>(then(id(), hand));
+        assert_eq!(
+            pretty(&normalize(lowered)).to_string(),
+            "unwrap[hlist] >>> hlist_map(lift(AddTen)) >>> wrap[hlist] >>> \
+             unwrap[hlist] >>> hlist_map(lift(Double)) >>> wrap[hlist]"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Normal forms of each payload shape (see `kit/shape.rs`).
+//!
+//! - nullary, host values, key / index parameters: the node itself
+//! - programs: `p` (also steps), `(p, q)`, `(p, value)`, `(value, p)`, `(pred, p, q)`: the node
+//!   over normalized programs
+//! - `Custom`: `then` and `bimap` are rewritten (see `NormalThen`, `CollapseBimap`); `then_balanced`
+//!   keeps its nesting, like a plain `(p, q)` node
+
+use core::marker::PhantomData;
+
+use super::{normal_bimap, normal_then, BothId, CollapseBimap, IsId, NormalThen};
+use super::{Normalize, NormalizeNode};
+use crate::compiler::optimize::{KeyIsOp, ThenOnto, Unchain};
+use crate::shape::{
+    Branches, Custom, IndexParam, KeyParam, Nullary, Prog, ProgPair, ProgValue, Step, Value,
+    ValueProg,
+};
+use crate::{And, NullaryToken, OpTy, Tagged, Then, IX_BIMAP, IX_ID, IX_THEN, IX_THEN_BALANCED};
+
+impl<K> NormalizeNode<K, NullaryToken> for Nullary {
+    type Out = Tagged<K, NullaryToken>;
+
+    #[inline]
+    fn normalize_node(payload: NullaryToken) -> Self::Out {
+        Tagged::new(payload)
+    }
+}
+
+impl<K, P: Normalize> NormalizeNode<K, P> for Prog {
+    type Out = Tagged<K, P::Out>;
+
+    #[inline]
+    fn normalize_node(p: P) -> Self::Out {
+        Tagged::new(p.normalize())
+    }
+}
+
+impl<K, P: Normalize> NormalizeNode<K, P> for Step {
+    type Out = Tagged<K, P::Out>;
+
+    #[inline]
+    fn normalize_node(p: P) -> Self::Out {
+        Tagged::new(p.normalize())
+    }
+}
+
+impl<K, P: Normalize, Q: Normalize> NormalizeNode<K, (P, Q)> for ProgPair {
+    type Out = Tagged<K, (P::Out, Q::Out)>;
+
+    #[inline]
+    fn normalize_node((p, q): (P, Q)) -> Self::Out {
+        Tagged::new((p.normalize(), q.normalize()))
+    }
+}
+
+impl<K, P: Normalize, V> NormalizeNode<K, (P, V)> for ProgValue {
+    type Out = Tagged<K, (P::Out, V)>;
+
+    #[inline]
+    fn normalize_node((p, v): (P, V)) -> Self::Out {
+        Tagged::new((p.normalize(), v))
+    }
+}
+
+impl<K, V, P: Normalize> NormalizeNode<K, (V, P)> for ValueProg {
+    type Out = Tagged<K, (V, P::Out)>;
+
+    #[inline]
+    fn normalize_node((v, p): (V, P)) -> Self::Out {
+        Tagged::new((v, p.normalize()))
+    }
+}
+
+// `if` keeps its predicate and normalizes both arms (it is resolved only by lowering).
+impl<K, Pred, P: Normalize, Q: Normalize> NormalizeNode<K, (Pred, P, Q)> for Branches {
+    type Out = Tagged<K, (Pred, P::Out, Q::Out)>;
+
+    #[inline]
+    fn normalize_node((pred, p, q): (Pred, P, Q)) -> Self::Out {
+        Tagged::new((pred, p.normalize(), q.normalize()))
+    }
+}
+
+impl<K, V> NormalizeNode<K, V> for Value {
+    type Out = Tagged<K, V>;
+
+    #[inline]
+    fn normalize_node(v: V) -> Self::Out {
+        Tagged::new(v)
+    }
+}
+
+impl<K, T> NormalizeNode<K, PhantomData<fn() -> T>> for KeyParam {
+    type Out = Tagged<K, PhantomData<fn() -> T>>;
+
+    #[inline]
+    fn normalize_node(payload: PhantomData<fn() -> T>) -> Self::Out {
+        Tagged::new(payload)
+    }
+}
+
+impl<K, Ix> NormalizeNode<K, PhantomData<fn() -> Ix>> for IndexParam {
+    type Out = Tagged<K, PhantomData<fn() -> Ix>>;
+
+    #[inline]
+    fn normalize_node(payload: PhantomData<fn() -> Ix>) -> Self::Out {
+        Tagged::new(payload)
+    }
+}
+
+impl<F: Normalize, G: Normalize> NormalizeNode<IX_THEN, (F, G)> for Custom<ProgPair>
+where
+    Then<F::Out, G::Out>: ThenOnto<()>,
+    <Then<F::Out, G::Out> as ThenOnto<()>>::Out: Unchain,
+{
+    type Out = NormalThen<F::Out, G::Out>;
+
+    #[inline]
+    fn normalize_node((f, g): (F, G)) -> Self::Out {
+        normal_then(f.normalize(), g.normalize())
+    }
+}
+
+impl<F, G> NormalizeNode<IX_THEN_BALANCED, (F, G)> for Custom<ProgPair>
+where
+    ProgPair: NormalizeNode<IX_THEN_BALANCED, (F, G)>,
+{
+    type Out = <ProgPair as NormalizeNode<IX_THEN_BALANCED, (F, G)>>::Out;
+
+    #[inline]
+    fn normalize_node(payload: (F, G)) -> Self::Out {
+        <ProgPair as NormalizeNode<IX_THEN_BALANCED, (F, G)>>::normalize_node(payload)
+    }
+}
+
+impl<F: Normalize, G: Normalize> NormalizeNode<IX_BIMAP, (F, G)> for Custom<ProgPair>
+where
+    KeyIsOp<IX_ID>: OpTy<F::Out> + OpTy<G::Out>,
+    And<IsId<G::Out>>: OpTy<IsId<F::Out>>,
+    BothId<F::Out, G::Out>: CollapseBimap<F::Out, G::Out>,
+{
+    type Out = <BothId<F::Out, G::Out> as CollapseBimap<F::Out, G::Out>>::Out;
+
+    #[inline]
+    fn normalize_node((f, g): (F, G)) -> Self::Out {
+        normal_bimap(f.normalize(), g.normalize())
+    }
+}
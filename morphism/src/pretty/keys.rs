@@ -0,0 +1,138 @@
+//! Names of this crate's registry keys: one `PrettyKey` impl per key, grouped by domain.
+//!
+//! How a node renders its payload follows from its shape (`shapes.rs`).
+
+use super::PrettyKey;
+use crate::{
+    BindingDomain, BoolDomain, CombinatorsDomain, ControlDomain, GenericDomain, HlistDomain,
+    HlistFoldDomain, HostTypeDomain, NewTypeDomain, OpDomain, SumDomain, TaggedDomain,
+};
+use crate::{
+    IX_AND, IX_APPLY, IX_ASSOC, IX_BIMAP, IX_CHOOSE, IX_CONST_MOVE, IX_DUP, IX_EITHER, IX_FALSE,
+    IX_FANOUT, IX_FIRST, IX_FOLD_CHILDREN_L, IX_FOLD_EVERYTHING_L, IX_FOLD_HLISTNODE_L,
+    IX_FOLD_NEWTYPENODE_L, IX_FST, IX_HLISTNODE, IX_HLIST_COMPOSE_BALANCED_L,
+    IX_HLIST_FOLD_EVERYTHING_STEP_L, IX_HLIST_MAP, IX_HLIST_PUSH_BACK, IX_HLIST_TO_SEGMENTS_L,
+    IX_ID, IX_IF, IX_LAM, IX_LEFT, IX_LET, IX_MAP_CHILDREN, IX_MAP_HLISTNODE, IX_MAP_NEWTYPENODE,
+    IX_NEWTYPENODE, IX_NOT, IX_OP_LIFT, IX_OR, IX_PARTIAL_L, IX_PARTIAL_R, IX_PRED, IX_REPEAT,
+    IX_RIGHT, IX_SECOND, IX_SND, IX_SUMNODE, IX_SWAP, IX_THEN, IX_THEN_BALANCED, IX_TRUE,
+    IX_UNASSOC, IX_UNWRAP_TAGGED, IX_VAR, IX_WRAP_TAGGED, IX_XOR,
+};
+use crate::{
+    IX_TY_BOOL, IX_TY_BOX, IX_TY_CHAR, IX_TY_F32, IX_TY_F64, IX_TY_I128, IX_TY_I16, IX_TY_I32,
+    IX_TY_I64, IX_TY_I8, IX_TY_ISIZE, IX_TY_OPTION, IX_TY_STR, IX_TY_STRING, IX_TY_U128, IX_TY_U16,
+    IX_TY_U32, IX_TY_U64, IX_TY_U8, IX_TY_USIZE, IX_TY_VEC,
+};
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Names
+// ─────────────────────────────────────────────────────────────────────────────
+
+macro_rules! key_names {
+    ( $( $D:ty { $( $K:ty => $name:literal $([value = $value:literal])? ),+ $(,)? } )+ ) => {
+        $( $(
+            impl PrettyKey<$K> for $D {
+                const NAME: &'static str = $name;
+                $( const VALUE: &'static str = $value; )?
+            }
+        )+ )+
+    };
+}
+
+key_names! {
+    BindingDomain {
+        IX_LAM => "lam",
+        IX_VAR => "var",
+        IX_LET => "let",
+    }
+    BoolDomain {
+        IX_TRUE => "true",
+        IX_FALSE => "false",
+        IX_PRED => "pred" [value = "pred"],
+        IX_NOT => "not",
+        IX_AND => "and",
+        IX_OR => "or",
+        IX_XOR => "xor",
+    }
+    CombinatorsDomain {
+        IX_ID => "id",
+        IX_THEN => "then",
+        IX_PARTIAL_L => "partial_l" [value = "env"],
+        IX_PARTIAL_R => "partial_r" [value = "env"],
+        IX_FST => "fst",
+        IX_SND => "snd",
+        IX_FANOUT => "fanout",
+        IX_CONST_MOVE => "const_move",
+        IX_BIMAP => "bimap",
+        IX_APPLY => "apply",
+        IX_SWAP => "swap",
+        IX_ASSOC => "assoc",
+        IX_UNASSOC => "unassoc",
+        IX_FIRST => "first",
+        IX_SECOND => "second",
+        IX_DUP => "dup",
+        IX_CHOOSE => "choose",
+        IX_EITHER => "either",
+        IX_LEFT => "left",
+        IX_RIGHT => "right",
+        IX_THEN_BALANCED => "then_balanced",
+    }
+    ControlDomain {
+        IX_IF => "if",
+        IX_REPEAT => "repeat_n" [value = "n"],
+    }
+    GenericDomain {
+        IX_MAP_NEWTYPENODE => "map_newtype",
+        IX_MAP_HLISTNODE => "map_hlist",
+        IX_MAP_CHILDREN => "map_children",
+        IX_FOLD_NEWTYPENODE_L => "fold_newtype_l" [value = "acc"],
+        IX_FOLD_CHILDREN_L => "fold_children_l" [value = "acc"],
+        IX_FOLD_HLISTNODE_L => "fold_hlist_l" [value = "acc"],
+        IX_FOLD_EVERYTHING_L => "fold_everything_l" [value = "acc"],
+    }
+    HlistDomain {
+        IX_HLISTNODE => "hlist",
+        IX_HLIST_PUSH_BACK => "push_back",
+    }
+    HlistFoldDomain {
+        IX_HLIST_TO_SEGMENTS_L => "to_segments_l",
+        IX_HLIST_COMPOSE_BALANCED_L => "compose_balanced_l",
+        IX_HLIST_MAP => "hlist_map",
+        IX_HLIST_FOLD_EVERYTHING_STEP_L => "fold_everything_step_l",
+    }
+    NewTypeDomain {
+        IX_NEWTYPENODE => "newtype",
+    }
+    OpDomain {
+        IX_OP_LIFT => "lift" [value = "op"],
+    }
+    SumDomain {
+        IX_SUMNODE => "sum",
+    }
+    TaggedDomain {
+        IX_WRAP_TAGGED => "wrap",
+        IX_UNWRAP_TAGGED => "unwrap",
+    }
+    HostTypeDomain {
+        IX_TY_BOOL => "bool",
+        IX_TY_CHAR => "char",
+        IX_TY_U8 => "u8",
+        IX_TY_U16 => "u16",
+        IX_TY_U32 => "u32",
+        IX_TY_U64 => "u64",
+        IX_TY_U128 => "u128",
+        IX_TY_USIZE => "usize",
+        IX_TY_I8 => "i8",
+        IX_TY_I16 => "i16",
+        IX_TY_I32 => "i32",
+        IX_TY_I64 => "i64",
+        IX_TY_I128 => "i128",
+        IX_TY_ISIZE => "isize",
+        IX_TY_F32 => "f32",
+        IX_TY_F64 => "f64",
+        IX_TY_STRING => "String",
+        IX_TY_STR => "&str",
+        IX_TY_OPTION => "Option",
+        IX_TY_VEC => "Vec",
+        IX_TY_BOX => "Box",
+    }
+}
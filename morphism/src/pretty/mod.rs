@@ -0,0 +1,227 @@
+//! Program pretty-printer: render a reflected program as readable text.
+//!
+//! `Then<UnwrapTagged<IX_HLISTNODE>, Then<HlistToSegmentsLProg<OpLift<Op>>, ..>>` prints as
+//! `unwrap[hlist] >>> to_segments_l(lift(Op)) >>> ..`. Printing is a plain interpretation of
+//! the program value, so it works the same before and after lowering (`lower::<P, _>(prog)`).
+//!
+//! ## Contents
+//!
+//! - `PrettyKey<K>`: a key's short name (implemented on the key's domain type, once per key)
+//! - `PrettyNode<K, Payload>`: how a payload renders (implemented on the payload shapes of
+//!   `kit/shape.rs`; a node's shape comes from its domain's `NodeShape` impl)
+//! - `KeyName`: key → name (derived from `PrettyKey`)
+//! - `ProgFmt`: programs that can be rendered (`Tagged` nodes and tuples of programs)
+//! - `pretty(&prog)`: `Display` adapter; `short_type_name::<T>()` for leaves
+//!
+//! Keys are rendered through their domain type (like `LowerDomain` / `ReifyDomain`) so that
+//! other crates can name and render their own keys on their local domain marker.
+
+mod keys;
+mod shapes;
+
+use core::fmt;
+
+use crate::{Key, NodeShape, ShapeOf, Tagged};
+
+/// Short name of key `K` in its domain (implemented on the domain type, once per key).
+pub trait PrettyKey<K> {
+    const NAME: &'static str;
+
+    /// What the node's host value is, e.g. `acc` for a fold (labels the value's edge in `dot`).
+    const VALUE: &'static str = "value";
+}
+
+/// Rendering of a node `Tagged<K, Payload>` named `name` (implemented on payload shapes, once
+/// per shape).
+pub trait PrettyNode<K, Payload> {
+    fn fmt_node(name: &str, payload: &Payload, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// A key's short name, e.g. `IX_HLISTNODE` → `"hlist"`.
+pub trait KeyName {
+    const NAME: &'static str;
+}
+
+impl<D, R, N> KeyName for Key<D, R, N>
+where
+    D: PrettyKey<Key<D, R, N>>,
+{
+    const NAME: &'static str = <D as PrettyKey<Key<D, R, N>>>::NAME;
+}
+
+/// A reflected program that can be rendered as text.
+pub trait ProgFmt {
+    fn fmt_prog(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<D, R, N, Payload> ProgFmt for Tagged<Key<D, R, N>, Payload>
+where
+    D: PrettyKey<Key<D, R, N>> + NodeShape<Key<D, R, N>>,
+    ShapeOf<D, Key<D, R, N>>: PrettyNode<Key<D, R, N>, Payload>,
+{
+    #[inline]
+    fn fmt_prog(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = <D as PrettyKey<Key<D, R, N>>>::NAME;
+        <ShapeOf<D, Key<D, R, N>> as PrettyNode<Key<D, R, N>, Payload>>::fmt_node(name, &self.0, f)
+    }
+}
+
+// Tuples of programs (e.g. per-child op lists) render as `(p0, p1, ..)`.
+macro_rules! impl_tuple_prog_fmt {
+    ( $( $A:ident $a:ident ),* ) => {
+        impl<$( $A: ProgFmt, )*> ProgFmt for ( $( $A, )* ) {
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            fn fmt_prog(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ( $( $a, )* ) = self;
+                let mut sep = "";
+                f.write_str("(")?;
+                $(
+                    f.write_str(sep)?;
+                    $a.fmt_prog(f)?;
+                    sep = ", ";
+                )*
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+impl_tuple_prog_fmt!();
+impl_tuple_prog_fmt!(A0 a0);
+impl_tuple_prog_fmt!(A0 a0, A1 a1);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10);
+impl_tuple_prog_fmt!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7, A8 a8, A9 a9, A10 a10, A11 a11);
+
+/// `Display` adapter for a program: `format!("{}", pretty(&prog))`.
+#[derive(Clone, Copy, Debug)]
+pub struct Pretty<'a, P>(pub &'a P);
+
+impl<P: ProgFmt> fmt::Display for Pretty<'_, P> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_prog(f)
+    }
+}
+
+/// Render a program with `Display` (see module docs).
+#[inline]
+pub const fn pretty<P: ProgFmt>(prog: &P) -> Pretty<'_, P> {
+    Pretty(prog)
+}
+
+/// `core::any::type_name` without module paths: `alloc::string::String` → `String`,
+/// `my_crate::ops::AddN<u8>` → `AddN<u8>`. Binary naturals are shown in decimal
+/// (`UInt<B0, UInt<B1, UTerm>>` → `U2`).
+///
+/// Used for leaves that are not programs (lifted ops, constants, accumulators).
+pub fn short_type_name<T: ?Sized>() -> String {
+    let full = core::any::type_name::<T>();
+    let mut out = String::with_capacity(full.len());
+    let mut segment = String::new();
+    for c in full.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            segment.push(c);
+        } else {
+            out.push_str(segment.rsplit("::").next().unwrap_or(""));
+            segment.clear();
+            out.push(c);
+        }
+    }
+    out.push_str(segment.rsplit("::").next().unwrap_or(""));
+    decimal_nats(&out)
+}
+
+/// Rewrite every `UTerm` / `UInt<B, ..>` chain in a (path-stripped) type name as `U<n>`.
+fn decimal_nats(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut rest = name;
+    while !rest.is_empty() {
+        let at_ident_start = !out.ends_with(|c: char| c.is_alphanumeric() || c == '_');
+        match at_ident_start.then(|| parse_nat(rest)).flatten() {
+            Some((n, tail)) => {
+                out.push_str(&format!("U{n}"));
+                rest = tail;
+            }
+            None => {
+                let c = rest.chars().next().unwrap_or_default();
+                out.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+/// Parse one LSB-first nat at the start of `s`: `(value, remaining input)`.
+fn parse_nat(s: &str) -> Option<(u128, &str)> {
+    if let Some(tail) = s.strip_prefix("UTerm") {
+        return Some((0, tail));
+    }
+    let tail = s.strip_prefix("UInt<")?;
+    let (bit, tail) = match tail.strip_prefix("B0, ") {
+        Some(tail) => (0, tail),
+        None => (1, tail.strip_prefix("B1, ")?),
+    };
+    let (high, tail) = parse_nat(tail)?;
+    let tail = tail.strip_prefix('>')?;
+    Some((high.checked_mul(2)? + bit, tail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bimap, fanout, fst, id, lower, map_children_prog, op_lift, snd, then, unwrap_tagged,
+        Domain, HlistNode, IX_HLISTNODE, U0, U11, U2,
+    };
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct AddTen;
+
+    #[test]
+    fn renders_combinators() {
+        let prog = then(
+            id(),
+            then(fanout(fst(), snd()), bimap(id(), op_lift(AddTen))),
+        );
+        assert_eq!(
+            pretty(&prog).to_string(),
+            "id >>> fanout(fst, snd) >>> bimap(id, lift(AddTen))"
+        );
+        assert_eq!(
+            pretty(&unwrap_tagged::<IX_HLISTNODE>()).to_string(),
+            "unwrap[hlist]"
+        );
+    }
+
+    #[test]
+    fn renders_before_and_after_lowering() {
+        let prog = map_children_prog(op_lift(AddTen));
+        assert_eq!(pretty(&prog).to_string(), "map_children(lift(AddTen))");
+
+        let lowered = lower::<HlistNode<(u8, u16)>, _>(prog);
+        assert_eq!(
+            pretty(&lowered).to_string(),
+            "unwrap[hlist] >>> hlist_map(lift(AddTen)) >>> wrap[hlist]"
+        );
+    }
+
+    #[test]
+    fn short_type_name_strips_paths() {
+        assert_eq!(short_type_name::<String>(), "String");
+        assert_eq!(short_type_name::<Option<String>>(), "Option<String>");
+        assert_eq!(short_type_name::<(u8, AddTen)>(), "(u8, AddTen)");
+        assert_eq!(
+            short_type_name::<Key<Domain<U2>, U0, U11>>(),
+            "Key<Domain<U2>, U0, U11>"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Renderings of each payload shape (see `kit/shape.rs`).
+//!
+//! - nullary: `name`
+//! - programs (and steps): `name(p)`, `name(p, q)`; host values beside them print by short type name
+//! - host values (constants, node contents, lifted ops): `name(Type)`
+//! - key / index parameters: `wrap[hlist]`, `var[U0]`
+//! - `Custom`: `then` is infix and associative, so nested `then`s print flat (`p >>> q >>> r`);
+//!   `then_balanced` prints the same way
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use super::{short_type_name, KeyName, Pretty, PrettyNode, ProgFmt};
+use crate::shape::{
+    Branches, Custom, IndexParam, KeyParam, Nullary, Prog, ProgPair, ProgValue, Step, Value,
+    ValueProg,
+};
+use crate::{NullaryToken, IX_BIMAP, IX_THEN, IX_THEN_BALANCED};
+
+impl<K> PrettyNode<K, NullaryToken> for Nullary {
+    fn fmt_node(name: &str, _: &NullaryToken, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(name)
+    }
+}
+
+impl<K, P: ProgFmt> PrettyNode<K, P> for Prog {
+    fn fmt_node(name: &str, p: &P, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", name, Pretty(p))
+    }
+}
+
+impl<K, P: ProgFmt> PrettyNode<K, P> for Step {
+    fn fmt_node(name: &str, p: &P, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", name, Pretty(p))
+    }
+}
+
+impl<K, P: ProgFmt, Q: ProgFmt> PrettyNode<K, (P, Q)> for ProgPair {
+    fn fmt_node(name: &str, (p, q): &(P, Q), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}, {})", name, Pretty(p), Pretty(q))
+    }
+}
+
+impl<K, P: ProgFmt, V> PrettyNode<K, (P, V)> for ProgValue {
+    fn fmt_node(name: &str, (p, _): &(P, V), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}, {})", name, Pretty(p), short_type_name::<V>())
+    }
+}
+
+impl<K, V, P: ProgFmt> PrettyNode<K, (V, P)> for ValueProg {
+    fn fmt_node(name: &str, (_, p): &(V, P), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({}, {})", name, short_type_name::<V>(), Pretty(p))
+    }
+}
+
+// The predicate prints by type: `if(KeyIsOp<..>, p, q)`.
+impl<K, Pred, P: ProgFmt, Q: ProgFmt> PrettyNode<K, (Pred, P, Q)> for Branches {
+    fn fmt_node(name: &str, (_, p, q): &(Pred, P, Q), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pred = short_type_name::<Pred>();
+        write!(f, "{}({}, {}, {})", name, pred, Pretty(p), Pretty(q))
+    }
+}
+
+impl<K, V> PrettyNode<K, V> for Value {
+    fn fmt_node(name: &str, _: &V, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({})", name, short_type_name::<V>())
+    }
+}
+
+// Tag ops print the key they wrap/unwrap: `unwrap[hlist]`.
+impl<K, T: KeyName> PrettyNode<K, PhantomData<fn() -> T>> for KeyParam {
+    fn fmt_node(name: &str, _: &PhantomData<fn() -> T>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]", name, T::NAME)
+    }
+}
+
+// `var` prints its de Bruijn index: `var[U0]`.
+impl<K, Ix> PrettyNode<K, PhantomData<fn() -> Ix>> for IndexParam {
+    fn fmt_node(
+        name: &str,
+        _: &PhantomData<fn() -> Ix>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}[{}]", name, short_type_name::<Ix>())
+    }
+}
+
+impl<P: ProgFmt, Q: ProgFmt> PrettyNode<IX_THEN, (P, Q)> for Custom<ProgPair> {
+    fn fmt_node(_: &str, (p, q): &(P, Q), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} >>> {}", Pretty(p), Pretty(q))
+    }
+}
+
+impl<P: ProgFmt, Q: ProgFmt> PrettyNode<IX_THEN_BALANCED, (P, Q)> for Custom<ProgPair> {
+    fn fmt_node(name: &str, payload: &(P, Q), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Custom<ProgPair> as PrettyNode<IX_THEN, (P, Q)>>::fmt_node(name, payload, f)
+    }
+}
+
+impl<P: ProgFmt, Q: ProgFmt> PrettyNode<IX_BIMAP, (P, Q)> for Custom<ProgPair> {
+    fn fmt_node(name: &str, payload: &(P, Q), f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <ProgPair as PrettyNode<IX_BIMAP, (P, Q)>>::fmt_node(name, payload, f)
+    }
+}
@@ -0,0 +1,236 @@
+//! `map_layer` derived from a layer's container view.
+//!
+//! A base functor can expose one layer through `IntoContainer` / `FromContainer` like any other
+//! host type (`SumNode` for variants, tuples / `HlistNode` for fields). The view wraps every
+//! recursive position in `Rec`, which is what tells a recursive position apart from an ordinary
+//! field of the same type: `MapRec` applies the op to `Rec` leaves and moves every other leaf
+//! through. `map_layer_via_container` runs that walk between the two container conversions.
+
+use crate::{Either, FromContainer, OpOnce, Tagged};
+
+/// A recursive position in a layer's container view.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rec<R>(pub R);
+
+/// Map `F: A -> B` over the `Rec<A>` positions of a container payload.
+///
+/// Implemented for `Rec`, `Either`, tuples, `Tagged` nodes and std scalar leaves. Any other
+/// field type of a layer needs a pass-through impl (`Out = Self`).
+pub trait MapRec<A, B, F> {
+    type Out;
+
+    fn map_rec(self, f: F) -> Self::Out;
+}
+
+impl<A, B, F> MapRec<A, B, F> for Rec<A>
+where
+    F: OpOnce<A, OutVal = B>,
+{
+    type Out = Rec<B>;
+
+    #[inline]
+    fn map_rec(self, f: F) -> Rec<B> {
+        Rec(f.run(self.0))
+    }
+}
+
+impl<A, B, F, L, R> MapRec<A, B, F> for Either<L, R>
+where
+    L: MapRec<A, B, F>,
+    R: MapRec<A, B, F>,
+{
+    type Out = Either<L::Out, R::Out>;
+
+    #[inline]
+    fn map_rec(self, f: F) -> Self::Out {
+        match self {
+            Either::Left(l) => Either::Left(l.map_rec(f)),
+            Either::Right(r) => Either::Right(r.map_rec(f)),
+        }
+    }
+}
+
+impl<A, B, F, K, P> MapRec<A, B, F> for Tagged<K, P>
+where
+    P: MapRec<A, B, F>,
+{
+    type Out = Tagged<K, P::Out>;
+
+    #[inline]
+    fn map_rec(self, f: F) -> Self::Out {
+        Tagged::new(self.0.map_rec(f))
+    }
+}
+
+impl<A, B, F> MapRec<A, B, F> for () {
+    type Out = ();
+
+    #[inline]
+    fn map_rec(self, _f: F) {}
+}
+
+// Positions are visited left-to-right; every position but the last gets a clone of `f`.
+macro_rules! impl_tuple_map_rec {
+    ( $( $T:ident $t:ident ),* ; $L:ident $l:ident ) => {
+        impl<A, B, F, $( $T, )* $L> MapRec<A, B, F> for ( $( $T, )* $L, )
+        where
+            F: Clone,
+            $( $T: MapRec<A, B, F>, )*
+            $L: MapRec<A, B, F>,
+        {
+            type Out = ( $( $T::Out, )* $L::Out, );
+
+            #[inline]
+            fn map_rec(self, f: F) -> Self::Out {
+                let ( $( $t, )* $l, ) = self;
+                $( let $t = $t.map_rec(f.clone()); )*
+                ( $( $t, )* $l.map_rec(f), )
+            }
+        }
+    };
+}
+
+impl_tuple_map_rec!(; T0 t0);
+impl_tuple_map_rec!(T0 t0; T1 t1);
+impl_tuple_map_rec!(T0 t0, T1 t1; T2 t2);
+impl_tuple_map_rec!(T0 t0, T1 t1, T2 t2; T3 t3);
+impl_tuple_map_rec!(T0 t0, T1 t1, T2 t2, T3 t3; T4 t4);
+impl_tuple_map_rec!(T0 t0, T1 t1, T2 t2, T3 t3, T4 t4; T5 t5);
+impl_tuple_map_rec!(T0 t0, T1 t1, T2 t2, T3 t3, T4 t4, T5 t5; T6 t6);
+impl_tuple_map_rec!(T0 t0, T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6; T7 t7);
+
+macro_rules! impl_scalar_map_rec {
+    ( $( $S:ty ),+ $(,)? ) => {
+        $(
+            impl<A, B, F> MapRec<A, B, F> for $S {
+                type Out = $S;
+
+                #[inline]
+                fn map_rec(self, _f: F) -> $S {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_map_rec!(bool, char);
+impl_scalar_map_rec!(u8, u16, u32, u64, u128, usize);
+impl_scalar_map_rec!(i8, i16, i32, i64, i128, isize);
+impl_scalar_map_rec!(f32, f64);
+impl_scalar_map_rec!(String, &'static str);
+
+/// `LayerFunctor::map_layer` for a layer with a container view.
+///
+/// The layer is taken apart with `IntoContainer`, its `Rec` positions are mapped with `MapRec`,
+/// and the result is rewrapped with `FromContainer` and converted into the output layer.
+/// A sum-shaped layer (`Rewrap<P> = P`, like `Option`) provides `From<Payload>` for that step.
+#[inline]
+pub fn map_layer_via_container<L, M, A, B, F>(layer: L, f: F) -> M
+where
+    L: FromContainer,
+    L::Payload: MapRec<A, B, F>,
+    M: From<L::Rewrap<<L::Payload as MapRec<A, B, F>>::Out>>,
+{
+    let payload = layer.into_container().into_tagged().0;
+    M::from(L::from_container(payload.map_rec(f)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cata, Container, Fix, Id, IntoContainer, LayerFunctor, IX_SUMNODE};
+
+    /// Cons-list layer whose element type coincides with the recursive position in tests.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum ListF<R> {
+        Nil,
+        Cons(u8, R),
+    }
+
+    impl<R> IntoContainer for ListF<R> {
+        type Tag = IX_SUMNODE;
+        type Payload = Either<(), (u8, Rec<R>)>;
+
+        fn into_container(self) -> Container<Self, Id> {
+            let payload = match self {
+                ListF::Nil => Either::Left(()),
+                ListF::Cons(x, r) => Either::Right((x, Rec(r))),
+            };
+            Container::<Self, Id>::from_tagged(Tagged::new(payload))
+        }
+    }
+
+    impl<R> FromContainer for ListF<R> {
+        type Rewrap<NewPayload> = NewPayload;
+
+        fn from_container<NewPayload>(payload: NewPayload) -> NewPayload {
+            payload
+        }
+    }
+
+    impl<R> From<Either<(), (u8, Rec<R>)>> for ListF<R> {
+        fn from(payload: Either<(), (u8, Rec<R>)>) -> Self {
+            match payload {
+                Either::Left(()) => ListF::Nil,
+                Either::Right((x, Rec(r))) => ListF::Cons(x, r),
+            }
+        }
+    }
+
+    struct List;
+
+    impl LayerFunctor for List {
+        type Layer<R> = ListF<R>;
+
+        fn map_layer<A, B, F>(layer: ListF<A>, f: F) -> ListF<B>
+        where
+            F: OpOnce<A, OutVal = B> + Clone,
+        {
+            map_layer_via_container(layer, f)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Inc;
+
+    impl OpOnce<u8> for Inc {
+        type OutVal = u8;
+        fn run(self, x: u8) -> u8 {
+            x + 1
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Sum;
+
+    impl OpOnce<ListF<u32>> for Sum {
+        type OutVal = u32;
+        fn run(self, layer: ListF<u32>) -> u32 {
+            match layer {
+                ListF::Nil => 0,
+                ListF::Cons(x, acc) => x as u32 + acc,
+            }
+        }
+    }
+
+    #[test]
+    fn derived_map_layer_only_touches_recursive_positions() {
+        assert_eq!(
+            List::map_layer(ListF::Cons(1u8, 10u8), Inc),
+            ListF::Cons(1, 11)
+        );
+        assert_eq!(List::map_layer(ListF::<u8>::Nil, Inc), ListF::Nil);
+    }
+
+    #[test]
+    fn derived_map_layer_drives_cata() {
+        let list = [1u8, 2, 3]
+            .into_iter()
+            .rev()
+            .fold(Fix::<List>::new(ListF::Nil), |tail, x| {
+                Fix::new(ListF::Cons(x, tail))
+            });
+        assert_eq!(cata::<_, u32>(Sum).run(list), 6);
+    }
+}
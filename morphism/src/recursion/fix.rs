@@ -0,0 +1,48 @@
+use crate::{Corecursive, LayerFunctor, OpOnce, Recursive};
+
+/// Fixed point of a base functor `F`: `Fix<F> ≅ F::Layer<Fix<F>>`.
+///
+/// Lets a user describe only the base functor (a marker type implementing `LayerFunctor`)
+/// and get a recursive type with `Recursive` + `Corecursive` for free.
+///
+/// No `Clone` / `PartialEq` / `Debug` impls: bounding them on `F::Layer<Fix<F>>` is cyclic.
+/// Fold into a host type with `cata` when those are needed.
+pub struct Fix<F: LayerFunctor>(pub Box<F::Layer<Fix<F>>>);
+
+impl<F: LayerFunctor> Fix<F> {
+    #[inline]
+    pub fn new(layer: F::Layer<Fix<F>>) -> Self {
+        Fix(Box::new(layer))
+    }
+
+    #[inline]
+    pub fn into_layer(self) -> F::Layer<Fix<F>> {
+        *self.0
+    }
+}
+
+impl<F: LayerFunctor> LayerFunctor for Fix<F> {
+    type Layer<R> = F::Layer<R>;
+
+    #[inline]
+    fn map_layer<A, B, G>(layer: Self::Layer<A>, g: G) -> Self::Layer<B>
+    where
+        G: OpOnce<A, OutVal = B> + Clone,
+    {
+        F::map_layer(layer, g)
+    }
+}
+
+impl<F: LayerFunctor> Recursive for Fix<F> {
+    #[inline]
+    fn project(self) -> Self::Layer<Self> {
+        self.into_layer()
+    }
+}
+
+impl<F: LayerFunctor> Corecursive for Fix<F> {
+    #[inline]
+    fn embed(layer: Self::Layer<Self>) -> Self {
+        Fix::new(layer)
+    }
+}
@@ -0,0 +1,31 @@
+//! One-layer view of a recursive host type.
+//!
+//! A recursive type `T` is described by its *base functor*: the same shape with every recursive
+//! position replaced by a type parameter `R` (`Layer<R>`). Schemes (`Cata`, `Ana`, `Para`)
+//! only need to peel one layer (`project`), rebuild one layer (`embed`), and map over the
+//! recursive positions of a layer (`map_layer`).
+
+use crate::OpOnce;
+
+/// Base functor of a recursive type: `Layer<R>` plus a map over its recursive positions.
+///
+/// `map_layer` must visit recursive positions left-to-right, applying `f` once per position
+/// (non-recursive fields are moved through unchanged). Layers with a container view can
+/// derive it with `map_layer_via_container`.
+pub trait LayerFunctor {
+    type Layer<R>;
+
+    fn map_layer<A, B, F>(layer: Self::Layer<A>, f: F) -> Self::Layer<B>
+    where
+        F: OpOnce<A, OutVal = B> + Clone;
+}
+
+/// A type that can be unrolled one layer at a time.
+pub trait Recursive: LayerFunctor + Sized {
+    fn project(self) -> Self::Layer<Self>;
+}
+
+/// A type that can be rebuilt one layer at a time.
+pub trait Corecursive: LayerFunctor + Sized {
+    fn embed(layer: Self::Layer<Self>) -> Self;
+}
@@ -0,0 +1,11 @@
+mod layer;
+pub use layer::*;
+
+mod fix;
+pub use fix::*;
+
+mod schemes;
+pub use schemes::*;
+
+mod container_layer;
+pub use container_layer::*;
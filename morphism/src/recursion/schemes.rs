@@ -0,0 +1,384 @@
+use core::marker::PhantomData;
+
+use crate::{Corecursive, OpOnce, OpTy, Recursive};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// SEMANTICS - Runnable op implementations
+// ═══════════════════════════════════════════════════════════════════════════
+
+// All std traits are hand-written: the second parameter is phantom (`fn() -> _`) and must not
+// pick up the bounds a derive would add. Only the wrapped (co)algebra is compared/printed.
+macro_rules! scheme_impls {
+    ($S:ident, $field:ident) => {
+        impl<F: Clone, P> Clone for $S<F, P> {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self::new(self.$field.clone())
+            }
+        }
+
+        impl<F: Copy, P> Copy for $S<F, P> {}
+
+        impl<F: Default, P> Default for $S<F, P> {
+            #[inline]
+            fn default() -> Self {
+                Self::new(F::default())
+            }
+        }
+
+        impl<F: core::fmt::Debug, P> core::fmt::Debug for $S<F, P> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($S))
+                    .field(stringify!($field), &self.$field)
+                    .finish()
+            }
+        }
+
+        impl<F: PartialEq, P> PartialEq for $S<F, P> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.$field == other.$field
+            }
+        }
+
+        impl<F: Eq, P> Eq for $S<F, P> {}
+    };
+}
+
+/// Catamorphism (fold): `x ↦ alg(map_layer(project(x), cata(alg)))`.
+///
+/// `Alg: OpOnce<X::Layer<A>, OutVal = A>` consumes one layer whose recursive positions have
+/// already been folded to `A`.
+pub struct Cata<Alg, A> {
+    pub alg: Alg,
+    _a: PhantomData<fn() -> A>,
+}
+
+impl<Alg, A> Cata<Alg, A> {
+    #[inline]
+    pub const fn new(alg: Alg) -> Self {
+        Self {
+            alg,
+            _a: PhantomData,
+        }
+    }
+}
+
+scheme_impls!(Cata, alg);
+
+/// Construct a catamorphism (`Cata<Alg, A>`).
+#[inline]
+pub const fn cata<Alg, A>(alg: Alg) -> Cata<Alg, A> {
+    Cata::new(alg)
+}
+
+impl<Alg, A, X> OpTy<X> for Cata<Alg, A>
+where
+    X: Recursive,
+    Alg: OpOnce<X::Layer<A>, OutVal = A> + Clone,
+{
+    type OutTy = A;
+}
+
+impl<Alg, A, X> OpOnce<X> for Cata<Alg, A>
+where
+    X: Recursive,
+    Alg: OpOnce<X::Layer<A>, OutVal = A> + Clone,
+{
+    type OutVal = A;
+
+    #[inline]
+    fn run(self, x: X) -> Self::OutVal {
+        let layer = X::map_layer(x.project(), self.clone());
+        self.alg.run(layer)
+    }
+}
+
+/// Anamorphism (unfold): `seed ↦ embed(map_layer(coalg(seed), ana(coalg)))`.
+///
+/// `Coalg: OpOnce<Seed, OutVal = X::Layer<Seed>>` produces one layer whose recursive positions
+/// are new seeds. The output type `X` is carried by the op.
+pub struct Ana<Coalg, X> {
+    pub coalg: Coalg,
+    _x: PhantomData<fn() -> X>,
+}
+
+impl<Coalg, X> Ana<Coalg, X> {
+    #[inline]
+    pub const fn new(coalg: Coalg) -> Self {
+        Self {
+            coalg,
+            _x: PhantomData,
+        }
+    }
+}
+
+scheme_impls!(Ana, coalg);
+
+/// Construct an anamorphism (`Ana<Coalg, X>`).
+#[inline]
+pub const fn ana<Coalg, X>(coalg: Coalg) -> Ana<Coalg, X> {
+    Ana::new(coalg)
+}
+
+impl<Coalg, X, Seed> OpTy<Seed> for Ana<Coalg, X>
+where
+    X: Corecursive,
+    Coalg: OpOnce<Seed, OutVal = X::Layer<Seed>> + Clone,
+{
+    type OutTy = X;
+}
+
+impl<Coalg, X, Seed> OpOnce<Seed> for Ana<Coalg, X>
+where
+    X: Corecursive,
+    Coalg: OpOnce<Seed, OutVal = X::Layer<Seed>> + Clone,
+{
+    type OutVal = X;
+
+    #[inline]
+    fn run(self, seed: Seed) -> Self::OutVal {
+        let layer = self.coalg.clone().run(seed);
+        X::embed(X::map_layer(layer, self))
+    }
+}
+
+/// Paramorphism (fold with access to subterms): like `Cata`, but every recursive position
+/// holds `(subterm, folded)`.
+///
+/// `Alg: OpOnce<X::Layer<(X, A)>, OutVal = A>`. Subterms are cloned once per position.
+pub struct Para<Alg, A> {
+    pub alg: Alg,
+    _a: PhantomData<fn() -> A>,
+}
+
+impl<Alg, A> Para<Alg, A> {
+    #[inline]
+    pub const fn new(alg: Alg) -> Self {
+        Self {
+            alg,
+            _a: PhantomData,
+        }
+    }
+}
+
+scheme_impls!(Para, alg);
+
+/// Construct a paramorphism (`Para<Alg, A>`).
+#[inline]
+pub const fn para<Alg, A>(alg: Alg) -> Para<Alg, A> {
+    Para::new(alg)
+}
+
+/// Per-position step of `Para`: keep the subterm next to its folded result.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[doc(hidden)]
+pub struct ParaKeep<P>(pub P);
+
+impl<P, X> OpOnce<X> for ParaKeep<P>
+where
+    X: Clone,
+    P: OpOnce<X>,
+{
+    type OutVal = (X, P::OutVal);
+
+    #[inline]
+    fn run(self, x: X) -> Self::OutVal {
+        let folded = self.0.run(x.clone());
+        (x, folded)
+    }
+}
+
+impl<Alg, A, X> OpTy<X> for Para<Alg, A>
+where
+    X: Recursive + Clone,
+    Alg: OpOnce<X::Layer<(X, A)>, OutVal = A> + Clone,
+{
+    type OutTy = A;
+}
+
+impl<Alg, A, X> OpOnce<X> for Para<Alg, A>
+where
+    X: Recursive + Clone,
+    Alg: OpOnce<X::Layer<(X, A)>, OutVal = A> + Clone,
+{
+    type OutVal = A;
+
+    #[inline]
+    fn run(self, x: X) -> Self::OutVal {
+        let layer = X::map_layer(x.project(), ParaKeep(self.clone()));
+        self.alg.run(layer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Fix, LayerFunctor};
+
+    /// Host AST, as a user would write it.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum Expr {
+        Lit(i32),
+        Neg(Box<Expr>),
+        Add(Box<Expr>, Box<Expr>),
+    }
+
+    /// Its base functor: recursive positions replaced by `R`.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum ExprF<R> {
+        Lit(i32),
+        Neg(R),
+        Add(R, R),
+    }
+
+    impl LayerFunctor for Expr {
+        type Layer<R> = ExprF<R>;
+
+        fn map_layer<A, B, F>(layer: ExprF<A>, f: F) -> ExprF<B>
+        where
+            F: OpOnce<A, OutVal = B> + Clone,
+        {
+            match layer {
+                ExprF::Lit(n) => ExprF::Lit(n),
+                ExprF::Neg(e) => ExprF::Neg(f.run(e)),
+                ExprF::Add(l, r) => {
+                    let l = f.clone().run(l);
+                    ExprF::Add(l, f.run(r))
+                }
+            }
+        }
+    }
+
+    impl Recursive for Expr {
+        fn project(self) -> ExprF<Expr> {
+            match self {
+                Expr::Lit(n) => ExprF::Lit(n),
+                Expr::Neg(e) => ExprF::Neg(*e),
+                Expr::Add(l, r) => ExprF::Add(*l, *r),
+            }
+        }
+    }
+
+    impl Corecursive for Expr {
+        fn embed(layer: ExprF<Expr>) -> Self {
+            match layer {
+                ExprF::Lit(n) => Expr::Lit(n),
+                ExprF::Neg(e) => Expr::Neg(Box::new(e)),
+                ExprF::Add(l, r) => Expr::Add(Box::new(l), Box::new(r)),
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Eval;
+
+    impl OpOnce<ExprF<i64>> for Eval {
+        type OutVal = i64;
+        fn run(self, layer: ExprF<i64>) -> i64 {
+            match layer {
+                ExprF::Lit(n) => n as i64,
+                ExprF::Neg(v) => -v,
+                ExprF::Add(l, r) => l + r,
+            }
+        }
+    }
+
+    /// Coalgebra: `n ↦ 1 + 1 + .. + 1` as a right-leaning sum of `n` literals.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Ones;
+
+    impl OpOnce<u32> for Ones {
+        type OutVal = ExprF<u32>;
+        fn run(self, n: u32) -> ExprF<u32> {
+            if n <= 1 {
+                ExprF::Lit(1)
+            } else {
+                ExprF::Add(1, n - 1)
+            }
+        }
+    }
+
+    /// Paramorphism algebra: render, parenthesising only `Add` operands of a `Neg`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Render;
+
+    impl OpOnce<ExprF<(Expr, String)>> for Render {
+        type OutVal = String;
+        fn run(self, layer: ExprF<(Expr, String)>) -> String {
+            match layer {
+                ExprF::Lit(n) => n.to_string(),
+                ExprF::Neg((Expr::Add(..), s)) => format!("-({s})"),
+                ExprF::Neg((_, s)) => format!("-{s}"),
+                ExprF::Add((_, l), (_, r)) => format!("{l} + {r}"),
+            }
+        }
+    }
+
+    fn lit(n: i32) -> Expr {
+        Expr::Lit(n)
+    }
+
+    fn add(l: Expr, r: Expr) -> Expr {
+        Expr::Add(Box::new(l), Box::new(r))
+    }
+
+    fn neg(e: Expr) -> Expr {
+        Expr::Neg(Box::new(e))
+    }
+
+    #[test]
+    fn cata_evaluates_host_ast() {
+        let e = add(lit(1), neg(add(lit(2), lit(3))));
+        let v: i64 = cata(Eval).run(e);
+        assert_eq!(v, -4);
+    }
+
+    #[test]
+    fn ana_then_cata_round_trips() {
+        let e: Expr = ana(Ones).run(4u32);
+        assert_eq!(e, add(lit(1), add(lit(1), add(lit(1), lit(1)))));
+        assert_eq!(cata::<_, i64>(Eval).run(e), 4);
+    }
+
+    #[test]
+    fn std_traits_ignore_the_phantom_parameter() {
+        // `Expr` is neither `Copy` nor `Default`; only the coalgebra's impls matter.
+        let op: Ana<Ones, Expr> = Default::default();
+        let copy = op;
+        assert_eq!(op, copy);
+        assert_eq!(format!("{op:?}"), "Ana { coalg: Ones }");
+    }
+
+    #[test]
+    fn para_sees_original_subterms() {
+        let e = add(neg(lit(1)), neg(add(lit(2), lit(3))));
+        let s: String = para(Render).run(e);
+        assert_eq!(s, "-1 + -(2 + 3)");
+    }
+
+    /// Base functor marker for `Fix`: no host recursive type needed.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct ExprL;
+
+    impl LayerFunctor for ExprL {
+        type Layer<R> = ExprF<R>;
+
+        fn map_layer<A, B, F>(layer: ExprF<A>, f: F) -> ExprF<B>
+        where
+            F: OpOnce<A, OutVal = B> + Clone,
+        {
+            Expr::map_layer(layer, f)
+        }
+    }
+
+    #[test]
+    fn fix_gets_schemes_for_free() {
+        let e: Fix<ExprL> = ana(Ones).run(3u32);
+        assert_eq!(cata::<_, i64>(Eval).run(e), 3);
+
+        let lit = |n| Fix::new(ExprF::Lit(n));
+        let e: Fix<ExprL> = Fix::new(ExprF::Add(lit(2), Fix::new(ExprF::Neg(lit(5)))));
+        assert_eq!(cata::<_, i64>(Eval).run(e), -3);
+    }
+}
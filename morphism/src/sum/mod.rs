@@ -0,0 +1,81 @@
+use crate::{Tagged, IX_SUMNODE};
+
+/// Sum node: a binary coproduct (`Either<L, R>`) in the generic representation.
+pub type SumNode<S> = Tagged<IX_SUMNODE, S>;
+
+/// Host binary sum: exactly one of `L` or `R`.
+///
+/// This is the coproduct counterpart of a pair `(A, B)`: `choose` / `either` consume it the way
+/// `bimap` / `fanout` consume pairs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Swap the two sides.
+    #[inline]
+    pub fn flip(self) -> Either<R, L> {
+        match self {
+            Either::Left(l) => Either::Right(l),
+            Either::Right(r) => Either::Left(r),
+        }
+    }
+
+    /// Return the left value, if any.
+    #[inline]
+    pub fn left(self) -> Option<L> {
+        match self {
+            Either::Left(l) => Some(l),
+            Either::Right(_) => None,
+        }
+    }
+
+    /// Return the right value, if any.
+    #[inline]
+    pub fn right(self) -> Option<R> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(r) => Some(r),
+        }
+    }
+}
+
+// `Ok` is the left side, matching `Option` (`Some` on the left) and `either(on_ok, on_err)`.
+impl<T, E> From<Result<T, E>> for Either<T, E> {
+    #[inline]
+    fn from(r: Result<T, E>) -> Self {
+        match r {
+            Ok(t) => Either::Left(t),
+            Err(e) => Either::Right(e),
+        }
+    }
+}
+
+impl<T, E> From<Either<T, E>> for Result<T, E> {
+    #[inline]
+    fn from(e: Either<T, E>) -> Self {
+        match e {
+            Either::Left(t) => Ok(t),
+            Either::Right(e) => Err(e),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Either<T, ()> {
+    #[inline]
+    fn from(o: Option<T>) -> Self {
+        match o {
+            Some(t) => Either::Left(t),
+            None => Either::Right(()),
+        }
+    }
+}
+
+impl<T> From<Either<T, ()>> for Option<T> {
+    #[inline]
+    fn from(e: Either<T, ()>) -> Self {
+        e.left()
+    }
+}
@@ -0,0 +1,11 @@
+//! Compile-fail checks: each compiler diagnostic is the only error its misuse produces
+//! (one case per message in `tests/ui/`, expected output in `tests/ui/*.stderr`).
+//!
+//! Regenerate the expected output after an intended message change with
+//! `TRYBUILD=overwrite cargo test --test diagnostics`.
+
+#[test]
+fn diagnostics() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
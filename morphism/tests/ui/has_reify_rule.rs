@@ -0,0 +1,81 @@
+//! `TwiceKey` survives lowering, but its reify slot is a `NoReifyRule` witness.
+
+use morphism::*;
+
+declare_domain! {
+    domain struct CountDomain: Ext<U2, U0>;
+
+    semantics {
+        KX_INCR,
+        KX_TWICE,
+    }
+}
+
+impl NodeShape<KX_INCR> for CountDomain {
+    type Shape = shape::Nullary;
+}
+
+impl NodeShape<KX_TWICE> for CountDomain {
+    type Shape = shape::Nullary;
+}
+
+type IncrKey = KX_INCR;
+type TwiceKey = KX_TWICE;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct LowerIncr;
+
+impl OpOnce<NullaryToken> for LowerIncr {
+    type OutVal = Tagged<IncrKey, NullaryToken>;
+    fn run(self, t: NullaryToken) -> Self::OutVal {
+        Tagged::new(t)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct IncrOp;
+
+impl OpOnce<u32> for IncrOp {
+    type OutVal = u32;
+    fn run(self, x: u32) -> u32 {
+        x + 1
+    }
+}
+
+impl OpOnce<NullaryToken> for IncrOp {
+    type OutVal = IncrOp;
+    fn run(self, _: NullaryToken) -> IncrOp {
+        IncrOp
+    }
+}
+
+/// Lowers `TwiceKey` to itself, so it reaches reify.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct KeepTwice;
+
+impl OpOnce<NullaryToken> for KeepTwice {
+    type OutVal = Tagged<TwiceKey, NullaryToken>;
+    fn run(self, t: NullaryToken) -> Self::OutVal {
+        Tagged::new(t)
+    }
+}
+
+impl<Input> LowerDomain<Input> for CountDomain {
+    type Table = (LowerIncr, KeepTwice);
+    fn lower_table() -> Self::Table {
+        (LowerIncr, KeepTwice)
+    }
+}
+
+impl<Input> ReifyDomain<Input> for CountDomain {
+    type Table = (IncrOp, NoReifyRule<TwiceKey>);
+    fn reify_table() -> Self::Table {
+        (IncrOp, NoReifyRule::new())
+    }
+}
+
+fn main() {
+    let incr: Tagged<IncrKey, NullaryToken> = Tagged::new(NullaryToken);
+    let twice: Tagged<TwiceKey, NullaryToken> = Tagged::new(NullaryToken);
+    let _ = compile::<u32, _>(then(incr, twice)).run(1);
+}
@@ -0,0 +1,91 @@
+//! Lowering leaves a syntax-role key behind; only `R_SEMANTICS` keys may reach reify.
+
+use morphism::*;
+
+declare_domain! {
+    domain struct CountDomain: Ext<U2, U0>;
+
+    syntax {
+        KX_INCR_SYNTAX,
+        KX_TWICE_SYNTAX,
+    }
+
+    semantics {
+        KX_INCR,
+        KX_TWICE,
+    }
+}
+
+impl NodeShape<KX_INCR> for CountDomain {
+    type Shape = shape::Nullary;
+}
+
+impl NodeShape<KX_TWICE> for CountDomain {
+    type Shape = shape::Nullary;
+}
+
+impl NodeShape<KX_TWICE_SYNTAX> for CountDomain {
+    type Shape = shape::Nullary;
+}
+
+type IncrKey = KX_INCR;
+type TwiceKey = KX_TWICE;
+type TwiceSyntax = KX_TWICE_SYNTAX;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct LowerIncr;
+
+impl OpOnce<NullaryToken> for LowerIncr {
+    type OutVal = Tagged<IncrKey, NullaryToken>;
+    fn run(self, t: NullaryToken) -> Self::OutVal {
+        Tagged::new(t)
+    }
+}
+
+/// Lowers `TwiceKey` to its syntax node instead of a kernel node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct LowerToSyntax;
+
+impl OpOnce<NullaryToken> for LowerToSyntax {
+    type OutVal = Tagged<TwiceSyntax, NullaryToken>;
+    fn run(self, t: NullaryToken) -> Self::OutVal {
+        Tagged::new(t)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct IncrOp;
+
+impl OpOnce<u32> for IncrOp {
+    type OutVal = u32;
+    fn run(self, x: u32) -> u32 {
+        x + 1
+    }
+}
+
+impl OpOnce<NullaryToken> for IncrOp {
+    type OutVal = IncrOp;
+    fn run(self, _: NullaryToken) -> IncrOp {
+        IncrOp
+    }
+}
+
+impl<Input> LowerDomain<Input> for CountDomain {
+    type Table = (LowerIncr, LowerToSyntax);
+    fn lower_table() -> Self::Table {
+        (LowerIncr, LowerToSyntax)
+    }
+}
+
+impl<Input> ReifyDomain<Input> for CountDomain {
+    type Table = (IncrOp, IncrOp);
+    fn reify_table() -> Self::Table {
+        (IncrOp, IncrOp)
+    }
+}
+
+fn main() {
+    let incr: Tagged<IncrKey, NullaryToken> = Tagged::new(NullaryToken);
+    let twice: Tagged<TwiceKey, NullaryToken> = Tagged::new(NullaryToken);
+    let _ = compile::<u32, _>(then(incr, twice)).run(1);
+}
@@ -0,0 +1,17 @@
+//! The mapped step runs on `u8` but not on the second child, a `String`.
+
+use morphism::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct AddTen;
+
+impl OpOnce<u8> for AddTen {
+    type OutVal = u8;
+    fn run(self, x: u8) -> u8 {
+        x + 10
+    }
+}
+
+fn main() {
+    let _ = compile::<HlistNode<(u8, String)>, _>(map_children_prog(op_lift(AddTen)));
+}
@@ -0,0 +1,22 @@
+//! Children past the numbered chunks are still checked: the step does not run on the
+//! `String` at the end of an 18-chunk HList.
+
+use morphism::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct AddTen;
+
+impl OpOnce<u8> for AddTen {
+    type OutVal = u8;
+    fn run(self, x: u8) -> u8 {
+        x + 10
+    }
+}
+
+type Chunk<Tail> = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, Tail);
+type C4<T> = Chunk<Chunk<Chunk<Chunk<T>>>>;
+type H = HlistNode<C4<C4<C4<C4<Chunk<(u8, String)>>>>>>;
+
+fn main() {
+    let _ = compile::<H, _>(map_children_prog(op_lift(AddTen)));
+}